@@ -0,0 +1,3 @@
+-- Drop feed subscription tables
+DROP TABLE IF EXISTS feed_entries;
+DROP TABLE IF EXISTS feeds;
@@ -0,0 +1,32 @@
+-- Create feeds table for RSS/Atom subscriptions
+CREATE TABLE feeds (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    url TEXT NOT NULL UNIQUE,
+    name TEXT NOT NULL,
+    poll_interval_seconds INTEGER NOT NULL DEFAULT 900,
+    is_active BOOLEAN NOT NULL DEFAULT TRUE,
+    last_polled_at TIMESTAMPTZ,
+    last_error TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Entries seen on each feed, keyed by GUID so polls can be diffed
+CREATE TABLE feed_entries (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    feed_id UUID NOT NULL REFERENCES feeds(id) ON DELETE CASCADE,
+    guid TEXT NOT NULL,
+    link TEXT NOT NULL,
+    title TEXT,
+    published_at TIMESTAMPTZ,
+    content_hash TEXT NOT NULL,
+    file_id UUID REFERENCES files(id) ON DELETE SET NULL,
+    first_seen_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (feed_id, guid)
+);
+
+CREATE INDEX idx_feeds_active ON feeds(is_active) WHERE is_active;
+CREATE INDEX idx_feed_entries_feed_id ON feed_entries(feed_id);
+
+ALTER TABLE feeds ADD CONSTRAINT chk_poll_interval CHECK (poll_interval_seconds > 0);
@@ -0,0 +1,3 @@
+DROP INDEX IF EXISTS idx_content_chunks_language;
+
+ALTER TABLE content_chunks DROP COLUMN IF EXISTS language;
@@ -0,0 +1,3 @@
+ALTER TABLE content_chunks ADD COLUMN language TEXT;
+
+CREATE INDEX idx_content_chunks_language ON content_chunks(language);
@@ -0,0 +1,3 @@
+DROP INDEX IF EXISTS idx_content_chunks_parent_chunk_id;
+
+ALTER TABLE content_chunks DROP COLUMN IF EXISTS parent_chunk_id;
@@ -0,0 +1,4 @@
+ALTER TABLE content_chunks
+    ADD COLUMN parent_chunk_id UUID REFERENCES content_chunks(id) ON DELETE CASCADE;
+
+CREATE INDEX idx_content_chunks_parent_chunk_id ON content_chunks(parent_chunk_id);
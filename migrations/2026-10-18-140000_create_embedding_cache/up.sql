@@ -0,0 +1,10 @@
+CREATE TABLE embedding_cache (
+    text_hash TEXT NOT NULL,
+    model_name TEXT NOT NULL,
+    -- Empty when the model reports no version, so it can be part of the key
+    model_version TEXT NOT NULL DEFAULT '',
+    template TEXT NOT NULL,
+    embedding VECTOR NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (text_hash, model_name, model_version, template)
+);
@@ -0,0 +1,8 @@
+DROP INDEX IF EXISTS embeddings_model_name_idx;
+DROP TABLE IF EXISTS embedding_models;
+
+-- Fails if vectors of another dimension were stored in the meantime
+ALTER TABLE embeddings
+ALTER COLUMN embedding TYPE VECTOR(1024);
+
+CREATE INDEX ON embeddings USING ivfflat (embedding vector_l2_ops) WITH (lists = 100);
@@ -0,0 +1,22 @@
+-- Each embedding model keeps its own vector size, so the column is no longer
+-- fixed to one dimension; every model instead gets a partial index on its
+-- vectors cast to that model's dimension when it is registered
+DROP INDEX IF EXISTS embeddings_embedding_idx;
+
+ALTER TABLE embeddings
+ALTER COLUMN embedding TYPE VECTOR;
+
+CREATE TABLE embedding_models (
+    name TEXT PRIMARY KEY,
+    dimension INTEGER NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Models that already have vectors keep the dimension they were stored with
+INSERT INTO embedding_models (name, dimension)
+SELECT model_name, MIN(vector_dims(embedding))
+FROM embeddings
+WHERE embedding IS NOT NULL
+GROUP BY model_name;
+
+CREATE INDEX embeddings_model_name_idx ON embeddings (model_name);
@@ -0,0 +1,9 @@
+DROP INDEX IF EXISTS embedding_models_active_idx;
+
+ALTER TABLE embedding_models
+DROP COLUMN is_active;
+
+DELETE FROM processing_jobs WHERE file_id IS NULL;
+
+ALTER TABLE processing_jobs
+ALTER COLUMN file_id SET NOT NULL;
@@ -0,0 +1,9 @@
+-- Re-embedding jobs can span the whole corpus rather than a single file
+ALTER TABLE processing_jobs
+ALTER COLUMN file_id DROP NOT NULL;
+
+-- The model searches use by default; at most one is active
+ALTER TABLE embedding_models
+ADD COLUMN is_active BOOLEAN NOT NULL DEFAULT FALSE;
+
+CREATE UNIQUE INDEX embedding_models_active_idx ON embedding_models (is_active) WHERE is_active;
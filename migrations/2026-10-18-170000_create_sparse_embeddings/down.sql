@@ -0,0 +1,2 @@
+DROP TABLE IF EXISTS sparse_embeddings;
+DROP TABLE IF EXISTS sparse_embedding_models;
@@ -0,0 +1,20 @@
+-- Term-weight vectors from sparse models such as SPLADE. Like dense vectors,
+-- the column has no fixed dimension; each model gets a partial HNSW index on
+-- its vectors cast to its vocabulary size when it is registered
+CREATE TABLE sparse_embedding_models (
+    name TEXT PRIMARY KEY,
+    dimension INTEGER NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE TABLE sparse_embeddings (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    content_chunk_id UUID NOT NULL REFERENCES content_chunks(id) ON DELETE CASCADE,
+    model_name TEXT NOT NULL,
+    model_version TEXT,
+    generated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    embedding SPARSEVEC NOT NULL
+);
+
+CREATE INDEX sparse_embeddings_content_chunk_id_idx ON sparse_embeddings (content_chunk_id);
+CREATE INDEX sparse_embeddings_model_name_idx ON sparse_embeddings (model_name);
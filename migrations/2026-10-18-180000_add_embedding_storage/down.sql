@@ -0,0 +1,2 @@
+ALTER TABLE embedding_models
+DROP COLUMN IF EXISTS storage;
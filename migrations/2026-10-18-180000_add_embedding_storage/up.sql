@@ -0,0 +1,6 @@
+-- How each model's vectors are indexed: full, halfvec or binary. Existing
+-- models keep their float32 index until the server starts with another
+-- EMBEDDING_STORAGE, which builds the new index over the stored vectors
+-- and then drops the old one
+ALTER TABLE embedding_models
+ADD COLUMN storage TEXT NOT NULL DEFAULT 'full';
@@ -0,0 +1,77 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+
+use crate::domain::value_objects::ChunkingConfig;
+
+#[derive(Debug)]
+pub enum ChunkingError {
+    EmbeddingError(String),
+    InvalidConfig(String),
+}
+
+impl std::fmt::Display for ChunkingError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ChunkingError::EmbeddingError(msg) => write!(f, "Embedding error: {}", msg),
+            ChunkingError::InvalidConfig(msg) => write!(f, "Invalid chunking config: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for ChunkingError {}
+
+/// A piece of document text produced by a chunking strategy, before it is
+/// attached to a file as a `ContentChunk`
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextChunk {
+    pub text: String,
+    /// Tokens the embedding model sees for `text`, special tokens included
+    pub token_count: i32,
+    /// Heading trail such as `Intro > Setup`, when the strategy knows it
+    pub section_path: Option<String>,
+}
+
+impl TextChunk {
+    pub fn new(text: String, token_count: i32) -> Self {
+        Self {
+            text,
+            token_count,
+            section_path: None,
+        }
+    }
+
+    pub fn with_section_path(mut self, section_path: Option<String>) -> Self {
+        self.section_path = section_path;
+        self
+    }
+}
+
+/// Splits extracted document text into retrievable pieces. Every ingestion
+/// pipeline goes through the same strategy so a document chunks identically
+/// no matter how it arrived.
+#[async_trait]
+pub trait ChunkingStrategy: Send + Sync {
+    async fn chunk(&self, text: &str) -> Result<Vec<TextChunk>, ChunkingError>;
+}
+
+/// Builds strategies for per-request chunking configs
+pub trait ChunkingStrategyFactory: Send + Sync {
+    /// Strategy used when a config does not name one
+    fn default_strategy(&self) -> &str;
+
+    /// Fills unset fields with the server defaults and checks the result
+    /// against the embedding model, so it can be recorded and replayed
+    fn resolve(&self, config: &ChunkingConfig) -> Result<ChunkingConfig, ChunkingError>;
+
+    fn create(&self, config: &ChunkingConfig) -> Result<Arc<dyn ChunkingStrategy>, ChunkingError>;
+
+    /// Strategy that cuts the parent chunks children are chunked from, or
+    /// None when the config is not hierarchical
+    fn create_parent(
+        &self,
+        config: &ChunkingConfig,
+    ) -> Result<Option<Arc<dyn ChunkingStrategy>>, ChunkingError>;
+
+    /// Tokens the embedding model sees for `text`, special tokens included
+    fn count_tokens(&self, text: &str) -> usize;
+}
@@ -0,0 +1,51 @@
+use std::path::Path;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DetectionMethod {
+    Signature,
+    Container,
+    Declared,
+    Extension,
+    TextHeuristic,
+    Fallback,
+}
+
+impl DetectionMethod {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DetectionMethod::Signature => "signature",
+            DetectionMethod::Container => "container",
+            DetectionMethod::Declared => "declared",
+            DetectionMethod::Extension => "extension",
+            DetectionMethod::TextHeuristic => "text_heuristic",
+            DetectionMethod::Fallback => "fallback",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DetectedContent {
+    pub mime_type: String,
+    /// Character encoding for text content, e.g. "UTF-8" or "windows-1252"
+    pub encoding: Option<String>,
+    pub method: DetectionMethod,
+}
+
+/// Determines the real content type of a document from its bytes.
+/// The client-declared type and the file name are only used as hints.
+pub trait ContentDetector: Send + Sync {
+    fn detect(
+        &self,
+        data: &[u8],
+        file_name: Option<&str>,
+        declared_type: Option<&str>,
+    ) -> DetectedContent;
+
+    /// Detects the type of a file on disk without reading all of it
+    fn detect_file(
+        &self,
+        path: &Path,
+        file_name: Option<&str>,
+        declared_type: Option<&str>,
+    ) -> std::io::Result<DetectedContent>;
+}
@@ -1,12 +1,18 @@
 use async_trait::async_trait;
 use pgvector::Vector;
+use std::time::Duration;
+
+use crate::domain::value_objects::{EmbeddingPrompts, EmbeddingPurpose};
 
 #[derive(Debug)]
 pub enum EmbeddingProviderError {
     NetworkError(String),
     ApiError(String),
     InvalidInput(String),
-    RateLimitExceeded,
+    /// The request as a whole was too large; smaller batches may succeed
+    BatchTooLarge(String),
+    /// How long the server asked to wait, when it said
+    RateLimitExceeded(Option<Duration>),
     ServiceUnavailable,
 }
 
@@ -16,7 +22,13 @@ impl std::fmt::Display for EmbeddingProviderError {
             EmbeddingProviderError::NetworkError(msg) => write!(f, "Network error: {}", msg),
             EmbeddingProviderError::ApiError(msg) => write!(f, "API error: {}", msg),
             EmbeddingProviderError::InvalidInput(msg) => write!(f, "Invalid input: {}", msg),
-            EmbeddingProviderError::RateLimitExceeded => write!(f, "Rate limit exceeded"),
+            EmbeddingProviderError::BatchTooLarge(msg) => write!(f, "Batch too large: {}", msg),
+            EmbeddingProviderError::RateLimitExceeded(None) => write!(f, "Rate limit exceeded"),
+            EmbeddingProviderError::RateLimitExceeded(Some(retry_after)) => write!(
+                f,
+                "Rate limit exceeded; retry after {:.1}s",
+                retry_after.as_secs_f64()
+            ),
             EmbeddingProviderError::ServiceUnavailable => write!(f, "Service unavailable"),
         }
     }
@@ -27,6 +39,7 @@ impl std::error::Error for EmbeddingProviderError {}
 #[derive(Debug, Clone)]
 pub struct EmbeddingRequest {
     pub text: String,
+    pub purpose: EmbeddingPurpose,
     pub model_name: Option<String>,
     pub model_version: Option<String>,
 }
@@ -42,6 +55,7 @@ pub struct EmbeddingResponse {
 #[derive(Debug, Clone)]
 pub struct BatchEmbeddingRequest {
     pub texts: Vec<String>,
+    pub purpose: EmbeddingPurpose,
     pub model_name: Option<String>,
     pub model_version: Option<String>,
 }
@@ -54,6 +68,36 @@ pub struct BatchEmbeddingResponse {
     pub total_tokens: Option<i32>,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CircuitState {
+    Closed,
+    Open,
+    HalfOpen,
+}
+
+impl CircuitState {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CircuitState::Closed => "closed",
+            CircuitState::Open => "open",
+            CircuitState::HalfOpen => "half_open",
+        }
+    }
+}
+
+/// Health and traffic counters of one server behind a provider
+#[derive(Debug, Clone)]
+pub struct EndpointStatus {
+    pub url: String,
+    pub state: CircuitState,
+    pub consecutive_failures: u32,
+    pub times_opened: u64,
+    pub requests: u64,
+    pub failures: u64,
+    /// Until the open circuit admits a probe
+    pub retry_in: Option<Duration>,
+}
+
 #[async_trait]
 pub trait EmbeddingProvider: Send + Sync {
     async fn generate_embedding(
@@ -73,4 +117,26 @@ pub trait EmbeddingProvider: Send + Sync {
     fn max_input_length(&self) -> usize;
 
     fn embedding_dimension(&self) -> usize;
+
+    /// The instructions this model applies per purpose; none by default
+    fn prompts(&self) -> EmbeddingPrompts {
+        EmbeddingPrompts::default()
+    }
+
+    /// The servers behind this provider, for those that track them
+    fn endpoints(&self) -> Vec<EndpointStatus> {
+        Vec::new()
+    }
+
+    /// How long to wait before requests can succeed, when every server
+    /// behind the provider is known to be down
+    fn unavailable_for(&self) -> Option<Duration> {
+        None
+    }
+
+    /// The model whose stored vectors this model's can be computed from
+    /// without calling it, such as the full-size model of a truncation
+    fn derived_from(&self) -> Option<String> {
+        None
+    }
 }
@@ -0,0 +1,40 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+#[derive(Debug)]
+pub enum FeedFetchError {
+    NetworkError(String),
+    ParseError(String),
+}
+
+impl std::fmt::Display for FeedFetchError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FeedFetchError::NetworkError(msg) => write!(f, "Network error: {}", msg),
+            FeedFetchError::ParseError(msg) => write!(f, "Parse error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for FeedFetchError {}
+
+#[derive(Debug, Clone)]
+pub struct FeedItem {
+    pub guid: String,
+    pub link: Option<String>,
+    pub title: Option<String>,
+    pub published_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone)]
+pub struct FetchedFeed {
+    pub title: Option<String>,
+    pub items: Vec<FeedItem>,
+}
+
+#[async_trait]
+pub trait FeedFetcher: Send + Sync {
+    /// Download and parse an RSS or Atom document
+    async fn fetch(&self, url: &str) -> Result<FetchedFeed, FeedFetchError>;
+}
@@ -0,0 +1,13 @@
+use crate::domain::value_objects::LanguageCode;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DetectedLanguage {
+    pub language: LanguageCode,
+    pub confidence: f64,
+}
+
+/// Identifies the natural language of a piece of text
+pub trait LanguageDetector: Send + Sync {
+    /// Returns None when the text is too short or ambiguous to call
+    fn detect(&self, text: &str) -> Option<DetectedLanguage>;
+}
@@ -1,9 +1,23 @@
+pub mod chunking_strategy;
+pub mod content_detector;
 pub mod embedding_provider;
 pub mod document_extractor;
+pub mod feed_fetcher;
 pub mod file_storage;
 pub mod job_queue;
+pub mod language_detector;
+pub mod sparse_embedding_provider;
+pub mod tokenizer;
+pub mod translation_provider;
 
+pub use chunking_strategy::{ChunkingStrategy, ChunkingStrategyFactory};
+pub use content_detector::ContentDetector;
 pub use embedding_provider::EmbeddingProvider;
 pub use document_extractor::DocumentExtractor;
+pub use feed_fetcher::FeedFetcher;
 pub use file_storage::FileStorage;
 pub use job_queue::JobQueue;
+pub use language_detector::LanguageDetector;
+pub use sparse_embedding_provider::SparseEmbeddingProvider;
+pub use tokenizer::Tokenizer;
+pub use translation_provider::TranslationProvider;
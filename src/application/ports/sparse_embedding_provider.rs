@@ -0,0 +1,29 @@
+use async_trait::async_trait;
+use pgvector::SparseVector;
+
+use crate::application::ports::embedding_provider::{
+    BatchEmbeddingRequest, EmbeddingProviderError,
+};
+
+#[derive(Debug, Clone)]
+pub struct SparseEmbeddingResponse {
+    pub embeddings: Vec<SparseVector>,
+    pub model_name: String,
+    pub model_version: Option<String>,
+}
+
+/// Produces term-weight vectors (SPLADE and similar): one weight per
+/// vocabulary entry, nearly all of them zero. They match exact terms that
+/// dense vectors blur, such as names, codes and rare words.
+#[async_trait]
+pub trait SparseEmbeddingProvider: Send + Sync {
+    async fn generate_sparse_embeddings(
+        &self,
+        request: BatchEmbeddingRequest,
+    ) -> Result<SparseEmbeddingResponse, EmbeddingProviderError>;
+
+    fn model_info(&self) -> (String, Option<String>);
+
+    /// Size of the vocabulary the term indices point into
+    fn dimension(&self) -> usize;
+}
@@ -0,0 +1,14 @@
+/// The embedding model's view of text length. Chunkers size pieces with it so
+/// nothing sent for embedding exceeds the model's input limit.
+pub trait Tokenizer: Send + Sync {
+    /// Byte ranges of each token in `text`, excluding special tokens
+    fn token_spans(&self, text: &str) -> Vec<(usize, usize)>;
+
+    /// Number of tokens the model sees for `text`, including special tokens
+    fn count_tokens(&self, text: &str) -> usize;
+
+    /// Tokens added to every input regardless of content (e.g. `[CLS]`/`[SEP]`)
+    fn special_token_overhead(&self) -> usize {
+        self.count_tokens("")
+    }
+}
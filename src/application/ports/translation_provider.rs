@@ -0,0 +1,33 @@
+use async_trait::async_trait;
+
+use crate::domain::value_objects::LanguageCode;
+
+#[derive(Debug)]
+pub enum TranslationError {
+    /// The provider has no translation into this language; callers fall
+    /// back to the untranslated text
+    UnsupportedLanguage(String),
+}
+
+impl std::fmt::Display for TranslationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TranslationError::UnsupportedLanguage(language) => {
+                write!(f, "Unsupported language: {}", language)
+            }
+        }
+    }
+}
+
+impl std::error::Error for TranslationError {}
+
+#[async_trait]
+pub trait TranslationProvider: Send + Sync {
+    /// Translates `text` into `target`; `source` is auto-detected when None
+    async fn translate(
+        &self,
+        text: &str,
+        source: Option<&LanguageCode>,
+        target: &LanguageCode,
+    ) -> Result<String, TranslationError>;
+}
@@ -1,19 +1,32 @@
+use pgvector::Vector;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use uuid::Uuid;
 
 use crate::application::ports::{
-    DocumentExtractor, EmbeddingProvider,
+    ChunkingStrategyFactory, DocumentExtractor, EmbeddingProvider, LanguageDetector,
+    SparseEmbeddingProvider,
     document_extractor::{ExtractedContent, ExtractionOptions},
     embedding_provider::BatchEmbeddingRequest,
 };
-use crate::domain::entities::{ContentChunk, Embedding, File};
-use crate::domain::repositories::{ChunkRepository, EmbeddingRepository, FileRepository};
+use crate::application::services::EmbeddingModelRegistry;
+use crate::domain::entities::{ContentChunk, Embedding, File, SparseEmbedding};
+use crate::domain::repositories::{
+    ChunkRepository, EmbeddingCacheRepository, EmbeddingRepository, FileRepository,
+    SparseEmbeddingRepository,
+};
+use crate::domain::value_objects::{
+    ChunkingConfig, EmbeddingCacheKey, EmbeddingPurpose, EmbeddingTextTemplate, FileMetadata,
+    LanguageCode,
+};
 
 #[derive(Debug)]
 pub enum DocumentProcessingError {
     ExtractionError(String),
     EmbeddingError(String),
     RepositoryError(String),
+    ChunkTooLarge(String),
+    ChunkingError(String),
 }
 
 impl std::fmt::Display for DocumentProcessingError {
@@ -22,51 +35,109 @@ impl std::fmt::Display for DocumentProcessingError {
             DocumentProcessingError::ExtractionError(msg) => write!(f, "Extraction error: {}", msg),
             DocumentProcessingError::EmbeddingError(msg) => write!(f, "Embedding error: {}", msg),
             DocumentProcessingError::RepositoryError(msg) => write!(f, "Repository error: {}", msg),
+            DocumentProcessingError::ChunkTooLarge(msg) => write!(f, "Chunk too large: {}", msg),
+            DocumentProcessingError::ChunkingError(msg) => write!(f, "Chunking error: {}", msg),
         }
     }
 }
 
 impl std::error::Error for DocumentProcessingError {}
 
+#[derive(Debug, Clone)]
+pub struct GeneratedEmbeddings {
+    pub embeddings: Vec<Embedding>,
+    /// Term-weight vectors, when a sparse model is configured
+    pub sparse_embeddings: Vec<SparseEmbedding>,
+    /// Chunks whose vector was reused instead of requested from the provider
+    pub cache_hits: usize,
+    /// Chunks whose vector was truncated from a stored full-size one
+    pub derived: usize,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ProcessedFile {
+    pub chunks_created: i32,
+    pub embeddings_created: i32,
+    pub embedding_cache_hits: i32,
+}
+
 pub struct DocumentProcessorService {
     document_extractor: Arc<dyn DocumentExtractor>,
-    embedding_provider: Arc<dyn EmbeddingProvider>,
+    embedding_models: Arc<EmbeddingModelRegistry>,
     chunk_repository: Arc<dyn ChunkRepository>,
     embedding_repository: Arc<dyn EmbeddingRepository>,
     file_repository: Arc<dyn FileRepository>,
-    chunk_size: usize,
-    chunk_overlap: usize,
+    language_detector: Arc<dyn LanguageDetector>,
+    chunking_factory: Arc<dyn ChunkingStrategyFactory>,
+    embedding_cache: Option<Arc<dyn EmbeddingCacheRepository>>,
+    sparse_embeddings: Option<(
+        Arc<dyn SparseEmbeddingProvider>,
+        Arc<dyn SparseEmbeddingRepository>,
+    )>,
 }
 
 impl DocumentProcessorService {
     pub fn new(
         document_extractor: Arc<dyn DocumentExtractor>,
-        embedding_provider: Arc<dyn EmbeddingProvider>,
+        embedding_models: Arc<EmbeddingModelRegistry>,
         chunk_repository: Arc<dyn ChunkRepository>,
         embedding_repository: Arc<dyn EmbeddingRepository>,
         file_repository: Arc<dyn FileRepository>,
+        language_detector: Arc<dyn LanguageDetector>,
+        chunking_factory: Arc<dyn ChunkingStrategyFactory>,
     ) -> Self {
         Self {
             document_extractor,
-            embedding_provider,
+            embedding_models,
             chunk_repository,
             embedding_repository,
             file_repository,
-            chunk_size: 578,
-            chunk_overlap: 102,
+            language_detector,
+            chunking_factory,
+            embedding_cache: None,
+            sparse_embeddings: None,
         }
     }
 
+    pub fn with_embedding_cache(mut self, cache: Arc<dyn EmbeddingCacheRepository>) -> Self {
+        self.embedding_cache = Some(cache);
+        self
+    }
+
+    /// Also stores term-weight vectors for every new chunk, for sparse and hybrid search
+    pub fn with_sparse_embeddings(
+        mut self,
+        provider: Arc<dyn SparseEmbeddingProvider>,
+        repository: Arc<dyn SparseEmbeddingRepository>,
+    ) -> Self {
+        self.sparse_embeddings = Some((provider, repository));
+        self
+    }
+
     pub async fn process_file(
         &self,
         file: &File,
         extraction_options: ExtractionOptions,
-    ) -> Result<(i32, i32), DocumentProcessingError> {
+        chunking_config: Option<&ChunkingConfig>,
+    ) -> Result<ProcessedFile, DocumentProcessingError> {
+        let chunking_config = self
+            .resolve_chunking_config(file.id(), chunking_config)
+            .await?;
         let extracted_content = self
             .extract_text_from_file(file, extraction_options)
             .await?;
 
-        let chunks = self.create_chunks(file.id(), &extracted_content.text)?;
+        let document_language = self.detect_document_language(&extracted_content);
+        let chunks = self
+            .create_chunks(file.id(), &extracted_content.text, &chunking_config)
+            .await?;
+        let title = Self::document_title(Some(file), &extracted_content);
+        self.validate_chunk_sizes(
+            &chunks,
+            chunking_config.embedding_template(),
+            title.as_deref(),
+        )?;
+        let chunks = self.assign_chunk_languages(chunks, document_language.as_ref());
 
         match self.file_repository.find_by_id(file.id()).await {
             Ok(Some(_verified_file)) => {}
@@ -84,19 +155,124 @@ impl DocumentProcessorService {
             }
         }
 
+        if let Some(language) = &document_language {
+            self.record_document_language(file.id(), language).await?;
+        }
+        self.record_chunking_config(file.id(), &chunking_config)
+            .await?;
+
+        // Reprocessing a changed file replaces its previous chunks
+        self.chunk_repository
+            .delete_by_file_id(file.id())
+            .await
+            .map_err(|e| DocumentProcessingError::RepositoryError(e.to_string()))?;
+
         self.chunk_repository
             .save_batch(&chunks)
             .await
             .map_err(|e| DocumentProcessingError::RepositoryError(e.to_string()))?;
 
-        let embeddings = self.generate_embeddings_for_chunks(&chunks).await?;
+        let generated = self
+            .generate_embeddings_for_chunks(
+                &ContentChunk::leaves(&chunks),
+                chunking_config.embedding_template(),
+                title.as_deref(),
+            )
+            .await?;
 
-        self.embedding_repository
-            .save_batch(&embeddings)
+        self.save_embeddings(&generated).await?;
+
+        Ok(ProcessedFile {
+            chunks_created: chunks.len() as i32,
+            embeddings_created: generated.embeddings.len() as i32,
+            embedding_cache_hits: generated.cache_hits as i32,
+        })
+    }
+
+    /// Title used in contextual headers: the one recorded on the file, else
+    /// the one the extractor found
+    pub fn document_title(file: Option<&File>, content: &ExtractedContent) -> Option<String> {
+        file.and_then(|file| file.metadata())
+            .and_then(|metadata| metadata.get_title())
+            .or_else(|| content.metadata.get_title())
+    }
+
+    /// Language of the whole document, falling back to what the extractor reported
+    pub fn detect_document_language(&self, content: &ExtractedContent) -> Option<LanguageCode> {
+        self.language_detector
+            .detect(&content.text)
+            .map(|detected| detected.language)
+            .or_else(|| {
+                content
+                    .language
+                    .as_deref()
+                    .and_then(|l| LanguageCode::parse(l).ok())
+            })
+    }
+
+    /// Tags each chunk with its own language; chunks too short to identify
+    /// inherit the document language
+    pub fn assign_chunk_languages(
+        &self,
+        chunks: Vec<ContentChunk>,
+        document_language: Option<&LanguageCode>,
+    ) -> Vec<ContentChunk> {
+        chunks
+            .into_iter()
+            .map(|chunk| {
+                let language = self
+                    .language_detector
+                    .detect(chunk.chunk_text())
+                    .map(|detected| detected.language)
+                    .or_else(|| document_language.cloned());
+                chunk.with_language(language.map(String::from))
+            })
+            .collect()
+    }
+
+    pub async fn record_document_language(
+        &self,
+        file_id: Uuid,
+        language: &LanguageCode,
+    ) -> Result<(), DocumentProcessingError> {
+        self.update_file_metadata(file_id, |metadata| {
+            metadata.set_language(language.to_string())
+        })
+        .await
+    }
+
+    /// Stores the resolved config on the file so reprocessing chunks it the same way
+    pub async fn record_chunking_config(
+        &self,
+        file_id: Uuid,
+        config: &ChunkingConfig,
+    ) -> Result<(), DocumentProcessingError> {
+        self.update_file_metadata(file_id, |metadata| metadata.set_chunking_config(config))
             .await
-            .map_err(|e| DocumentProcessingError::RepositoryError(e.to_string()))?;
+    }
 
-        Ok((chunks.len() as i32, embeddings.len() as i32))
+    async fn update_file_metadata(
+        &self,
+        file_id: Uuid,
+        update: impl FnOnce(&mut FileMetadata),
+    ) -> Result<(), DocumentProcessingError> {
+        let Some(mut file) = self
+            .file_repository
+            .find_by_id(file_id)
+            .await
+            .map_err(|e| DocumentProcessingError::RepositoryError(e.to_string()))?
+        else {
+            return Ok(());
+        };
+
+        let mut metadata = file.metadata().cloned().unwrap_or_default();
+        update(&mut metadata);
+        file.update_metadata(metadata);
+
+        self.file_repository
+            .update(&file)
+            .await
+            .map_err(|e| DocumentProcessingError::RepositoryError(e.to_string()))
     }
 
     async fn extract_text_from_file(
@@ -110,99 +286,542 @@ impl DocumentProcessorService {
             .map_err(|e| DocumentProcessingError::ExtractionError(e.to_string()))
     }
 
-    fn create_chunks(
+    pub fn default_chunking_strategy(&self) -> &str {
+        self.chunking_factory.default_strategy()
+    }
+
+    /// The config a job chunks with: the one it was queued with, else the one
+    /// recorded on the file by its last processing, else the server defaults
+    pub async fn resolve_chunking_config(
+        &self,
+        file_id: Uuid,
+        requested: Option<&ChunkingConfig>,
+    ) -> Result<ChunkingConfig, DocumentProcessingError> {
+        let config = match requested {
+            Some(config) => Some(config.clone()),
+            None => self
+                .file_repository
+                .find_by_id(file_id)
+                .await
+                .map_err(|e| DocumentProcessingError::RepositoryError(e.to_string()))?
+                .and_then(|file| file.metadata().and_then(|m| m.get_chunking_config())),
+        };
+
+        self.chunking_factory
+            .resolve(&config.unwrap_or_default())
+            .map_err(|e| DocumentProcessingError::ChunkingError(e.to_string()))
+    }
+
+    /// Chunks text as `config` describes; used by every ingestion pipeline.
+    /// Hierarchical configs return the parent chunks first, followed by the
+    /// children that reference them.
+    pub async fn create_chunks(
         &self,
         file_id: Uuid,
         text: &str,
+        config: &ChunkingConfig,
     ) -> Result<Vec<ContentChunk>, DocumentProcessingError> {
-        let mut chunks = Vec::new();
-        let words: Vec<&str> = text.split_whitespace().collect();
+        let strategy = self
+            .chunking_factory
+            .create(config)
+            .map_err(|e| DocumentProcessingError::ChunkingError(e.to_string()))?;
+        let parent_strategy = self
+            .chunking_factory
+            .create_parent(config)
+            .map_err(|e| DocumentProcessingError::ChunkingError(e.to_string()))?;
+
+        let Some(parent_strategy) = parent_strategy else {
+            let pieces = strategy
+                .chunk(text)
+                .await
+                .map_err(|e| DocumentProcessingError::ChunkingError(e.to_string()))?;
+
+            return Ok(pieces
+                .into_iter()
+                .enumerate()
+                .map(|(index, piece)| {
+                    ContentChunk::new(
+                        file_id,
+                        piece.text,
+                        index as i32,
+                        Some(piece.token_count),
+                        None, // Page number - could be extracted from metadata
+                        piece.section_path,
+                    )
+                })
+                .collect());
+        };
+
+        let parents = parent_strategy
+            .chunk(text)
+            .await
+            .map_err(|e| DocumentProcessingError::ChunkingError(e.to_string()))?;
 
-        if words.is_empty() {
-            return Ok(chunks);
-        }
+        let mut chunks = Vec::with_capacity(parents.len());
+        let mut children = Vec::new();
+        for (parent_index, parent) in parents.into_iter().enumerate() {
+            let parent_chunk = ContentChunk::new(
+                file_id,
+                parent.text,
+                parent_index as i32,
+                Some(parent.token_count),
+                None,
+                parent.section_path,
+            );
 
-        let mut start = 0;
-        let mut chunk_index = 0;
+            let pieces = strategy
+                .chunk(parent_chunk.chunk_text())
+                .await
+                .map_err(|e| DocumentProcessingError::ChunkingError(e.to_string()))?;
+            for piece in pieces {
+                let section_path = piece
+                    .section_path
+                    .or_else(|| parent_chunk.section_path().map(String::from));
+                children.push(
+                    ContentChunk::new(
+                        file_id,
+                        piece.text,
+                        children.len() as i32,
+                        Some(piece.token_count),
+                        None,
+                        section_path,
+                    )
+                    .with_parent_chunk_id(Some(parent_chunk.id())),
+                );
+            }
 
-        while start < words.len() {
-            // Calculate end position for this chunk
-            let end = std::cmp::min(start + self.chunk_size, words.len());
+            chunks.push(parent_chunk);
+        }
+        chunks.extend(children);
 
-            // Create chunk text
-            let chunk_text = words[start..end].join(" ");
+        Ok(chunks)
+    }
 
-            // Skip empty or very small chunks
-            if chunk_text.trim().len() < 10 {
-                break;
+    /// Refuses chunks the embedding models would reject or silently
+    /// truncate, measured as they are sent: behind the contextual header
+    /// and inside each model's document instruction. Parent chunks are
+    /// exempt since they are never embedded.
+    pub fn validate_chunk_sizes(
+        &self,
+        chunks: &[ContentChunk],
+        template: EmbeddingTextTemplate,
+        title: Option<&str>,
+    ) -> Result<(), DocumentProcessingError> {
+        for provider in self.embedding_models.providers() {
+            let prompts = provider.prompts();
+            let max_tokens = provider.max_input_length();
+
+            for chunk in ContentChunk::leaves(chunks) {
+                let text =
+                    prompts.render(EmbeddingPurpose::Document, &template.render(title, chunk));
+                let token_count = if text == chunk.chunk_text() {
+                    chunk.token_count().map(|count| count as usize)
+                } else {
+                    Some(self.chunking_factory.count_tokens(&text))
+                };
+
+                if let Some(token_count) = token_count
+                    && token_count > max_tokens
+                {
+                    return Err(DocumentProcessingError::ChunkTooLarge(format!(
+                        "chunk {} has {} tokens as embedded but {} accepts at most {}",
+                        chunk.chunk_index(),
+                        token_count,
+                        provider.model_info().0,
+                        max_tokens
+                    )));
+                }
             }
+        }
 
-            // Create chunk entity
-            let chunk = ContentChunk::new(
-                file_id,
-                chunk_text,
-                chunk_index,
-                Some(end as i32 - start as i32), // Approximate token count
-                None,                            // Page number - could be extracted from metadata
-                None, // Section path - could be extracted from document structure
-            );
+        Ok(())
+    }
+
+    /// Embeds `chunks` with every registered model, and the sparse model if any
+    pub async fn generate_embeddings_for_chunks(
+        &self,
+        chunks: &[&ContentChunk],
+        template: EmbeddingTextTemplate,
+        title: Option<&str>,
+    ) -> Result<GeneratedEmbeddings, DocumentProcessingError> {
+        let mut generated = GeneratedEmbeddings {
+            embeddings: Vec::new(),
+            sparse_embeddings: Vec::new(),
+            cache_hits: 0,
+            derived: 0,
+        };
+        for provider in self.embedding_models.providers() {
+            let model_generated = self
+                .generate_model_embeddings(provider.as_ref(), chunks, template, title)
+                .await?;
+            generated.embeddings.extend(model_generated.embeddings);
+            generated.cache_hits += model_generated.cache_hits;
+        }
+        if let Some((provider, _)) = &self.sparse_embeddings {
+            generated.sparse_embeddings = self
+                .generate_sparse_embeddings(provider.as_ref(), chunks, template, title)
+                .await?;
+        }
 
-            chunks.push(chunk);
-            chunk_index += 1;
+        Ok(generated)
+    }
 
-            // Move start position with overlap
-            start = if end >= words.len() {
-                break;
-            } else {
-                std::cmp::max(start + self.chunk_size - self.chunk_overlap, start + 1)
-            };
+    /// Stores the dense and sparse vectors of `generated`
+    pub async fn save_embeddings(
+        &self,
+        generated: &GeneratedEmbeddings,
+    ) -> Result<(), DocumentProcessingError> {
+        self.embedding_repository
+            .save_batch(&generated.embeddings)
+            .await
+            .map_err(|e| DocumentProcessingError::RepositoryError(e.to_string()))?;
+        if let Some((_, repository)) = &self.sparse_embeddings {
+            repository
+                .save_batch(&generated.sparse_embeddings)
+                .await
+                .map_err(|e| DocumentProcessingError::RepositoryError(e.to_string()))?;
         }
 
-        Ok(chunks)
+        Ok(())
+    }
+
+    /// Term weights for `chunks`, from the same rendered text the dense models see
+    async fn generate_sparse_embeddings(
+        &self,
+        provider: &dyn SparseEmbeddingProvider,
+        chunks: &[&ContentChunk],
+        template: EmbeddingTextTemplate,
+        title: Option<&str>,
+    ) -> Result<Vec<SparseEmbedding>, DocumentProcessingError> {
+        if chunks.is_empty() {
+            return Ok(Vec::new());
+        }
+        let (model_name, model_version) = provider.model_info();
+
+        let response = provider
+            .generate_sparse_embeddings(BatchEmbeddingRequest {
+                texts: chunks
+                    .iter()
+                    .map(|chunk| template.render(title, chunk))
+                    .collect(),
+                purpose: EmbeddingPurpose::Document,
+                model_name: Some(model_name.clone()),
+                model_version,
+            })
+            .await
+            .map_err(|e| DocumentProcessingError::EmbeddingError(e.to_string()))?;
+        if response.embeddings.len() != chunks.len() {
+            return Err(DocumentProcessingError::EmbeddingError(format!(
+                "Expected {} sparse embeddings from {}, got {}",
+                chunks.len(),
+                model_name,
+                response.embeddings.len()
+            )));
+        }
+
+        Ok(chunks
+            .iter()
+            .zip(response.embeddings)
+            .map(|(chunk, embedding)| {
+                SparseEmbedding::new(
+                    chunk.id(),
+                    response.model_name.clone(),
+                    response.model_version.clone(),
+                    embedding,
+                )
+            })
+            .collect())
     }
 
-    async fn generate_embeddings_for_chunks(
+    /// Embeds already stored chunks with `model_name`, each file's chunks
+    /// rendered with the template and title recorded for that file. A
+    /// truncated model takes the chunks its full-size model already embedded
+    /// from the stored vectors; only the rest reach the provider.
+    pub async fn embed_stored_chunks(
         &self,
+        model_name: &str,
         chunks: &[ContentChunk],
-    ) -> Result<Vec<Embedding>, DocumentProcessingError> {
-        let mut embeddings = Vec::new();
-        let (model_name, model_version) = self.embedding_provider.model_info();
+    ) -> Result<GeneratedEmbeddings, DocumentProcessingError> {
+        let provider = self.embedding_models.get(model_name).ok_or_else(|| {
+            DocumentProcessingError::EmbeddingError(format!(
+                "Embedding model {} is not configured",
+                model_name
+            ))
+        })?;
+
+        let mut generated = GeneratedEmbeddings {
+            embeddings: Vec::new(),
+            sparse_embeddings: Vec::new(),
+            cache_hits: 0,
+            derived: 0,
+        };
+        let mut derived_chunks = HashSet::new();
+        if let Some(source_model) = provider.derived_from() {
+            let chunk_ids: Vec<Uuid> = chunks.iter().map(ContentChunk::id).collect();
+            let stored = self
+                .embedding_repository
+                .find_by_chunk_ids(&chunk_ids, &source_model)
+                .await
+                .map_err(|e| DocumentProcessingError::RepositoryError(e.to_string()))?;
+            let (model_name, _) = provider.model_info();
+            for embedding in stored {
+                if !derived_chunks.insert(embedding.content_chunk_id()) {
+                    continue;
+                }
+                generated.embeddings.push(
+                    embedding
+                        .truncated(model_name.clone(), provider.embedding_dimension())
+                        .map_err(DocumentProcessingError::EmbeddingError)?,
+                );
+            }
+            generated.derived = derived_chunks.len();
+        }
 
-        const BATCH_SIZE: usize = 10;
+        let mut by_file: Vec<(Uuid, Vec<&ContentChunk>)> = Vec::new();
+        for chunk in chunks
+            .iter()
+            .filter(|chunk| !derived_chunks.contains(&chunk.id()))
+        {
+            match by_file
+                .iter_mut()
+                .find(|(file_id, _)| *file_id == chunk.file_id())
+            {
+                Some((_, file_chunks)) => file_chunks.push(chunk),
+                None => by_file.push((chunk.file_id(), vec![chunk])),
+            }
+        }
 
-        for chunk_batch in chunks.chunks(BATCH_SIZE) {
-            let texts: Vec<String> = chunk_batch
-                .iter()
-                .map(|chunk| chunk.chunk_text().to_string())
-                .collect();
+        for (file_id, file_chunks) in by_file {
+            let file = self
+                .file_repository
+                .find_by_id(file_id)
+                .await
+                .map_err(|e| DocumentProcessingError::RepositoryError(e.to_string()))?;
+            let template = self
+                .resolve_chunking_config(file_id, None)
+                .await?
+                .embedding_template();
+            let title = file
+                .as_ref()
+                .and_then(|file| file.metadata())
+                .and_then(|metadata| metadata.get_title());
+
+            let file_generated = self
+                .generate_model_embeddings(
+                    provider.as_ref(),
+                    &file_chunks,
+                    template,
+                    title.as_deref(),
+                )
+                .await?;
+            generated.embeddings.extend(file_generated.embeddings);
+            generated.cache_hits += file_generated.cache_hits;
+        }
+
+        Ok(generated)
+    }
+
+    /// Embeds `chunks` with one model, reusing cached vectors where the same
+    /// input text was already embedded by it; only the misses reach the provider
+    pub async fn generate_model_embeddings(
+        &self,
+        provider: &dyn EmbeddingProvider,
+        chunks: &[&ContentChunk],
+        template: EmbeddingTextTemplate,
+        title: Option<&str>,
+    ) -> Result<GeneratedEmbeddings, DocumentProcessingError> {
+        let (model_name, model_version) = provider.model_info();
+        let mut generation_parameters = template.generation_parameters();
+
+        // The instruction changes the vector as much as the text does, so it
+        // is part of both the cache key and the recorded parameters
+        let instruction = provider.prompts().instruction(EmbeddingPurpose::Document);
+        let key_template = match &instruction {
+            Some(instruction) => format!("{}\n{}", instruction, template.template()),
+            None => template.template().to_string(),
+        };
+        if let Some(instruction) = instruction {
+            generation_parameters["instruction"] = serde_json::Value::String(instruction);
+        }
 
+        let texts: Vec<String> = chunks
+            .iter()
+            .map(|chunk| template.render(title, chunk))
+            .collect();
+        let keys: Vec<EmbeddingCacheKey> = texts
+            .iter()
+            .map(|text| {
+                EmbeddingCacheKey::new(text, &model_name, model_version.as_deref(), &key_template)
+            })
+            .collect();
+
+        let mut vectors: HashMap<EmbeddingCacheKey, Vector> = match &self.embedding_cache {
+            Some(cache) => match cache.find_many(&keys).await {
+                Ok(entries) => entries.into_iter().collect(),
+                Err(e) => {
+                    // A cold cache only costs time, so carry on without it
+                    eprintln!("Embedding cache lookup failed: {}", e);
+                    HashMap::new()
+                }
+            },
+            None => HashMap::new(),
+        };
+
+        // Identical chunks within the document are embedded once
+        let mut misses: Vec<(&EmbeddingCacheKey, &String)> = Vec::new();
+        let mut queued = HashSet::new();
+        for (key, text) in keys.iter().zip(texts.iter()) {
+            if !vectors.contains_key(key) && queued.insert(key) {
+                misses.push((key, text));
+            }
+        }
+        let cache_hits = chunks.len() - misses.len();
+
+        // Providers batch, pace and retry the request themselves
+        let mut generated = Vec::new();
+        if !misses.is_empty() {
             let batch_request = BatchEmbeddingRequest {
-                texts,
+                texts: misses.iter().map(|(_, text)| text.to_string()).collect(),
+                purpose: EmbeddingPurpose::Document,
                 model_name: Some(model_name.clone()),
                 model_version: model_version.clone(),
             };
 
-            let batch_response = self
-                .embedding_provider
+            let batch_response = provider
                 .generate_embeddings(batch_request)
                 .await
                 .map_err(|e| DocumentProcessingError::EmbeddingError(e.to_string()))?;
+            if batch_response.embeddings.len() != misses.len() {
+                return Err(DocumentProcessingError::EmbeddingError(format!(
+                    "Expected {} embeddings from {}, got {}",
+                    misses.len(),
+                    model_name,
+                    batch_response.embeddings.len()
+                )));
+            }
 
-            for (chunk, embedding_vector) in
-                chunk_batch.iter().zip(batch_response.embeddings.iter())
-            {
-                let embedding = Embedding::new(
-                    chunk.id(),
-                    batch_response.model_name.clone(),
-                    batch_response.model_version.clone(),
-                    None,
-                    embedding_vector.clone(),
-                );
+            generated = misses
+                .iter()
+                .map(|(key, _)| (*key).clone())
+                .zip(batch_response.embeddings)
+                .collect();
+        }
 
-                embeddings.push(embedding);
-            }
+        if let Some(cache) = &self.embedding_cache
+            && let Err(e) = cache.save_many(&generated).await
+        {
+            eprintln!("Failed to store embeddings in cache: {}", e);
         }
+        vectors.extend(generated);
+
+        let embeddings = chunks
+            .iter()
+            .zip(keys.iter())
+            .filter_map(|(chunk, key)| {
+                vectors.get(key).map(|vector| {
+                    Embedding::new(
+                        chunk.id(),
+                        model_name.clone(),
+                        model_version.clone(),
+                        Some(generation_parameters.clone()),
+                        vector.clone(),
+                    )
+                })
+            })
+            .collect();
+
+        Ok(GeneratedEmbeddings {
+            embeddings,
+            sparse_embeddings: Vec::new(),
+            cache_hits,
+            derived: 0,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::database::in_memory::InMemoryDatabase;
+    use crate::infrastructure::database::repositories::{
+        InMemoryChunkRepository, InMemoryEmbeddingRepository, InMemoryFileRepository,
+    };
+    use crate::infrastructure::external_services::chunking::ConfiguredChunkingFactory;
+    use crate::infrastructure::external_services::document_extractors::CompositeDocumentExtractor;
+    use crate::infrastructure::external_services::tokenization::WhitespaceTokenizer;
+    use crate::infrastructure::external_services::{HashEmbeddingProvider, WhatlangDetector};
+
+    fn processor() -> DocumentProcessorService {
+        let provider: Arc<dyn EmbeddingProvider> =
+            Arc::new(HashEmbeddingProvider::new("hash-embedding", 16));
+        let database = InMemoryDatabase::new();
+        DocumentProcessorService::new(
+            Arc::new(CompositeDocumentExtractor::new().unwrap()),
+            Arc::new(EmbeddingModelRegistry::new(provider.clone())),
+            Arc::new(InMemoryChunkRepository::new(database.clone())),
+            Arc::new(InMemoryEmbeddingRepository::new(database.clone())),
+            Arc::new(InMemoryFileRepository::new(database)),
+            Arc::new(WhatlangDetector::new()),
+            Arc::new(
+                ConfiguredChunkingFactory::new(
+                    "token_window",
+                    Arc::new(WhitespaceTokenizer::new()),
+                    provider.max_input_length(),
+                    provider,
+                )
+                .unwrap(),
+            ),
+        )
+    }
 
-        Ok(embeddings)
+    #[tokio::test]
+    async fn test_validation_counts_the_contextual_header() {
+        let processor = processor();
+        let file_id = Uuid::new_v4();
+        let max_tokens = processor.embedding_models.max_input_length();
+
+        let full_size = ContentChunk::new(
+            file_id,
+            "word ".repeat(max_tokens).trim_end().to_string(),
+            0,
+            Some(max_tokens as i32),
+            None,
+            Some("Guide > Install".to_string()),
+        );
+        let chunks = [full_size];
+        assert!(
+            processor
+                .validate_chunk_sizes(&chunks, EmbeddingTextTemplate::ChunkOnly, Some("Manual"))
+                .is_ok()
+        );
+        assert!(matches!(
+            processor.validate_chunk_sizes(
+                &chunks,
+                EmbeddingTextTemplate::ContextualHeader,
+                Some("Manual")
+            ),
+            Err(DocumentProcessingError::ChunkTooLarge(_))
+        ));
+
+        // Default sizes leave room for the header
+        let config = processor
+            .resolve_chunking_config(
+                file_id,
+                Some(&ChunkingConfig {
+                    contextual_headers: true,
+                    ..Default::default()
+                }),
+            )
+            .await
+            .unwrap();
+        let chunks = processor
+            .create_chunks(file_id, &"word ".repeat(3 * max_tokens), &config)
+            .await
+            .unwrap();
+        assert!(
+            processor
+                .validate_chunk_sizes(&chunks, config.embedding_template(), Some("Manual"))
+                .is_ok()
+        );
     }
 }
@@ -0,0 +1,81 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::application::ports::EmbeddingProvider;
+
+/// The embedding models documents are indexed with, keyed by model name.
+/// Every model embeds each document; searches pick one, defaulting to the
+/// active model, which starts out as the first registered.
+pub struct EmbeddingModelRegistry {
+    providers: Vec<Arc<dyn EmbeddingProvider>>,
+    active: AtomicUsize,
+}
+
+impl EmbeddingModelRegistry {
+    pub fn new(default_provider: Arc<dyn EmbeddingProvider>) -> Self {
+        Self {
+            providers: vec![default_provider],
+            active: AtomicUsize::new(0),
+        }
+    }
+
+    /// Adds a model, replacing any registered under the same name
+    pub fn with_model(mut self, provider: Arc<dyn EmbeddingProvider>) -> Self {
+        let (name, _) = provider.model_info();
+        match self.position(&name) {
+            Some(index) => self.providers[index] = provider,
+            None => self.providers.push(provider),
+        }
+        self
+    }
+
+    pub fn active_provider(&self) -> Arc<dyn EmbeddingProvider> {
+        self.providers[self.active.load(Ordering::Acquire)].clone()
+    }
+
+    pub fn active_model(&self) -> String {
+        self.active_provider().model_info().0
+    }
+
+    /// Makes searches default to `model_name`; false if it is not registered
+    pub fn set_active_model(&self, model_name: &str) -> bool {
+        match self.position(model_name) {
+            Some(index) => {
+                self.active.store(index, Ordering::Release);
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn get(&self, model_name: &str) -> Option<Arc<dyn EmbeddingProvider>> {
+        self.position(model_name)
+            .map(|index| self.providers[index].clone())
+    }
+
+    pub fn providers(&self) -> &[Arc<dyn EmbeddingProvider>] {
+        &self.providers
+    }
+
+    pub fn model_names(&self) -> Vec<String> {
+        self.providers
+            .iter()
+            .map(|provider| provider.model_info().0)
+            .collect()
+    }
+
+    /// Chunks are shared by every model, so they must fit the smallest input
+    pub fn max_input_length(&self) -> usize {
+        self.providers
+            .iter()
+            .map(|provider| provider.max_input_length())
+            .min()
+            .unwrap_or_default()
+    }
+
+    fn position(&self, model_name: &str) -> Option<usize> {
+        self.providers
+            .iter()
+            .position(|provider| provider.model_info().0 == model_name)
+    }
+}
@@ -4,6 +4,7 @@ use crate::application::ports::embedding_provider::{
     BatchEmbeddingRequest, EmbeddingProvider, EmbeddingRequest,
 };
 use crate::domain::entities::{ContentChunk, Embedding};
+use crate::domain::value_objects::EmbeddingPurpose;
 
 #[derive(Debug)]
 pub enum EmbeddingServiceError {
@@ -43,6 +44,7 @@ impl EmbeddingService {
 
         let request = EmbeddingRequest {
             text: chunk.chunk_text().to_string(),
+            purpose: EmbeddingPurpose::Document,
             model_name: None,
             model_version: None,
         };
@@ -85,6 +87,7 @@ impl EmbeddingService {
 
         let request = BatchEmbeddingRequest {
             texts,
+            purpose: EmbeddingPurpose::Document,
             model_name: None,
             model_version: None,
         };
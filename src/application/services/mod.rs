@@ -1,7 +1,9 @@
 pub mod document_processor;
+pub mod embedding_model_registry;
 pub mod embedding_service;
 pub mod search_service;
 
 pub use document_processor::DocumentProcessorService;
+pub use embedding_model_registry::EmbeddingModelRegistry;
 pub use embedding_service::EmbeddingService;
 pub use search_service::SearchService;
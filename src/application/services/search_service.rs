@@ -1,14 +1,27 @@
+use pgvector::Vector;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use uuid::Uuid;
 
-use crate::application::ports::embedding_provider::{EmbeddingProvider, EmbeddingRequest};
+use crate::application::ports::embedding_provider::{BatchEmbeddingRequest, EmbeddingRequest};
+use crate::application::ports::translation_provider::TranslationError;
+use crate::application::ports::{LanguageDetector, SparseEmbeddingProvider, TranslationProvider};
+use crate::application::services::EmbeddingModelRegistry;
 use crate::application::use_cases::search_content::SearchResult;
-use crate::domain::repositories::{ChunkRepository, EmbeddingRepository};
+use crate::domain::repositories::{
+    ChunkRepository, EmbeddingRepository, SparseEmbeddingRepository,
+    chunk_repository::ChunkIndexRange, embedding_repository::SimilaritySearchFilter,
+};
+use crate::domain::value_objects::{ChunkContext, EmbeddingPurpose, LanguageCode};
+
+/// Rank constant for reciprocal rank fusion; dampens the weight of the top ranks
+const RRF_K: f32 = 60.0;
 
 #[derive(Debug)]
 pub enum SearchServiceError {
     EmbeddingError(String),
     RepositoryError(String),
+    UnknownModel(String),
 }
 
 impl std::fmt::Display for SearchServiceError {
@@ -16,67 +29,537 @@ impl std::fmt::Display for SearchServiceError {
         match self {
             SearchServiceError::EmbeddingError(msg) => write!(f, "Embedding error: {}", msg),
             SearchServiceError::RepositoryError(msg) => write!(f, "Repository error: {}", msg),
+            SearchServiceError::UnknownModel(name) => {
+                write!(f, "Unknown embedding model: {}", name)
+            }
         }
     }
 }
 
 impl std::error::Error for SearchServiceError {}
 
+/// Which vectors a search ranks by
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RetrievalMode {
+    #[default]
+    Dense,
+    /// Term weights only; matches exact words, names and codes
+    Sparse,
+    /// Dense and sparse rankings fused; `similarity_score` becomes the fused score
+    Hybrid,
+}
+
+impl RetrievalMode {
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value.trim().to_lowercase().as_str() {
+            "dense" => Ok(RetrievalMode::Dense),
+            "sparse" => Ok(RetrievalMode::Sparse),
+            "hybrid" => Ok(RetrievalMode::Hybrid),
+            other => Err(format!(
+                "Unknown retrieval mode '{}'; expected dense, sparse or hybrid",
+                other
+            )),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RetrievalMode::Dense => "dense",
+            RetrievalMode::Sparse => "sparse",
+            RetrievalMode::Hybrid => "hybrid",
+        }
+    }
+
+    pub fn uses_sparse(&self) -> bool {
+        *self != RetrievalMode::Dense
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CrossLingualOptions {
+    /// How many of the corpus' most common languages to search
+    pub max_languages: usize,
+    pub translate_query: bool,
+}
+
+impl Default for CrossLingualOptions {
+    fn default() -> Self {
+        Self {
+            max_languages: 3,
+            translate_query: true,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CrossLingualResults {
+    pub results: Vec<SearchResult>,
+    pub query_language: Option<String>,
+    /// Query text used for each searched language
+    pub queries: Vec<(String, String)>,
+}
+
 pub struct SearchService {
-    embedding_provider: Arc<dyn EmbeddingProvider>,
+    embedding_models: Arc<EmbeddingModelRegistry>,
     embedding_repository: Arc<dyn EmbeddingRepository>,
     chunk_repository: Arc<dyn ChunkRepository>,
+    language_detector: Arc<dyn LanguageDetector>,
+    translation_provider: Arc<dyn TranslationProvider>,
+    sparse: Option<SparseRetrieval>,
+}
+
+struct SparseRetrieval {
+    provider: Arc<dyn SparseEmbeddingProvider>,
+    repository: Arc<dyn SparseEmbeddingRepository>,
 }
 
 impl SearchService {
     pub fn new(
-        embedding_provider: Arc<dyn EmbeddingProvider>,
+        embedding_models: Arc<EmbeddingModelRegistry>,
         embedding_repository: Arc<dyn EmbeddingRepository>,
         chunk_repository: Arc<dyn ChunkRepository>,
+        language_detector: Arc<dyn LanguageDetector>,
+        translation_provider: Arc<dyn TranslationProvider>,
     ) -> Self {
         Self {
-            embedding_provider,
+            embedding_models,
             embedding_repository,
             chunk_repository,
+            language_detector,
+            translation_provider,
+            sparse: None,
+        }
+    }
+
+    pub fn with_sparse_embeddings(
+        mut self,
+        provider: Arc<dyn SparseEmbeddingProvider>,
+        repository: Arc<dyn SparseEmbeddingRepository>,
+    ) -> Self {
+        self.sparse = Some(SparseRetrieval {
+            provider,
+            repository,
+        });
+        self
+    }
+
+    /// The sparse model searches can use, if one is configured
+    pub fn sparse_model(&self) -> Option<String> {
+        self.sparse
+            .as_ref()
+            .map(|sparse| sparse.provider.model_info().0)
+    }
+
+    /// Name of the model a search runs against: `requested` when it is
+    /// registered, else the default model
+    pub fn resolve_model(&self, requested: Option<&str>) -> Result<String, SearchServiceError> {
+        match requested {
+            Some(name) if self.embedding_models.get(name).is_some() => Ok(name.to_string()),
+            Some(name) => Err(SearchServiceError::UnknownModel(name.to_string())),
+            None => Ok(self.embedding_models.active_model()),
         }
     }
 
+    pub fn model_names(&self) -> Vec<String> {
+        self.embedding_models.model_names()
+    }
+
+    /// Embeds the query with the model `filter` names (the default model when
+    /// it names none) and searches only that model's vectors. Sparse vectors
+    /// have unbounded scores, so the threshold only applies to dense ones.
     pub async fn search_content(
         &self,
         query: &str,
         limit: i32,
         similarity_threshold: Option<f32>,
-        file_id_filter: Option<Uuid>,
+        filter: SimilaritySearchFilter,
+        mode: RetrievalMode,
+    ) -> Result<Vec<SearchResult>, SearchServiceError> {
+        let filter = self.with_model(filter)?;
+        self.search_with_mode(query, limit, similarity_threshold, &filter, mode)
+            .await
+    }
+
+    /// Searches each of the corpus' top languages separately, translating the
+    /// query where needed, and fuses the per-language rankings so no single
+    /// language crowds out the others.
+    pub async fn search_cross_lingual(
+        &self,
+        query: &str,
+        limit: i32,
+        similarity_threshold: Option<f32>,
+        filter: SimilaritySearchFilter,
+        options: CrossLingualOptions,
+        mode: RetrievalMode,
+    ) -> Result<CrossLingualResults, SearchServiceError> {
+        let filter = self.with_model(filter)?;
+        let query_language = self
+            .language_detector
+            .detect(query)
+            .map(|detected| detected.language);
+
+        let corpus_languages = self
+            .chunk_repository
+            .count_by_language()
+            .await
+            .map_err(|e| SearchServiceError::RepositoryError(e.to_string()))?;
+
+        let mut languages: Vec<String> = corpus_languages
+            .into_iter()
+            .map(|(language, _)| language)
+            .filter(|language| filter.languages.is_empty() || filter.languages.contains(language))
+            .collect();
+
+        // The query's own language is always searched when the corpus has it
+        if let Some(query_language) = &query_language
+            && let Some(position) = languages.iter().position(|l| l == query_language.as_str())
+        {
+            let own = languages.remove(position);
+            languages.insert(0, own);
+        }
+        languages.truncate(options.max_languages.max(1));
+
+        if languages.is_empty() {
+            let results = self
+                .search_content(query, limit, similarity_threshold, filter, mode)
+                .await?;
+            return Ok(CrossLingualResults {
+                results,
+                query_language: query_language.map(String::from),
+                queries: Vec::new(),
+            });
+        }
+
+        let mut queries = Vec::new();
+        let mut ranked_lists = Vec::new();
+        for language in languages {
+            let language_query = if options.translate_query {
+                self.translate_query(query, query_language.as_ref(), &language)
+                    .await
+            } else {
+                query.to_string()
+            };
+
+            let language_filter = SimilaritySearchFilter {
+                languages: vec![language.clone()],
+                ..filter.clone()
+            };
+            ranked_lists.push(
+                self.search_with_mode(
+                    &language_query,
+                    limit,
+                    similarity_threshold,
+                    &language_filter,
+                    mode,
+                )
+                .await?,
+            );
+            queries.push((language, language_query));
+        }
+
+        Ok(CrossLingualResults {
+            results: fuse_rankings(ranked_lists, limit.max(0) as usize),
+            query_language: query_language.map(String::from),
+            queries,
+        })
+    }
+
+    /// Attaches each result's parent chunk and keeps only the best-ranked
+    /// child per parent, so the same context is not returned twice
+    pub async fn expand_parents(
+        &self,
+        results: Vec<SearchResult>,
+        limit: usize,
+    ) -> Result<Vec<SearchResult>, SearchServiceError> {
+        let mut seen_parents = HashSet::new();
+        let mut expanded = Vec::new();
+
+        for mut result in results {
+            if expanded.len() >= limit {
+                break;
+            }
+
+            if let Some(parent_id) = result.chunk.parent_chunk_id() {
+                if !seen_parents.insert(parent_id) {
+                    continue;
+                }
+                result.parent_chunk = self
+                    .chunk_repository
+                    .find_by_id(parent_id)
+                    .await
+                    .map_err(|e| SearchServiceError::RepositoryError(e.to_string()))?;
+            }
+            expanded.push(result);
+        }
+
+        Ok(expanded)
+    }
+
+    /// Attaches up to `window` neighbouring chunks on each side of every
+    /// result. Hits whose windows overlap in the same file collapse into one
+    /// result at the best-ranked hit, carrying every hit's offsets.
+    pub async fn expand_context(
+        &self,
+        results: Vec<SearchResult>,
+        window: usize,
+    ) -> Result<Vec<SearchResult>, SearchServiceError> {
+        if window == 0 || results.is_empty() {
+            return Ok(results);
+        }
+        let window = window as i32;
+
+        let ranges: Vec<ChunkIndexRange> = results
+            .iter()
+            .map(|result| ChunkIndexRange {
+                file_id: result.file_id,
+                start: (result.chunk.chunk_index() - window).max(0),
+                end: result.chunk.chunk_index() + window,
+            })
+            .collect();
+        let neighbours = self
+            .chunk_repository
+            .find_by_index_ranges(&ranges)
+            .await
+            .map_err(|e| SearchServiceError::RepositoryError(e.to_string()))?;
+
+        // Parents and children number their chunks separately, so windows
+        // only merge within the same file and level
+        let level =
+            |result: &SearchResult| (result.file_id, result.chunk.parent_chunk_id().is_some());
+        let mut by_level: HashMap<(Uuid, bool), Vec<usize>> = HashMap::new();
+        for (rank, result) in results.iter().enumerate() {
+            by_level.entry(level(result)).or_default().push(rank);
+        }
+
+        // Each cluster is a run of ranks whose windows touch, in index order
+        let mut clusters: Vec<Vec<usize>> = Vec::new();
+        for mut ranks in by_level.into_values() {
+            ranks.sort_by_key(|rank| ranges[*rank].start);
+            let mut current: Vec<usize> = Vec::new();
+            let mut current_end = i32::MIN;
+            for rank in ranks {
+                if !current.is_empty() && ranges[rank].start > current_end + 1 {
+                    clusters.push(std::mem::take(&mut current));
+                }
+                current_end = current_end.max(ranges[rank].end);
+                current.push(rank);
+            }
+            clusters.push(current);
+        }
+        for cluster in &mut clusters {
+            cluster.sort_unstable();
+        }
+        clusters.sort_by_key(|cluster| cluster[0]);
+
+        let mut results: Vec<Option<SearchResult>> = results.into_iter().map(Some).collect();
+        let mut expanded = Vec::with_capacity(clusters.len());
+        for cluster in clusters {
+            let Some(mut best) = results[cluster[0]].take() else {
+                continue;
+            };
+            let start = cluster
+                .iter()
+                .map(|rank| ranges[*rank].start)
+                .min()
+                .unwrap_or(0);
+            let end = cluster
+                .iter()
+                .map(|rank| ranges[*rank].end)
+                .max()
+                .unwrap_or(0);
+            let is_child = best.chunk.parent_chunk_id().is_some();
+            let chunks: Vec<_> = neighbours
+                .iter()
+                .filter(|chunk| {
+                    chunk.file_id() == best.file_id
+                        && chunk.parent_chunk_id().is_some() == is_child
+                        && (start..=end).contains(&chunk.chunk_index())
+                })
+                .cloned()
+                .collect();
+
+            let mut hit_ids = vec![best.chunk.id()];
+            for rank in &cluster[1..] {
+                if let Some(other) = results[*rank].take() {
+                    hit_ids.push(other.chunk.id());
+                }
+            }
+            best.context = ChunkContext::stitch(&chunks, &hit_ids);
+            expanded.push(best);
+        }
+
+        Ok(expanded)
+    }
+
+    async fn translate_query(
+        &self,
+        query: &str,
+        query_language: Option<&LanguageCode>,
+        target: &str,
+    ) -> String {
+        let Ok(target) = LanguageCode::parse(target) else {
+            return query.to_string();
+        };
+        if query_language == Some(&target) {
+            return query.to_string();
+        }
+
+        match self
+            .translation_provider
+            .translate(query, query_language, &target)
+            .await
+        {
+            Ok(translated) => translated,
+            // Fall back to the original query; multilingual embeddings still match
+            Err(TranslationError::UnsupportedLanguage(_)) => query.to_string(),
+        }
+    }
+
+    /// Vectors of different models are not comparable, so every search is
+    /// pinned to exactly one model
+    fn with_model(
+        &self,
+        filter: SimilaritySearchFilter,
+    ) -> Result<SimilaritySearchFilter, SearchServiceError> {
+        let model_name = self.resolve_model(filter.model_name.as_deref())?;
+        Ok(SimilaritySearchFilter {
+            model_name: Some(model_name),
+            ..filter
+        })
+    }
+
+    async fn search_with_mode(
+        &self,
+        query: &str,
+        limit: i32,
+        similarity_threshold: Option<f32>,
+        filter: &SimilaritySearchFilter,
+        mode: RetrievalMode,
     ) -> Result<Vec<SearchResult>, SearchServiceError> {
-        // Generate embedding for the query
+        match mode {
+            RetrievalMode::Dense => {
+                let query_vector = self.embed_query(query, filter).await?;
+                self.retrieve(&query_vector, limit, similarity_threshold, filter)
+                    .await
+            }
+            RetrievalMode::Sparse => self.retrieve_sparse(query, limit, filter).await,
+            RetrievalMode::Hybrid => {
+                let query_vector = self.embed_query(query, filter).await?;
+                let (dense, sparse) = futures::try_join!(
+                    self.retrieve(&query_vector, limit, similarity_threshold, filter),
+                    self.retrieve_sparse(query, limit, filter),
+                )?;
+                Ok(fuse_scored(vec![dense, sparse], limit.max(0) as usize)
+                    .into_iter()
+                    .map(|(score, mut result)| {
+                        result.similarity_score = score;
+                        result
+                    })
+                    .collect())
+            }
+        }
+    }
+
+    async fn retrieve_sparse(
+        &self,
+        query: &str,
+        limit: i32,
+        filter: &SimilaritySearchFilter,
+    ) -> Result<Vec<SearchResult>, SearchServiceError> {
+        let sparse = self.sparse.as_ref().ok_or_else(|| {
+            SearchServiceError::EmbeddingError(
+                "No sparse embedding model is configured".to_string(),
+            )
+        })?;
+        let (model_name, model_version) = sparse.provider.model_info();
+
+        let response = sparse
+            .provider
+            .generate_sparse_embeddings(BatchEmbeddingRequest {
+                texts: vec![query.to_string()],
+                purpose: EmbeddingPurpose::Query,
+                model_name: Some(model_name.clone()),
+                model_version,
+            })
+            .await
+            .map_err(|e| SearchServiceError::EmbeddingError(e.to_string()))?;
+        let query_vector = response.embeddings.into_iter().next().ok_or_else(|| {
+            SearchServiceError::EmbeddingError("No sparse embedding returned".to_string())
+        })?;
+
+        let sparse_filter = SimilaritySearchFilter {
+            model_name: Some(model_name),
+            ..filter.clone()
+        };
+        let matches = sparse
+            .repository
+            .search(&query_vector, limit, &sparse_filter)
+            .await
+            .map_err(|e| SearchServiceError::RepositoryError(e.to_string()))?;
+
+        let mut results = Vec::new();
+        for sparse_match in matches {
+            if let Ok(Some(chunk)) = self
+                .chunk_repository
+                .find_by_id(sparse_match.chunk_id)
+                .await
+            {
+                results.push(SearchResult {
+                    file_id: chunk.file_id(),
+                    source_language: chunk.language().map(|l| l.to_string()),
+                    chunk,
+                    similarity_score: sparse_match.score,
+                    parent_chunk: None,
+                    context: None,
+                });
+            }
+        }
+
+        Ok(results)
+    }
+
+    async fn embed_query(
+        &self,
+        query: &str,
+        filter: &SimilaritySearchFilter,
+    ) -> Result<Vector, SearchServiceError> {
+        let provider = match filter.model_name.as_deref() {
+            Some(name) => self
+                .embedding_models
+                .get(name)
+                .ok_or_else(|| SearchServiceError::UnknownModel(name.to_string()))?,
+            None => self.embedding_models.active_provider(),
+        };
+        let (model_name, model_version) = provider.model_info();
+
         let embedding_request = EmbeddingRequest {
             text: query.to_string(),
-            model_name: None, // Use default model
-            model_version: None,
+            purpose: EmbeddingPurpose::Query,
+            model_name: Some(model_name),
+            model_version,
         };
 
-        let embedding_response = self
-            .embedding_provider
+        let embedding_response = provider
             .generate_embedding(embedding_request)
             .await
             .map_err(|e| SearchServiceError::EmbeddingError(e.to_string()))?;
 
+        Ok(embedding_response.embedding)
+    }
+
+    async fn retrieve(
+        &self,
+        query_vector: &Vector,
+        limit: i32,
+        similarity_threshold: Option<f32>,
+        filter: &SimilaritySearchFilter,
+    ) -> Result<Vec<SearchResult>, SearchServiceError> {
         // Perform similarity search
-        let similarity_results = if let Some(file_id) = file_id_filter {
-            self.embedding_repository
-                .similarity_search_by_file(
-                    &embedding_response.embedding,
-                    file_id,
-                    limit,
-                    similarity_threshold,
-                )
-                .await
-        } else {
-            self.embedding_repository
-                .similarity_search(&embedding_response.embedding, limit, similarity_threshold)
-                .await
-        }
-        .map_err(|e| SearchServiceError::RepositoryError(e.to_string()))?;
+        let similarity_results = self
+            .embedding_repository
+            .similarity_search_filtered(query_vector, limit, similarity_threshold, filter)
+            .await
+            .map_err(|e| SearchServiceError::RepositoryError(e.to_string()))?;
 
         // Get the corresponding chunks
         let mut results = Vec::new();
@@ -87,9 +570,12 @@ impl SearchService {
                 .await
             {
                 results.push(SearchResult {
+                    file_id: chunk.file_id(),
+                    source_language: chunk.language().map(|l| l.to_string()),
                     chunk,
                     similarity_score: similarity_result.similarity_score,
-                    file_id: similarity_result.chunk_id, // This should be the file_id from the chunk
+                    parent_chunk: None,
+                    context: None,
                 });
             }
         }
@@ -97,3 +583,170 @@ impl SearchService {
         Ok(results)
     }
 }
+
+/// Reciprocal rank fusion: each list contributes 1 / (k + rank) per hit, so
+/// the best hit of every language ranks level regardless of raw scores
+fn fuse_rankings(ranked_lists: Vec<Vec<SearchResult>>, limit: usize) -> Vec<SearchResult> {
+    fuse_scored(ranked_lists, limit)
+        .into_iter()
+        .map(|(_, result)| result)
+        .collect()
+}
+
+/// Fused results best first, each with its reciprocal rank fusion score
+fn fuse_scored(ranked_lists: Vec<Vec<SearchResult>>, limit: usize) -> Vec<(f32, SearchResult)> {
+    let mut fused: HashMap<Uuid, (f32, SearchResult)> = HashMap::new();
+
+    for list in ranked_lists {
+        for (rank, result) in list.into_iter().enumerate() {
+            let score = 1.0 / (RRF_K + rank as f32 + 1.0);
+            fused
+                .entry(result.chunk.id())
+                .and_modify(|(total, best)| {
+                    *total += score;
+                    if result.similarity_score > best.similarity_score {
+                        *best = result.clone();
+                    }
+                })
+                .or_insert((score, result));
+        }
+    }
+
+    let mut fused: Vec<(f32, SearchResult)> = fused.into_values().collect();
+    fused.sort_by(|(a_score, a), (b_score, b)| {
+        b_score
+            .total_cmp(a_score)
+            .then(b.similarity_score.total_cmp(&a.similarity_score))
+    });
+    fused.truncate(limit);
+    fused
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::ports::EmbeddingProvider;
+    use crate::domain::entities::{ContentChunk, Embedding, File};
+    use crate::domain::repositories::FileRepository;
+    use crate::infrastructure::database::in_memory::InMemoryDatabase;
+    use crate::infrastructure::database::repositories::{
+        InMemoryChunkRepository, InMemoryEmbeddingRepository, InMemoryFileRepository,
+    };
+    use crate::infrastructure::external_services::{
+        HashEmbeddingProvider, MockTranslationProvider, WhatlangDetector,
+    };
+
+    const QUERY: &str = "ownership and borrowing";
+
+    /// Two models of the same dimension, each with one chunk embedded from
+    /// the query text, so only the model pin tells their vectors apart
+    async fn two_model_search() -> (SearchService, Arc<EmbeddingModelRegistry>, [Uuid; 2]) {
+        let models: [Arc<dyn EmbeddingProvider>; 2] = [
+            Arc::new(HashEmbeddingProvider::new("model-a", 8)),
+            Arc::new(HashEmbeddingProvider::new("model-b", 8)),
+        ];
+        let registry =
+            Arc::new(EmbeddingModelRegistry::new(models[0].clone()).with_model(models[1].clone()));
+        let database = InMemoryDatabase::new();
+        let chunk_repository = Arc::new(InMemoryChunkRepository::new(database.clone()));
+        let embedding_repository = Arc::new(InMemoryEmbeddingRepository::new(database.clone()));
+        let file_id = InMemoryFileRepository::new(database)
+            .save(&File::new(
+                "notes.txt".to_string(),
+                "notes.txt".to_string(),
+                None,
+                None,
+                None,
+                None,
+            ))
+            .await
+            .unwrap();
+
+        let mut chunk_ids = [Uuid::nil(); 2];
+        for (provider, chunk_id) in models.iter().zip(chunk_ids.iter_mut()) {
+            let (model_name, _) = provider.model_info();
+            embedding_repository
+                .register_model(&model_name, provider.embedding_dimension())
+                .await
+                .unwrap();
+            let chunk = ContentChunk::new(file_id, QUERY.to_string(), 0, None, None, None);
+            let vector = provider
+                .generate_embedding(EmbeddingRequest {
+                    text: QUERY.to_string(),
+                    purpose: EmbeddingPurpose::Query,
+                    model_name: Some(model_name.clone()),
+                    model_version: None,
+                })
+                .await
+                .unwrap()
+                .embedding;
+            chunk_repository
+                .save_batch(std::slice::from_ref(&chunk))
+                .await
+                .unwrap();
+            embedding_repository
+                .save(&Embedding::new(chunk.id(), model_name, None, None, vector))
+                .await
+                .unwrap();
+            *chunk_id = chunk.id();
+        }
+
+        let service = SearchService::new(
+            registry.clone(),
+            embedding_repository,
+            chunk_repository,
+            Arc::new(WhatlangDetector::new()),
+            Arc::new(MockTranslationProvider::new()),
+        );
+        (service, registry, chunk_ids)
+    }
+
+    async fn hits(service: &SearchService, model: Option<&str>) -> Vec<Uuid> {
+        let filter = SimilaritySearchFilter {
+            model_name: model.map(str::to_string),
+            ..Default::default()
+        };
+        service
+            .search_content(QUERY, 10, None, filter, RetrievalMode::Dense)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|result| result.chunk.id())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_searches_default_to_the_active_model() {
+        let (service, registry, [chunk_a, chunk_b]) = two_model_search().await;
+        assert_eq!(service.model_names(), ["model-a", "model-b"]);
+
+        assert_eq!(hits(&service, None).await, [chunk_a]);
+        assert!(registry.set_active_model("model-b"));
+        assert_eq!(hits(&service, None).await, [chunk_b]);
+        assert!(!registry.set_active_model("model-c"));
+        assert_eq!(registry.active_model(), "model-b");
+    }
+
+    #[tokio::test]
+    async fn test_pinned_searches_only_hit_that_models_vectors() {
+        let (service, _, [chunk_a, chunk_b]) = two_model_search().await;
+
+        assert_eq!(hits(&service, Some("model-a")).await, [chunk_a]);
+        assert_eq!(hits(&service, Some("model-b")).await, [chunk_b]);
+        assert!(matches!(
+            service
+                .search_content(
+                    QUERY,
+                    10,
+                    None,
+                    SimilaritySearchFilter {
+                        model_name: Some("model-c".to_string()),
+                        ..Default::default()
+                    },
+                    RetrievalMode::Dense,
+                )
+                .await,
+            Err(SearchServiceError::UnknownModel(_))
+        ));
+    }
+}
@@ -3,11 +3,15 @@ pub mod get_file;
 pub mod get_file_chunks;
 pub mod get_job_status;
 pub mod list_files;
+pub mod poll_feed;
 pub mod process_document;
 pub mod process_url_direct;
 pub mod process_youtube_direct;
 pub mod queue_processing_job;
+pub mod queue_reembed;
 pub mod search_content;
+pub mod subscribe_feed;
+pub mod sync_watched_file;
 pub mod upload_file;
 pub mod upload_with_processing;
 
@@ -16,10 +20,14 @@ pub use get_file::GetFileUseCase;
 pub use get_file_chunks::GetFileChunksUseCase;
 pub use get_job_status::GetJobStatusUseCase;
 pub use list_files::ListFilesUseCase;
+pub use poll_feed::PollFeedUseCase;
 pub use process_document::ProcessDocumentUseCase;
 pub use process_url_direct::ProcessUrlDirectUseCase;
 pub use process_youtube_direct::ProcessYoutubeDirectUseCase;
 pub use queue_processing_job::QueueProcessingJobUseCase;
+pub use queue_reembed::QueueReembedUseCase;
 pub use search_content::SearchContentUseCase;
+pub use subscribe_feed::SubscribeFeedUseCase;
+pub use sync_watched_file::SyncWatchedFileUseCase;
 pub use upload_file::UploadFileUseCase;
 pub use upload_with_processing::UploadWithProcessingUseCase;
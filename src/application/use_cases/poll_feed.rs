@@ -0,0 +1,316 @@
+use chrono::Utc;
+use std::sync::Arc;
+use url::Url;
+use uuid::Uuid;
+
+use super::queue_processing_job::{QueueJobRequest, QueueProcessingJobUseCase};
+use crate::application::ports::{FeedFetcher, feed_fetcher::FeedItem};
+use crate::domain::entities::{Feed, FeedEntry, File, processing_job::JobType};
+use crate::domain::repositories::{
+    FeedRepository, FileRepository, feed_repository::FeedRepositoryError,
+    file_repository::FileRepositoryError,
+};
+use crate::domain::value_objects::{FileHash, FileMetadata};
+
+#[derive(Debug)]
+pub struct PollFeedResponse {
+    pub feed_id: Uuid,
+    pub items_seen: usize,
+    pub new_items: usize,
+    pub updated_items: usize,
+    pub skipped_items: usize,
+    pub job_ids: Vec<Uuid>,
+}
+
+#[derive(Debug)]
+pub enum PollFeedError {
+    FeedNotFound(Uuid),
+    FetchError(String),
+    RepositoryError(String),
+}
+
+impl std::fmt::Display for PollFeedError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PollFeedError::FeedNotFound(id) => write!(f, "Feed not found: {}", id),
+            PollFeedError::FetchError(msg) => write!(f, "Failed to fetch feed: {}", msg),
+            PollFeedError::RepositoryError(msg) => write!(f, "Repository error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for PollFeedError {}
+
+impl From<FeedRepositoryError> for PollFeedError {
+    fn from(error: FeedRepositoryError) -> Self {
+        PollFeedError::RepositoryError(error.to_string())
+    }
+}
+
+impl From<FileRepositoryError> for PollFeedError {
+    fn from(error: FileRepositoryError) -> Self {
+        PollFeedError::RepositoryError(error.to_string())
+    }
+}
+
+enum ItemOutcome {
+    New(Uuid),
+    Updated(Uuid),
+    Unchanged,
+    Skipped,
+}
+
+pub struct PollFeedUseCase {
+    feed_repository: Arc<dyn FeedRepository>,
+    file_repository: Arc<dyn FileRepository>,
+    feed_fetcher: Arc<dyn FeedFetcher>,
+    queue_job_use_case: Arc<QueueProcessingJobUseCase>,
+}
+
+impl PollFeedUseCase {
+    pub fn new(
+        feed_repository: Arc<dyn FeedRepository>,
+        file_repository: Arc<dyn FileRepository>,
+        feed_fetcher: Arc<dyn FeedFetcher>,
+        queue_job_use_case: Arc<QueueProcessingJobUseCase>,
+    ) -> Self {
+        Self {
+            feed_repository,
+            file_repository,
+            feed_fetcher,
+            queue_job_use_case,
+        }
+    }
+
+    pub async fn execute(&self, feed_id: Uuid) -> Result<PollFeedResponse, PollFeedError> {
+        let mut feed = self
+            .feed_repository
+            .find_by_id(feed_id)
+            .await?
+            .ok_or(PollFeedError::FeedNotFound(feed_id))?;
+
+        self.poll(&mut feed).await
+    }
+
+    /// Poll every active feed whose interval has elapsed
+    pub async fn poll_due_feeds(&self) -> Result<Vec<PollFeedResponse>, PollFeedError> {
+        let now = Utc::now();
+        let feeds = self.feed_repository.find_active().await?;
+
+        let mut responses = Vec::new();
+        for mut feed in feeds.into_iter().filter(|feed| feed.is_due(now)) {
+            match self.poll(&mut feed).await {
+                Ok(response) => responses.push(response),
+                Err(e) => eprintln!("Failed to poll feed {} ({}): {}", feed.name(), feed.id(), e),
+            }
+        }
+
+        Ok(responses)
+    }
+
+    async fn poll(&self, feed: &mut Feed) -> Result<PollFeedResponse, PollFeedError> {
+        let fetched = match self.feed_fetcher.fetch(feed.url()).await {
+            Ok(fetched) => fetched,
+            Err(e) => {
+                feed.mark_failed(Utc::now(), e.to_string());
+                self.feed_repository.update(feed).await?;
+                return Err(PollFeedError::FetchError(e.to_string()));
+            }
+        };
+
+        let mut response = PollFeedResponse {
+            feed_id: feed.id(),
+            items_seen: fetched.items.len(),
+            new_items: 0,
+            updated_items: 0,
+            skipped_items: 0,
+            job_ids: Vec::new(),
+        };
+
+        for item in &fetched.items {
+            match self.process_item(feed, item).await? {
+                ItemOutcome::New(job_id) => {
+                    response.new_items += 1;
+                    response.job_ids.push(job_id);
+                }
+                ItemOutcome::Updated(job_id) => {
+                    response.updated_items += 1;
+                    response.job_ids.push(job_id);
+                }
+                ItemOutcome::Unchanged => {}
+                ItemOutcome::Skipped => response.skipped_items += 1,
+            }
+        }
+
+        feed.mark_polled(Utc::now());
+        self.feed_repository.update(feed).await?;
+
+        Ok(response)
+    }
+
+    async fn process_item(
+        &self,
+        feed: &Feed,
+        item: &FeedItem,
+    ) -> Result<ItemOutcome, PollFeedError> {
+        // Items without a fetchable link have nothing to extract
+        let Some(link) = item.link.as_deref().filter(|link| Url::parse(link).is_ok()) else {
+            return Ok(ItemOutcome::Skipped);
+        };
+
+        let content_hash = FeedEntry::compute_hash(link, item.title.as_deref(), item.updated_at);
+        let existing = self
+            .feed_repository
+            .find_entry(feed.id(), &item.guid)
+            .await?;
+
+        if let Some(entry) = &existing
+            && !entry.is_changed(&content_hash)
+            && entry.file_id().is_some()
+        {
+            return Ok(ItemOutcome::Unchanged);
+        }
+
+        let file_id = self
+            .upsert_file(
+                feed,
+                item,
+                link,
+                existing.as_ref().and_then(|e| e.file_id()),
+            )
+            .await?;
+
+        let queue_request = QueueJobRequest {
+            file_id,
+            job_type: JobType::UrlExtraction {
+                url: link.to_string(),
+            },
+            chunking_config: None,
+        };
+
+        // Leave the entry unrecorded on failure so the next poll retries it
+        let job_id = match self.queue_job_use_case.execute(queue_request).await {
+            Ok(job_response) => job_response.job_id,
+            Err(e) => {
+                eprintln!(
+                    "Failed to queue feed item {} from {}: {}",
+                    item.guid,
+                    feed.name(),
+                    e
+                );
+                return Ok(ItemOutcome::Skipped);
+            }
+        };
+
+        match existing {
+            Some(mut entry) => {
+                entry.apply_update(
+                    link.to_string(),
+                    item.title.clone(),
+                    item.published_at.or(item.updated_at),
+                    content_hash,
+                    file_id,
+                );
+                self.feed_repository.save_entry(&entry).await?;
+                Ok(ItemOutcome::Updated(job_id))
+            }
+            None => {
+                let entry = FeedEntry::new(
+                    feed.id(),
+                    item.guid.clone(),
+                    link.to_string(),
+                    item.title.clone(),
+                    item.published_at.or(item.updated_at),
+                    content_hash,
+                    Some(file_id),
+                );
+                self.feed_repository.save_entry(&entry).await?;
+                Ok(ItemOutcome::New(job_id))
+            }
+        }
+    }
+
+    async fn upsert_file(
+        &self,
+        feed: &Feed,
+        item: &FeedItem,
+        link: &str,
+        known_file_id: Option<Uuid>,
+    ) -> Result<Uuid, PollFeedError> {
+        let metadata = Self::item_metadata(feed, item, link);
+        let file_hash = FileHash::from_bytes(link.as_bytes());
+
+        // Reuse the file recorded for this entry, or one already ingested from the same URL
+        let existing = match known_file_id {
+            Some(file_id) => self.file_repository.find_by_id(file_id).await?,
+            None => None,
+        };
+        let existing = match existing {
+            Some(file) => Some(file),
+            None => {
+                self.file_repository
+                    .find_by_hash(file_hash.as_str())
+                    .await?
+            }
+        };
+
+        if let Some(mut file) = existing {
+            let mut merged = file.metadata().cloned().unwrap_or_default();
+            merged.merge(metadata);
+            file.update_metadata(merged);
+            self.file_repository.update(&file).await?;
+            return Ok(file.id());
+        }
+
+        let file_name = item
+            .title
+            .clone()
+            .filter(|title| !title.is_empty())
+            .unwrap_or_else(|| link.to_string());
+
+        let file = File::new(
+            link.to_string(),
+            file_name,
+            None,
+            Some("text/html".to_string()),
+            Some(file_hash),
+            Some(metadata),
+        );
+
+        Ok(self.file_repository.save(&file).await?)
+    }
+
+    fn item_metadata(feed: &Feed, item: &FeedItem, link: &str) -> FileMetadata {
+        let mut metadata = FileMetadata::new();
+        metadata.set_property(
+            "source_url".to_string(),
+            serde_json::Value::String(link.to_string()),
+        );
+        metadata.set_property(
+            "extraction_type".to_string(),
+            serde_json::Value::String("feed".to_string()),
+        );
+        metadata.set_property(
+            "feed_id".to_string(),
+            serde_json::Value::String(feed.id().to_string()),
+        );
+        metadata.set_property(
+            "feed_name".to_string(),
+            serde_json::Value::String(feed.name().to_string()),
+        );
+        metadata.set_property(
+            "feed_entry_guid".to_string(),
+            serde_json::Value::String(item.guid.clone()),
+        );
+        if let Some(published_at) = item.published_at.or(item.updated_at) {
+            metadata.set_property(
+                "published_at".to_string(),
+                serde_json::Value::String(published_at.to_rfc3339()),
+            );
+        }
+        if let Some(title) = &item.title {
+            metadata.set_title(title.clone());
+        }
+        metadata
+    }
+}
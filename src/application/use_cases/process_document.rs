@@ -3,6 +3,7 @@ use uuid::Uuid;
 
 use crate::application::ports::document_extractor::ExtractionOptions;
 use crate::application::services::DocumentProcessorService;
+use crate::application::services::document_processor::ProcessedFile;
 use crate::domain::repositories::{FileRepository, file_repository::FileRepositoryError};
 
 #[derive(Debug)]
@@ -97,11 +98,25 @@ impl ProcessDocumentUseCase {
         // Process the document
         let processing_result = self
             .document_processor
-            .process_file(&file, request.extraction_options.unwrap_or_default())
+            .process_file(&file, request.extraction_options.unwrap_or_default(), None)
             .await;
 
         match processing_result {
-            Ok((chunks_created, embeddings_created)) => {
+            Ok(ProcessedFile {
+                chunks_created,
+                embeddings_created,
+                ..
+            }) => {
+                // Pick up metadata recorded during processing (e.g. the detected language)
+                if let Some(metadata) = self
+                    .file_repository
+                    .find_by_id(file.id())
+                    .await?
+                    .and_then(|processed| processed.metadata().cloned())
+                {
+                    file.update_metadata(metadata);
+                }
+
                 // Mark as completed
                 file.complete_processing()
                     .map_err(|e| ProcessDocumentError::ProcessingError(e))?;
@@ -5,13 +5,14 @@ use uuid::Uuid;
 use super::queue_processing_job::{QueueJobError, QueueJobRequest, QueueProcessingJobUseCase};
 use crate::domain::entities::{File, processing_job::JobType};
 use crate::domain::repositories::FileRepository;
-use crate::domain::value_objects::{FileHash, FileMetadata};
+use crate::domain::value_objects::{ChunkingConfig, FileHash, FileMetadata};
 
 #[derive(Debug)]
 pub struct ProcessUrlDirectRequest {
     pub url: String,
     pub filename: Option<String>,
     pub auto_process: bool,
+    pub chunking_config: Option<ChunkingConfig>,
 }
 
 #[derive(Debug)]
@@ -133,6 +134,7 @@ impl ProcessUrlDirectUseCase {
                 job_type: JobType::UrlExtraction {
                     url: request.url.clone(),
                 },
+                chunking_config: request.chunking_config.clone(),
             };
 
             self.queue_job_use_case.execute(queue_request).await?
@@ -5,7 +5,7 @@ use uuid::Uuid;
 use super::queue_processing_job::{QueueJobError, QueueJobRequest, QueueProcessingJobUseCase};
 use crate::domain::entities::{File, processing_job::JobType};
 use crate::domain::repositories::FileRepository;
-use crate::domain::value_objects::{FileHash, FileMetadata};
+use crate::domain::value_objects::{ChunkingConfig, FileHash, FileMetadata};
 
 #[derive(Debug)]
 pub struct ProcessYoutubeDirectRequest {
@@ -14,6 +14,7 @@ pub struct ProcessYoutubeDirectRequest {
     pub extract_timestamps: bool,
     pub language_preference: Vec<String>,
     pub auto_process: bool,
+    pub chunking_config: Option<ChunkingConfig>,
 }
 
 #[derive(Debug)]
@@ -149,6 +150,7 @@ impl ProcessYoutubeDirectUseCase {
                 job_type: JobType::YoutubeExtraction {
                     url: request.url.clone(),
                 },
+                chunking_config: request.chunking_config.clone(),
             };
 
             self.queue_job_use_case.execute(queue_request).await?
@@ -6,6 +6,7 @@ use crate::domain::entities::{ProcessingJob, processing_job::JobType};
 use crate::domain::repositories::{
     FileRepository, JobRepository, job_repository::JobRepositoryError,
 };
+use crate::domain::value_objects::ChunkingConfig;
 
 #[derive(Debug)]
 pub enum QueueJobError {
@@ -44,12 +45,15 @@ impl From<JobQueueError> for QueueJobError {
 pub struct QueueJobRequest {
     pub file_id: Uuid,
     pub job_type: JobType,
+    /// Chunking for this job; None reuses the file's recorded config
+    pub chunking_config: Option<ChunkingConfig>,
 }
 
 #[derive(Debug, Clone)]
 pub struct QueueJobResponse {
     pub job_id: Uuid,
-    pub file_id: Uuid,
+    /// None for jobs that span the whole corpus
+    pub file_id: Option<Uuid>,
     pub job_type: JobType,
     pub status: String,
     pub message: String,
@@ -78,6 +82,10 @@ impl QueueProcessingJobUseCase {
         &self,
         request: QueueJobRequest,
     ) -> Result<QueueJobResponse, QueueJobError> {
+        if let Some(config) = &request.chunking_config {
+            config.validate().map_err(QueueJobError::ValidationError)?;
+        }
+
         // Validate that the file exists
         let file = self
             .file_repository
@@ -103,7 +111,11 @@ impl QueueProcessingJobUseCase {
             JobType::YoutubeExtraction { url } => {
                 ProcessingJob::new_youtube_extraction(request.file_id, url.clone())
             }
-        };
+            JobType::Reembed { model_name } => {
+                ProcessingJob::new_reembed(model_name.clone(), Some(request.file_id))
+            }
+        }
+        .with_chunking_config(request.chunking_config.clone());
 
         // Save job to repository
         self.job_repository.save(&job).await?;
@@ -113,7 +125,7 @@ impl QueueProcessingJobUseCase {
 
         Ok(QueueJobResponse {
             job_id: job.id(),
-            file_id: request.file_id,
+            file_id: Some(request.file_id),
             job_type: request.job_type,
             status: "queued".to_string(),
             message: "Job queued successfully for processing".to_string(),
@@ -123,10 +135,12 @@ impl QueueProcessingJobUseCase {
     pub async fn queue_file_processing(
         &self,
         file_id: Uuid,
+        chunking_config: Option<ChunkingConfig>,
     ) -> Result<QueueJobResponse, QueueJobError> {
         let request = QueueJobRequest {
             file_id,
             job_type: JobType::FileProcessing,
+            chunking_config,
         };
         self.execute(request).await
     }
@@ -135,6 +149,7 @@ impl QueueProcessingJobUseCase {
         &self,
         file_id: Uuid,
         url: String,
+        chunking_config: Option<ChunkingConfig>,
     ) -> Result<QueueJobResponse, QueueJobError> {
         // Validate URL
         if url.trim().is_empty() {
@@ -152,6 +167,7 @@ impl QueueProcessingJobUseCase {
         let request = QueueJobRequest {
             file_id,
             job_type: JobType::UrlExtraction { url },
+            chunking_config,
         };
         self.execute(request).await
     }
@@ -160,6 +176,7 @@ impl QueueProcessingJobUseCase {
         &self,
         file_id: Uuid,
         url: String,
+        chunking_config: Option<ChunkingConfig>,
     ) -> Result<QueueJobResponse, QueueJobError> {
         // Validate YouTube URL
         if url.trim().is_empty() {
@@ -186,6 +203,7 @@ impl QueueProcessingJobUseCase {
         let request = QueueJobRequest {
             file_id,
             job_type: JobType::YoutubeExtraction { url },
+            chunking_config,
         };
         self.execute(request).await
     }
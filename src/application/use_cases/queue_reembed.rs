@@ -0,0 +1,84 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::application::ports::JobQueue;
+use crate::application::services::EmbeddingModelRegistry;
+use crate::application::use_cases::queue_processing_job::{QueueJobError, QueueJobResponse};
+use crate::domain::entities::{ProcessingJob, processing_job::JobType};
+use crate::domain::repositories::{FileRepository, JobRepository};
+
+#[derive(Debug, Clone)]
+pub struct QueueReembedRequest {
+    pub model_name: String,
+    /// Re-embed one file only; None walks the whole corpus and activates the
+    /// model once every chunk has a vector from it
+    pub file_id: Option<Uuid>,
+}
+
+pub struct QueueReembedUseCase {
+    job_repository: Arc<dyn JobRepository>,
+    job_queue: Arc<dyn JobQueue>,
+    file_repository: Arc<dyn FileRepository>,
+    embedding_models: Arc<EmbeddingModelRegistry>,
+}
+
+impl QueueReembedUseCase {
+    pub fn new(
+        job_repository: Arc<dyn JobRepository>,
+        job_queue: Arc<dyn JobQueue>,
+        file_repository: Arc<dyn FileRepository>,
+        embedding_models: Arc<EmbeddingModelRegistry>,
+    ) -> Self {
+        Self {
+            job_repository,
+            job_queue,
+            file_repository,
+            embedding_models,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        request: QueueReembedRequest,
+    ) -> Result<QueueJobResponse, QueueJobError> {
+        if self.embedding_models.get(&request.model_name).is_none() {
+            return Err(QueueJobError::ValidationError(format!(
+                "Unknown model '{}'; available models: {}",
+                request.model_name,
+                self.embedding_models.model_names().join(", ")
+            )));
+        }
+
+        if let Some(file_id) = request.file_id {
+            self.file_repository
+                .find_by_id(file_id)
+                .await
+                .map_err(|e| QueueJobError::RepositoryError(e.to_string()))?
+                .ok_or(QueueJobError::FileNotFound(file_id))?;
+        }
+
+        // Two runs for the same model would embed the same chunks twice
+        let active_jobs = self.job_repository.find_active_jobs().await?;
+        if let Some(running) = active_jobs.iter().find(|job| {
+            matches!(job.job_type(), JobType::Reembed { model_name } if *model_name == request.model_name)
+        }) {
+            return Err(QueueJobError::ValidationError(format!(
+                "Model {} is already being re-embedded by job {}",
+                request.model_name,
+                running.id()
+            )));
+        }
+
+        let job = ProcessingJob::new_reembed(request.model_name, request.file_id);
+        self.job_repository.save(&job).await?;
+        self.job_queue.enqueue(job.clone()).await?;
+
+        Ok(QueueJobResponse {
+            job_id: job.id(),
+            file_id: job.file_id(),
+            job_type: job.job_type().clone(),
+            status: "queued".to_string(),
+            message: "Re-embedding job queued successfully".to_string(),
+        })
+    }
+}
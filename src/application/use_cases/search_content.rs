@@ -1,7 +1,16 @@
 use std::sync::Arc;
 
 use crate::application::services::SearchService;
+use crate::application::services::search_service::{
+    CrossLingualOptions, RetrievalMode, SearchServiceError,
+};
 use crate::domain::entities::ContentChunk;
+use crate::domain::repositories::embedding_repository::SimilaritySearchFilter;
+use crate::domain::value_objects::{ChunkContext, LanguageCode};
+
+const PARENT_OVERFETCH: i32 = 3;
+const MAX_FETCH_LIMIT: i32 = 300;
+const MAX_CONTEXT_WINDOW: usize = 10;
 
 #[derive(Debug)]
 pub enum SearchContentError {
@@ -28,6 +37,18 @@ pub struct SearchContentRequest {
     pub limit: Option<i32>,
     pub similarity_threshold: Option<f32>,
     pub file_id_filter: Option<uuid::Uuid>,
+    /// Restrict results to chunks in these languages (ISO 639 codes or names)
+    pub languages: Vec<String>,
+    /// Search each top corpus language separately and fuse the rankings
+    pub cross_lingual: bool,
+    pub max_languages: Option<usize>,
+    /// Attach each match's parent chunk, returning one result per parent
+    pub expand_parents: bool,
+    /// Neighbouring chunks to stitch around each match on either side; 0 disables
+    pub context_window: usize,
+    /// Embedding model to search with; the default model when None
+    pub model: Option<String>,
+    pub mode: RetrievalMode,
 }
 
 #[derive(Debug, Clone)]
@@ -35,6 +56,11 @@ pub struct SearchResult {
     pub chunk: ContentChunk,
     pub similarity_score: f32,
     pub file_id: uuid::Uuid,
+    pub source_language: Option<String>,
+    /// Parent of the matched chunk, when parent expansion was requested
+    pub parent_chunk: Option<ContentChunk>,
+    /// Stitched neighbouring chunks, when a context window was requested
+    pub context: Option<ChunkContext>,
 }
 
 #[derive(Debug, Clone)]
@@ -43,6 +69,12 @@ pub struct SearchContentResponse {
     pub results: Vec<SearchResult>,
     pub total_results: i32,
     pub search_time_ms: u64,
+    pub query_language: Option<String>,
+    pub searched_languages: Vec<String>,
+    pub model: String,
+    /// Sparse model the search ranked by, in sparse and hybrid mode
+    pub sparse_model: Option<String>,
+    pub mode: RetrievalMode,
 }
 
 pub struct SearchContentUseCase {
@@ -67,17 +99,125 @@ impl SearchContentUseCase {
             return Err(SearchContentError::ValidationError("Limit must be between 1 and 100".to_string()));
         }
 
+        if request.context_window > MAX_CONTEXT_WINDOW {
+            return Err(SearchContentError::ValidationError(format!(
+                "context_window must be between 0 and {}",
+                MAX_CONTEXT_WINDOW
+            )));
+        }
+
+        let languages = request
+            .languages
+            .iter()
+            .map(|language| LanguageCode::parse(language).map(String::from))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(SearchContentError::ValidationError)?;
+
+        let model = self
+            .search_service
+            .resolve_model(request.model.as_deref())
+            .map_err(|e| match e {
+                SearchServiceError::UnknownModel(name) => {
+                    SearchContentError::ValidationError(format!(
+                        "Unknown model '{}'; available models: {}",
+                        name,
+                        self.search_service.model_names().join(", ")
+                    ))
+                }
+                other => SearchContentError::EmbeddingError(other.to_string()),
+            })?;
+
+        let sparse_model = match (
+            request.mode.uses_sparse(),
+            self.search_service.sparse_model(),
+        ) {
+            (false, _) => None,
+            (true, Some(sparse_model)) => Some(sparse_model),
+            (true, None) => {
+                return Err(SearchContentError::ValidationError(format!(
+                    "{} retrieval needs a sparse embedding model; none is configured",
+                    request.mode.as_str()
+                )));
+            }
+        };
+
+        let filter = SimilaritySearchFilter {
+            file_id: request.file_id_filter,
+            languages,
+            model_name: Some(model.clone()),
+        };
+
+        // Several children of one parent collapse into a single result, so fetch extra
+        let fetch_limit = if request.expand_parents {
+            (limit * PARENT_OVERFETCH).min(MAX_FETCH_LIMIT)
+        } else {
+            limit
+        };
+
         // Perform search
-        let results = self.search_service
-            .search_content(
-                &request.query,
-                limit,
-                request.similarity_threshold,
-                request.file_id_filter,
+        let (results, query_language, searched_languages) = if request.cross_lingual {
+            let mut options = CrossLingualOptions::default();
+            if let Some(max_languages) = request.max_languages {
+                if max_languages == 0 || max_languages > 10 {
+                    return Err(SearchContentError::ValidationError(
+                        "max_languages must be between 1 and 10".to_string(),
+                    ));
+                }
+                options.max_languages = max_languages;
+            }
+
+            let cross_lingual = self
+                .search_service
+                .search_cross_lingual(
+                    &request.query,
+                    fetch_limit,
+                    request.similarity_threshold,
+                    filter,
+                    options,
+                    request.mode,
+                )
+                .await
+                .map_err(|e| SearchContentError::RepositoryError(e.to_string()))?;
+            let searched_languages = cross_lingual
+                .queries
+                .into_iter()
+                .map(|(language, _)| language)
+                .collect();
+            (
+                cross_lingual.results,
+                cross_lingual.query_language,
+                searched_languages,
             )
+        } else {
+            let results = self
+                .search_service
+                .search_content(
+                    &request.query,
+                    fetch_limit,
+                    request.similarity_threshold,
+                    filter,
+                    request.mode,
+                )
+                .await
+                .map_err(|e| SearchContentError::RepositoryError(e.to_string()))?;
+            (results, None, Vec::new())
+        };
+
+        let results = self
+            .search_service
+            .expand_context(results, request.context_window)
             .await
             .map_err(|e| SearchContentError::RepositoryError(e.to_string()))?;
 
+        let results = if request.expand_parents {
+            self.search_service
+                .expand_parents(results, limit as usize)
+                .await
+                .map_err(|e| SearchContentError::RepositoryError(e.to_string()))?
+        } else {
+            results
+        };
+
         let search_time = start_time.elapsed().as_millis() as u64;
 
         Ok(SearchContentResponse {
@@ -85,6 +225,11 @@ impl SearchContentUseCase {
             total_results: results.len() as i32,
             results,
             search_time_ms: search_time,
+            query_language,
+            searched_languages,
+            model,
+            sparse_model,
+            mode: request.mode,
         })
     }
 }
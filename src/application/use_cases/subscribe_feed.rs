@@ -0,0 +1,125 @@
+use std::sync::Arc;
+use url::Url;
+
+use crate::application::ports::FeedFetcher;
+use crate::domain::entities::{
+    Feed,
+    feed::{DEFAULT_POLL_INTERVAL_SECONDS, MIN_POLL_INTERVAL_SECONDS},
+};
+use crate::domain::repositories::{FeedRepository, feed_repository::FeedRepositoryError};
+
+#[derive(Debug)]
+pub struct SubscribeFeedRequest {
+    pub url: String,
+    pub name: Option<String>,
+    pub poll_interval_seconds: Option<i32>,
+}
+
+#[derive(Debug)]
+pub struct SubscribeFeedResponse {
+    pub feed: Feed,
+    pub item_count: usize,
+}
+
+#[derive(Debug)]
+pub enum SubscribeFeedError {
+    InvalidUrl(String),
+    AlreadySubscribed(String),
+    FetchError(String),
+    ValidationError(String),
+    RepositoryError(String),
+}
+
+impl std::fmt::Display for SubscribeFeedError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SubscribeFeedError::InvalidUrl(msg) => write!(f, "Invalid URL: {}", msg),
+            SubscribeFeedError::AlreadySubscribed(url) => {
+                write!(f, "Feed already subscribed: {}", url)
+            }
+            SubscribeFeedError::FetchError(msg) => write!(f, "Failed to fetch feed: {}", msg),
+            SubscribeFeedError::ValidationError(msg) => write!(f, "Validation error: {}", msg),
+            SubscribeFeedError::RepositoryError(msg) => write!(f, "Repository error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for SubscribeFeedError {}
+
+impl From<FeedRepositoryError> for SubscribeFeedError {
+    fn from(error: FeedRepositoryError) -> Self {
+        SubscribeFeedError::RepositoryError(error.to_string())
+    }
+}
+
+pub struct SubscribeFeedUseCase {
+    feed_repository: Arc<dyn FeedRepository>,
+    feed_fetcher: Arc<dyn FeedFetcher>,
+}
+
+impl SubscribeFeedUseCase {
+    pub fn new(
+        feed_repository: Arc<dyn FeedRepository>,
+        feed_fetcher: Arc<dyn FeedFetcher>,
+    ) -> Self {
+        Self {
+            feed_repository,
+            feed_fetcher,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        request: SubscribeFeedRequest,
+    ) -> Result<SubscribeFeedResponse, SubscribeFeedError> {
+        let parsed_url =
+            Url::parse(&request.url).map_err(|e| SubscribeFeedError::InvalidUrl(e.to_string()))?;
+        if !matches!(parsed_url.scheme(), "http" | "https") {
+            return Err(SubscribeFeedError::InvalidUrl(
+                "Feed URL must use http or https".to_string(),
+            ));
+        }
+
+        let poll_interval = request
+            .poll_interval_seconds
+            .unwrap_or(DEFAULT_POLL_INTERVAL_SECONDS);
+        if poll_interval < MIN_POLL_INTERVAL_SECONDS {
+            return Err(SubscribeFeedError::ValidationError(format!(
+                "Poll interval must be at least {} seconds",
+                MIN_POLL_INTERVAL_SECONDS
+            )));
+        }
+
+        if self
+            .feed_repository
+            .find_by_url(parsed_url.as_str())
+            .await?
+            .is_some()
+        {
+            return Err(SubscribeFeedError::AlreadySubscribed(
+                parsed_url.to_string(),
+            ));
+        }
+
+        // Fetch once up front so broken URLs are rejected at subscription time
+        let fetched = self
+            .feed_fetcher
+            .fetch(parsed_url.as_str())
+            .await
+            .map_err(|e| SubscribeFeedError::FetchError(e.to_string()))?;
+
+        let name = request
+            .name
+            .filter(|name| !name.trim().is_empty())
+            .or(fetched.title)
+            .unwrap_or_else(|| parsed_url.host_str().unwrap_or("feed").to_string());
+
+        let feed = Feed::new(parsed_url.to_string(), name, poll_interval);
+        self.feed_repository.save(&feed).await?;
+
+        Ok(SubscribeFeedResponse {
+            feed,
+            item_count: fetched.items.len(),
+        })
+    }
+}
@@ -0,0 +1,212 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use super::queue_processing_job::{QueueJobError, QueueJobRequest, QueueProcessingJobUseCase};
+use crate::application::ports::content_detector::DetectedContent;
+use crate::domain::entities::{File, processing_job::JobType};
+use crate::domain::repositories::{FileRepository, file_repository::FileRepositoryError};
+use crate::domain::value_objects::{FileHash, FileMetadata};
+
+#[derive(Debug)]
+pub enum WatchedFileChange {
+    Upserted {
+        path: String,
+        data: Vec<u8>,
+        detected: DetectedContent,
+        watch_root: String,
+    },
+    Removed {
+        path: String,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SyncAction {
+    Created,
+    Updated,
+    Unchanged,
+    Removed,
+    NotTracked,
+}
+
+#[derive(Debug)]
+pub struct SyncWatchedFileResponse {
+    pub file_id: Option<Uuid>,
+    pub job_id: Option<Uuid>,
+    pub action: SyncAction,
+}
+
+#[derive(Debug)]
+pub enum SyncWatchedFileError {
+    RepositoryError(String),
+    QueueError(String),
+}
+
+impl std::fmt::Display for SyncWatchedFileError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SyncWatchedFileError::RepositoryError(msg) => write!(f, "Repository error: {}", msg),
+            SyncWatchedFileError::QueueError(msg) => write!(f, "Queue error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for SyncWatchedFileError {}
+
+impl From<FileRepositoryError> for SyncWatchedFileError {
+    fn from(error: FileRepositoryError) -> Self {
+        SyncWatchedFileError::RepositoryError(error.to_string())
+    }
+}
+
+impl From<QueueJobError> for SyncWatchedFileError {
+    fn from(error: QueueJobError) -> Self {
+        SyncWatchedFileError::QueueError(error.to_string())
+    }
+}
+
+pub struct SyncWatchedFileUseCase {
+    file_repository: Arc<dyn FileRepository>,
+    queue_job_use_case: Arc<QueueProcessingJobUseCase>,
+}
+
+impl SyncWatchedFileUseCase {
+    pub fn new(
+        file_repository: Arc<dyn FileRepository>,
+        queue_job_use_case: Arc<QueueProcessingJobUseCase>,
+    ) -> Self {
+        Self {
+            file_repository,
+            queue_job_use_case,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        change: WatchedFileChange,
+    ) -> Result<SyncWatchedFileResponse, SyncWatchedFileError> {
+        match change {
+            WatchedFileChange::Upserted {
+                path,
+                data,
+                detected,
+                watch_root,
+            } => self.upsert(path, data, detected, watch_root).await,
+            WatchedFileChange::Removed { path } => self.remove(&path).await,
+        }
+    }
+
+    /// Paths of tracked files under a watched root, for reconciling after downtime
+    pub async fn tracked_paths(
+        &self,
+        watch_root: &str,
+    ) -> Result<Vec<String>, SyncWatchedFileError> {
+        let files = self.file_repository.find_by_path_prefix(watch_root).await?;
+        Ok(files
+            .into_iter()
+            .map(|file| file.file_path().to_string())
+            .collect())
+    }
+
+    async fn upsert(
+        &self,
+        path: String,
+        data: Vec<u8>,
+        detected: DetectedContent,
+        watch_root: String,
+    ) -> Result<SyncWatchedFileResponse, SyncWatchedFileError> {
+        let file_hash = FileHash::from_bytes(&data);
+        let file_size = Some(data.len() as i64);
+
+        if let Some(mut file) = self.file_repository.find_by_path(&path).await? {
+            if file.file_hash() == Some(&file_hash) {
+                return Ok(SyncWatchedFileResponse {
+                    file_id: Some(file.id()),
+                    job_id: None,
+                    action: SyncAction::Unchanged,
+                });
+            }
+
+            // Queue before recording the new hash so a rejected job is retried on the next change
+            let job_id = self.queue_processing(file.id()).await?;
+
+            // The new content may be of another type, which picks another extractor
+            file.update_content(file_size, file_hash, Some(detected.mime_type));
+            self.file_repository.update(&file).await?;
+
+            return Ok(SyncWatchedFileResponse {
+                file_id: Some(file.id()),
+                job_id: Some(job_id),
+                action: SyncAction::Updated,
+            });
+        }
+
+        let file_name = std::path::Path::new(&path)
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_else(|| path.clone());
+
+        let mut metadata = FileMetadata::new();
+        metadata.set_property(
+            "extraction_type".to_string(),
+            serde_json::Value::String("watched_folder".to_string()),
+        );
+        metadata.set_property(
+            "watch_root".to_string(),
+            serde_json::Value::String(watch_root),
+        );
+        if let Some(encoding) = detected.encoding {
+            metadata.set_encoding(encoding);
+        }
+
+        let file = File::new(
+            path,
+            file_name,
+            file_size,
+            Some(detected.mime_type),
+            Some(file_hash),
+            Some(metadata),
+        );
+
+        let file_id = self.file_repository.save(&file).await?;
+        let job_id = self.queue_processing(file_id).await?;
+
+        Ok(SyncWatchedFileResponse {
+            file_id: Some(file_id),
+            job_id: Some(job_id),
+            action: SyncAction::Created,
+        })
+    }
+
+    async fn remove(&self, path: &str) -> Result<SyncWatchedFileResponse, SyncWatchedFileError> {
+        let Some(file) = self.file_repository.find_by_path(path).await? else {
+            return Ok(SyncWatchedFileResponse {
+                file_id: None,
+                job_id: None,
+                action: SyncAction::NotTracked,
+            });
+        };
+
+        // Chunks, embeddings and jobs are removed by the database cascade
+        self.file_repository.delete(file.id()).await?;
+
+        Ok(SyncWatchedFileResponse {
+            file_id: Some(file.id()),
+            job_id: None,
+            action: SyncAction::Removed,
+        })
+    }
+
+    async fn queue_processing(&self, file_id: Uuid) -> Result<Uuid, SyncWatchedFileError> {
+        let response = self
+            .queue_job_use_case
+            .execute(QueueJobRequest {
+                file_id,
+                job_type: JobType::FileProcessing,
+                chunking_config: None,
+            })
+            .await?;
+
+        Ok(response.job_id)
+    }
+}
@@ -1,7 +1,7 @@
 use std::sync::Arc;
 use uuid::Uuid;
 
-use crate::application::ports::FileStorage;
+use crate::application::ports::{ContentDetector, FileStorage};
 use crate::domain::entities::File;
 use crate::domain::repositories::{FileRepository, file_repository::FileRepositoryError};
 use crate::domain::value_objects::{FileHash, FileMetadata};
@@ -48,21 +48,25 @@ pub struct UploadFileResponse {
     pub file_size: i64,
     pub file_hash: String,
     pub content_type: Option<String>,
+    pub declared_content_type: Option<String>,
 }
 
 pub struct UploadFileUseCase {
     file_repository: Arc<dyn FileRepository>,
     file_storage: Arc<dyn FileStorage>,
+    content_detector: Arc<dyn ContentDetector>,
 }
 
 impl UploadFileUseCase {
     pub fn new(
         file_repository: Arc<dyn FileRepository>,
         file_storage: Arc<dyn FileStorage>,
+        content_detector: Arc<dyn ContentDetector>,
     ) -> Self {
         Self {
             file_repository,
             file_storage,
+            content_detector,
         }
     }
 
@@ -93,13 +97,32 @@ impl UploadFileUseCase {
             ));
         }
 
+        // The client's content type is only a hint; route on what the bytes say
+        let detected = self.content_detector.detect(
+            &request.file_data,
+            Some(&request.file_name),
+            request.content_type.as_deref(),
+        );
+
+        let mut metadata = request.metadata.unwrap_or_default();
+        if let Some(declared) = &request.content_type {
+            metadata.set_declared_content_type(declared.clone());
+        }
+        if let Some(encoding) = &detected.encoding {
+            metadata.set_encoding(encoding.clone());
+        }
+        metadata.set_property(
+            "content_detection".to_string(),
+            serde_json::Value::String(detected.method.as_str().to_string()),
+        );
+
         // Store file
         let stored_file = self
             .file_storage
             .store_file(
                 &request.file_data,
                 &request.file_name,
-                request.content_type.as_deref(),
+                Some(&detected.mime_type),
             )
             .await
             .map_err(|e| UploadFileError::StorageError(e.to_string()))?;
@@ -109,9 +132,9 @@ impl UploadFileUseCase {
             stored_file.path,
             request.file_name.clone(),
             Some(request.file_data.len() as i64),
-            request.content_type.clone(),
+            Some(detected.mime_type.clone()),
             Some(file_hash.clone()),
-            request.metadata,
+            Some(metadata),
         );
 
         // Save to repository and get the generated ID
@@ -122,7 +145,8 @@ impl UploadFileUseCase {
             file_name: request.file_name,
             file_size: request.file_data.len() as i64,
             file_hash: file_hash.to_string(),
-            content_type: request.content_type,
+            content_type: Some(detected.mime_type),
+            declared_content_type: request.content_type,
         })
     }
 }
@@ -7,6 +7,7 @@ use crate::application::use_cases::{
 };
 use crate::domain::entities::processing_job::JobType;
 use crate::domain::repositories::FileRepository;
+use crate::domain::value_objects::ChunkingConfig;
 
 #[derive(Debug)]
 pub enum UploadWithProcessingError {
@@ -52,6 +53,7 @@ pub struct UploadWithProcessingRequest {
     pub content_type: Option<String>,
     pub auto_process: bool,
     pub metadata: Option<crate::domain::value_objects::FileMetadata>,
+    pub chunking_config: Option<ChunkingConfig>,
 }
 
 #[derive(Debug)]
@@ -168,6 +170,7 @@ impl UploadWithProcessingUseCase {
             let queue_request = QueueJobRequest {
                 file_id: upload_response.file_id,
                 job_type: JobType::FileProcessing,
+                chunking_config: request.chunking_config,
             };
 
             match self.queue_job_use_case.execute(queue_request).await {
@@ -1,5 +1,6 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 use uuid::Uuid;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -11,6 +12,10 @@ pub struct ContentChunk {
     token_count: Option<i32>,
     page_number: Option<i32>,
     section_path: Option<String>,
+    language: Option<String>,
+    /// Larger chunk this one was cut from; parents are stored for context
+    /// and only their children are embedded
+    parent_chunk_id: Option<Uuid>,
     created_at: DateTime<Utc>,
 }
 
@@ -31,10 +36,57 @@ impl ContentChunk {
             token_count,
             page_number,
             section_path,
+            language: None,
+            parent_chunk_id: None,
             created_at: Utc::now(),
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
+    pub fn with_id(
+        id: Uuid,
+        file_id: Uuid,
+        chunk_text: String,
+        chunk_index: i32,
+        token_count: Option<i32>,
+        page_number: Option<i32>,
+        section_path: Option<String>,
+        language: Option<String>,
+        created_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            file_id,
+            chunk_text,
+            chunk_index,
+            token_count,
+            page_number,
+            section_path,
+            language,
+            parent_chunk_id: None,
+            created_at,
+        }
+    }
+
+    pub fn with_language(mut self, language: Option<String>) -> Self {
+        self.language = language;
+        self
+    }
+
+    pub fn with_parent_chunk_id(mut self, parent_chunk_id: Option<Uuid>) -> Self {
+        self.parent_chunk_id = parent_chunk_id;
+        self
+    }
+
+    /// Chunks that get embeddings: everything except parents of other chunks
+    pub fn leaves(chunks: &[ContentChunk]) -> Vec<&ContentChunk> {
+        let parent_ids: HashSet<Uuid> = chunks.iter().filter_map(|c| c.parent_chunk_id).collect();
+        chunks
+            .iter()
+            .filter(|chunk| !parent_ids.contains(&chunk.id))
+            .collect()
+    }
+
     // Getters
     pub fn id(&self) -> Uuid {
         self.id
@@ -64,6 +116,15 @@ impl ContentChunk {
         self.section_path.as_deref()
     }
 
+    /// ISO 639 code of the chunk's natural language, when it could be identified
+    pub fn language(&self) -> Option<&str> {
+        self.language.as_deref()
+    }
+
+    pub fn parent_chunk_id(&self) -> Option<Uuid> {
+        self.parent_chunk_id
+    }
+
     pub fn created_at(&self) -> DateTime<Utc> {
         self.created_at
     }
@@ -135,4 +196,36 @@ mod tests {
         assert_eq!(chunk.word_count(), 3);
         assert_eq!(chunk.character_count(), 16);
     }
+
+    #[test]
+    fn test_chunk_language() {
+        let file_id = Uuid::new_v4();
+        let chunk = ContentChunk::new(file_id, "Bonjour le monde".to_string(), 0, None, None, None);
+        assert_eq!(chunk.language(), None);
+
+        let chunk = chunk.with_language(Some("fr".to_string()));
+        assert_eq!(chunk.language(), Some("fr"));
+    }
+
+    #[test]
+    fn test_leaves_skip_parents() {
+        let file_id = Uuid::new_v4();
+        let parent = ContentChunk::new(
+            file_id,
+            "Parent section text".to_string(),
+            0,
+            None,
+            None,
+            None,
+        );
+        let child = ContentChunk::new(file_id, "Parent section".to_string(), 0, None, None, None)
+            .with_parent_chunk_id(Some(parent.id()));
+        let flat = ContentChunk::new(file_id, "Standalone".to_string(), 1, None, None, None);
+        let chunks = vec![parent, child.clone(), flat.clone()];
+
+        let leaves = ContentChunk::leaves(&chunks);
+
+        assert_eq!(leaves, vec![&child, &flat]);
+        assert_eq!(child.parent_chunk_id(), Some(chunks[0].id()));
+    }
 }
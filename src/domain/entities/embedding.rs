@@ -105,6 +105,48 @@ impl Embedding {
     pub fn is_from_model(&self, model_name: &str, model_version: Option<&str>) -> bool {
         self.model_name == model_name && self.model_version.as_deref() == model_version
     }
+
+    /// The first `dimension` values rescaled to unit length. For a model
+    /// trained with Matryoshka representation learning this is the vector
+    /// the model itself would return at that size.
+    pub fn truncate_vector(vector: &Vector, dimension: usize) -> Result<Vector, String> {
+        let values = vector.as_slice();
+        if dimension == 0 || dimension > values.len() {
+            return Err(format!(
+                "Cannot truncate a {}-dimensional vector to {} dimensions",
+                values.len(),
+                dimension
+            ));
+        }
+
+        let truncated = &values[..dimension];
+        let norm = truncated.iter().map(|x| x * x).sum::<f32>().sqrt();
+        if norm == 0.0 {
+            return Err("Cannot normalize a zero vector".to_string());
+        }
+        Ok(Vector::from(
+            truncated.iter().map(|x| x / norm).collect::<Vec<f32>>(),
+        ))
+    }
+
+    /// This embedding shortened to `dimension`, stored as `model_name`
+    pub fn truncated(&self, model_name: String, dimension: usize) -> Result<Embedding, String> {
+        let mut generation_parameters = self
+            .generation_parameters
+            .clone()
+            .filter(|parameters| parameters.is_object())
+            .unwrap_or_else(|| serde_json::json!({}));
+        generation_parameters["truncated_from"] =
+            serde_json::Value::String(self.model_name.clone());
+
+        Ok(Embedding::new(
+            self.content_chunk_id,
+            model_name,
+            self.model_version.clone(),
+            Some(generation_parameters),
+            Self::truncate_vector(&self.embedding, dimension)?,
+        ))
+    }
 }
 
 #[cfg(test)]
@@ -191,4 +233,25 @@ mod tests {
         let similarity = embedding1.cosine_similarity(&embedding2).unwrap();
         assert!((similarity - 1.0).abs() < 1e-6); // Should be 1.0 for identical vectors
     }
+
+    #[test]
+    fn test_truncated() {
+        let embedding = Embedding::new(
+            Uuid::new_v4(),
+            "test-model".to_string(),
+            None,
+            Some(serde_json::json!({ "template": "{text}" })),
+            create_test_vector(vec![3.0, 4.0, 12.0]),
+        );
+
+        let truncated = embedding.truncated("test-model@2".to_string(), 2).unwrap();
+
+        assert_eq!(truncated.embedding().as_slice(), &[0.6, 0.8]);
+        assert_eq!(truncated.model_name(), "test-model@2");
+        assert_eq!(truncated.content_chunk_id(), embedding.content_chunk_id());
+        let parameters = truncated.generation_parameters().unwrap();
+        assert_eq!(parameters["truncated_from"], "test-model");
+        assert_eq!(parameters["template"], "{text}");
+        assert!(embedding.truncated("test-model@4".to_string(), 4).is_err());
+    }
 }
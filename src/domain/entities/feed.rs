@@ -0,0 +1,338 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::value_objects::FileHash;
+
+pub const DEFAULT_POLL_INTERVAL_SECONDS: i32 = 900;
+pub const MIN_POLL_INTERVAL_SECONDS: i32 = 60;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Feed {
+    id: Uuid,
+    url: String,
+    name: String,
+    poll_interval_seconds: i32,
+    is_active: bool,
+    last_polled_at: Option<DateTime<Utc>>,
+    last_error: Option<String>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl Feed {
+    pub fn new(url: String, name: String, poll_interval_seconds: i32) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            url,
+            name,
+            poll_interval_seconds: poll_interval_seconds.max(MIN_POLL_INTERVAL_SECONDS),
+            is_active: true,
+            last_polled_at: None,
+            last_error: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// Create a Feed from database values (for repository reconstruction)
+    #[allow(clippy::too_many_arguments)]
+    pub fn from_database(
+        id: Uuid,
+        url: String,
+        name: String,
+        poll_interval_seconds: i32,
+        is_active: bool,
+        last_polled_at: Option<DateTime<Utc>>,
+        last_error: Option<String>,
+        created_at: DateTime<Utc>,
+        updated_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            url,
+            name,
+            poll_interval_seconds,
+            is_active,
+            last_polled_at,
+            last_error,
+            created_at,
+            updated_at,
+        }
+    }
+
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn poll_interval_seconds(&self) -> i32 {
+        self.poll_interval_seconds
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.is_active
+    }
+
+    pub fn last_polled_at(&self) -> Option<DateTime<Utc>> {
+        self.last_polled_at
+    }
+
+    pub fn last_error(&self) -> Option<&str> {
+        self.last_error.as_deref()
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    pub fn updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+
+    // Business logic methods
+    pub fn next_poll_at(&self) -> Option<DateTime<Utc>> {
+        self.last_polled_at
+            .map(|polled| polled + Duration::seconds(self.poll_interval_seconds as i64))
+    }
+
+    pub fn is_due(&self, now: DateTime<Utc>) -> bool {
+        if !self.is_active {
+            return false;
+        }
+
+        match self.next_poll_at() {
+            Some(next) => next <= now,
+            None => true, // Never polled
+        }
+    }
+
+    pub fn mark_polled(&mut self, now: DateTime<Utc>) {
+        self.last_polled_at = Some(now);
+        self.last_error = None;
+        self.updated_at = now;
+    }
+
+    pub fn mark_failed(&mut self, now: DateTime<Utc>, error: String) {
+        // Still record the attempt so a broken feed waits a full interval
+        self.last_polled_at = Some(now);
+        self.last_error = Some(error);
+        self.updated_at = now;
+    }
+
+    pub fn set_active(&mut self, active: bool) {
+        self.is_active = active;
+        self.updated_at = Utc::now();
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FeedEntry {
+    id: Uuid,
+    feed_id: Uuid,
+    guid: String,
+    link: String,
+    title: Option<String>,
+    published_at: Option<DateTime<Utc>>,
+    content_hash: String,
+    file_id: Option<Uuid>,
+    first_seen_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl FeedEntry {
+    pub fn new(
+        feed_id: Uuid,
+        guid: String,
+        link: String,
+        title: Option<String>,
+        published_at: Option<DateTime<Utc>>,
+        content_hash: String,
+        file_id: Option<Uuid>,
+    ) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            feed_id,
+            guid,
+            link,
+            title,
+            published_at,
+            content_hash,
+            file_id,
+            first_seen_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// Create a FeedEntry from database values (for repository reconstruction)
+    #[allow(clippy::too_many_arguments)]
+    pub fn from_database(
+        id: Uuid,
+        feed_id: Uuid,
+        guid: String,
+        link: String,
+        title: Option<String>,
+        published_at: Option<DateTime<Utc>>,
+        content_hash: String,
+        file_id: Option<Uuid>,
+        first_seen_at: DateTime<Utc>,
+        updated_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            feed_id,
+            guid,
+            link,
+            title,
+            published_at,
+            content_hash,
+            file_id,
+            first_seen_at,
+            updated_at,
+        }
+    }
+
+    /// Fingerprint of the fields that mark an entry as updated upstream
+    pub fn compute_hash(
+        link: &str,
+        title: Option<&str>,
+        updated_at: Option<DateTime<Utc>>,
+    ) -> String {
+        let stamp = updated_at.map(|t| t.to_rfc3339()).unwrap_or_default();
+        let payload = format!("{}\n{}\n{}", link, title.unwrap_or(""), stamp);
+        FileHash::from_bytes(payload.as_bytes())
+            .as_str()
+            .to_string()
+    }
+
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    pub fn feed_id(&self) -> Uuid {
+        self.feed_id
+    }
+
+    pub fn guid(&self) -> &str {
+        &self.guid
+    }
+
+    pub fn link(&self) -> &str {
+        &self.link
+    }
+
+    pub fn title(&self) -> Option<&str> {
+        self.title.as_deref()
+    }
+
+    pub fn published_at(&self) -> Option<DateTime<Utc>> {
+        self.published_at
+    }
+
+    pub fn content_hash(&self) -> &str {
+        &self.content_hash
+    }
+
+    pub fn file_id(&self) -> Option<Uuid> {
+        self.file_id
+    }
+
+    pub fn first_seen_at(&self) -> DateTime<Utc> {
+        self.first_seen_at
+    }
+
+    pub fn updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+
+    pub fn is_changed(&self, content_hash: &str) -> bool {
+        self.content_hash != content_hash
+    }
+
+    pub fn apply_update(
+        &mut self,
+        link: String,
+        title: Option<String>,
+        published_at: Option<DateTime<Utc>>,
+        content_hash: String,
+        file_id: Uuid,
+    ) {
+        self.link = link;
+        self.title = title;
+        self.published_at = published_at;
+        self.content_hash = content_hash;
+        self.file_id = Some(file_id);
+        self.updated_at = Utc::now();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_feed_creation() {
+        let feed = Feed::new(
+            "https://example.com/feed.xml".to_string(),
+            "Example".to_string(),
+            10,
+        );
+
+        assert_eq!(feed.poll_interval_seconds(), MIN_POLL_INTERVAL_SECONDS);
+        assert!(feed.is_active());
+        assert!(feed.is_due(Utc::now()));
+    }
+
+    #[test]
+    fn test_feed_scheduling() {
+        let mut feed = Feed::new(
+            "https://example.com/feed.xml".to_string(),
+            "Example".to_string(),
+            600,
+        );
+        let now = Utc::now();
+
+        feed.mark_polled(now);
+        assert!(!feed.is_due(now + Duration::seconds(599)));
+        assert!(feed.is_due(now + Duration::seconds(600)));
+
+        feed.mark_failed(now, "timeout".to_string());
+        assert_eq!(feed.last_error(), Some("timeout"));
+        assert!(!feed.is_due(now));
+
+        feed.set_active(false);
+        assert!(!feed.is_due(now + Duration::days(1)));
+    }
+
+    #[test]
+    fn test_entry_change_detection() {
+        let link = "https://example.com/post";
+        let hash = FeedEntry::compute_hash(link, Some("Post"), None);
+        let entry = FeedEntry::new(
+            Uuid::new_v4(),
+            "guid-1".to_string(),
+            link.to_string(),
+            Some("Post".to_string()),
+            None,
+            hash.clone(),
+            None,
+        );
+
+        assert!(!entry.is_changed(&FeedEntry::compute_hash(link, Some("Post"), None)));
+        assert!(entry.is_changed(&FeedEntry::compute_hash(link, Some("Post (edited)"), None)));
+        assert!(entry.is_changed(&FeedEntry::compute_hash(
+            link,
+            Some("Post"),
+            Some(Utc::now())
+        )));
+    }
+}
@@ -153,6 +153,19 @@ impl File {
         self.metadata = Some(metadata);
         self.updated_at = Utc::now();
     }
+
+    pub fn update_content(
+        &mut self,
+        file_size: Option<i64>,
+        file_hash: FileHash,
+        file_type: Option<String>,
+    ) {
+        self.file_size = file_size;
+        self.file_hash = Some(file_hash);
+        self.file_type = file_type;
+        self.processing_status = ProcessingStatus::Pending;
+        self.updated_at = Utc::now();
+    }
 }
 
 #[cfg(test)]
@@ -215,4 +228,30 @@ mod tests {
             panic!("Expected failed status");
         }
     }
+
+    #[test]
+    fn test_content_update() {
+        let mut file = File::new(
+            "/watched/report.pdf".to_string(),
+            "report.pdf".to_string(),
+            Some(1024),
+            Some("application/pdf".to_string()),
+            Some(FileHash::from_bytes(b"v1")),
+            None,
+        );
+        file.start_processing().unwrap();
+        file.complete_processing().unwrap();
+
+        let new_hash = FileHash::from_bytes(b"v2");
+        file.update_content(
+            Some(2048),
+            new_hash.clone(),
+            Some("text/markdown".to_string()),
+        );
+
+        assert_eq!(file.file_size(), Some(2048));
+        assert_eq!(file.file_hash(), Some(&new_hash));
+        assert_eq!(file.file_type(), Some("text/markdown"));
+        assert!(file.is_processable());
+    }
 }
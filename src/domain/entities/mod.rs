@@ -1,10 +1,14 @@
 pub mod content_chunk;
 pub mod embedding;
+pub mod feed;
 pub mod file;
 pub mod processing_job;
 pub mod search_query;
+pub mod sparse_embedding;
 
 pub use content_chunk::ContentChunk;
 pub use embedding::Embedding;
+pub use feed::{Feed, FeedEntry};
 pub use file::File;
 pub use processing_job::ProcessingJob;
+pub use sparse_embedding::SparseEmbedding;
@@ -2,12 +2,13 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use crate::domain::value_objects::ProcessingStatus;
+use crate::domain::value_objects::{ChunkingConfig, ProcessingStatus};
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ProcessingJob {
     id: Uuid,
-    file_id: Uuid,
+    /// None for jobs that span the whole corpus
+    file_id: Option<Uuid>,
     job_type: JobType,
     status: ProcessingStatus,
     progress: f32, // 0.0 to 1.0
@@ -16,13 +17,23 @@ pub struct ProcessingJob {
     completed_at: Option<DateTime<Utc>>,
     error_message: Option<String>,
     result_summary: Option<JobResult>,
+    /// Chunking requested for this job; None reuses the file's recorded config
+    chunking_config: Option<ChunkingConfig>,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum JobType {
     FileProcessing,
-    UrlExtraction { url: String },
-    YoutubeExtraction { url: String },
+    UrlExtraction {
+        url: String,
+    },
+    YoutubeExtraction {
+        url: String,
+    },
+    /// Embeds every stored chunk that has no vector from `model_name` yet
+    Reembed {
+        model_name: String,
+    },
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -31,13 +42,25 @@ pub struct JobResult {
     pub embeddings_created: i32,
     pub processing_time_ms: u64,
     pub extracted_text_length: usize,
+    /// Chunks whose embedding came from the cache instead of the provider
+    #[serde(default)]
+    pub embedding_cache_hits: i32,
+}
+
+impl JobResult {
+    pub fn embedding_cache_hit_ratio(&self) -> f32 {
+        if self.embeddings_created == 0 {
+            return 0.0;
+        }
+        self.embedding_cache_hits as f32 / self.embeddings_created as f32
+    }
 }
 
 impl ProcessingJob {
     pub fn new_file_processing(file_id: Uuid) -> Self {
         Self {
             id: Uuid::new_v4(),
-            file_id,
+            file_id: Some(file_id),
             job_type: JobType::FileProcessing,
             status: ProcessingStatus::Pending,
             progress: 0.0,
@@ -46,13 +69,14 @@ impl ProcessingJob {
             completed_at: None,
             error_message: None,
             result_summary: None,
+            chunking_config: None,
         }
     }
 
     pub fn new_url_extraction(file_id: Uuid, url: String) -> Self {
         Self {
             id: Uuid::new_v4(),
-            file_id,
+            file_id: Some(file_id),
             job_type: JobType::UrlExtraction { url },
             status: ProcessingStatus::Pending,
             progress: 0.0,
@@ -61,13 +85,14 @@ impl ProcessingJob {
             completed_at: None,
             error_message: None,
             result_summary: None,
+            chunking_config: None,
         }
     }
 
     pub fn new_youtube_extraction(file_id: Uuid, url: String) -> Self {
         Self {
             id: Uuid::new_v4(),
-            file_id,
+            file_id: Some(file_id),
             job_type: JobType::YoutubeExtraction { url },
             status: ProcessingStatus::Pending,
             progress: 0.0,
@@ -76,13 +101,31 @@ impl ProcessingJob {
             completed_at: None,
             error_message: None,
             result_summary: None,
+            chunking_config: None,
+        }
+    }
+
+    /// Re-embeds the chunks of one file, or of the whole corpus when `file_id` is None
+    pub fn new_reembed(model_name: String, file_id: Option<Uuid>) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            file_id,
+            job_type: JobType::Reembed { model_name },
+            status: ProcessingStatus::Pending,
+            progress: 0.0,
+            created_at: Utc::now(),
+            started_at: None,
+            completed_at: None,
+            error_message: None,
+            result_summary: None,
+            chunking_config: None,
         }
     }
 
     /// Create a ProcessingJob from database values (for repository reconstruction)
     pub fn from_database(
         id: Uuid,
-        file_id: Uuid,
+        file_id: Option<Uuid>,
         job_type: JobType,
         status: ProcessingStatus,
         progress: f32,
@@ -103,14 +146,20 @@ impl ProcessingJob {
             completed_at,
             error_message,
             result_summary,
+            chunking_config: None,
         }
     }
 
+    pub fn with_chunking_config(mut self, chunking_config: Option<ChunkingConfig>) -> Self {
+        self.chunking_config = chunking_config;
+        self
+    }
+
     pub fn id(&self) -> Uuid {
         self.id
     }
 
-    pub fn file_id(&self) -> Uuid {
+    pub fn file_id(&self) -> Option<Uuid> {
         self.file_id
     }
 
@@ -146,6 +195,10 @@ impl ProcessingJob {
         self.result_summary.as_ref()
     }
 
+    pub fn chunking_config(&self) -> Option<&ChunkingConfig> {
+        self.chunking_config.as_ref()
+    }
+
     // Business logic methods
     pub fn start_processing(&mut self) -> Result<(), String> {
         if !self.status.is_pending() {
@@ -250,7 +303,7 @@ mod tests {
         let file_id = Uuid::new_v4();
         let job = ProcessingJob::new_file_processing(file_id);
 
-        assert_eq!(job.file_id(), file_id);
+        assert_eq!(job.file_id(), Some(file_id));
         assert_eq!(job.status(), &ProcessingStatus::Pending);
         assert_eq!(job.progress(), 0.0);
         assert!(job.is_active());
@@ -279,7 +332,9 @@ mod tests {
             embeddings_created: 10,
             processing_time_ms: 5000,
             extracted_text_length: 1000,
+            embedding_cache_hits: 4,
         };
+        assert_eq!(result.embedding_cache_hit_ratio(), 0.4);
         assert!(job.complete_processing(result).is_ok());
         assert_eq!(job.status(), &ProcessingStatus::Completed);
         assert!(job.completed_at().is_some());
@@ -316,4 +371,32 @@ mod tests {
             panic!("Expected URL extraction job type");
         }
     }
+
+    #[test]
+    fn test_reembed_job() {
+        let job = ProcessingJob::new_reembed("bge-m3".to_string(), None);
+
+        assert_eq!(job.file_id(), None);
+        assert_eq!(
+            job.job_type(),
+            &JobType::Reembed {
+                model_name: "bge-m3".to_string()
+            }
+        );
+        assert!(job.is_active());
+    }
+
+    #[test]
+    fn test_chunking_config() {
+        let job = ProcessingJob::new_file_processing(Uuid::new_v4());
+        assert_eq!(job.chunking_config(), None);
+
+        let config = ChunkingConfig {
+            strategy: Some("semantic".to_string()),
+            chunk_size: Some(256),
+            ..Default::default()
+        };
+        let job = job.with_chunking_config(Some(config.clone()));
+        assert_eq!(job.chunking_config(), Some(&config));
+    }
 }
@@ -0,0 +1,146 @@
+use chrono::{DateTime, Utc};
+use pgvector::SparseVector;
+use uuid::Uuid;
+
+/// Most non-zero weights a stored vector keeps; pgvector's HNSW index
+/// rejects sparse vectors with more than this
+pub const MAX_SPARSE_NON_ZEROS: usize = 1000;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SparseEmbedding {
+    id: Uuid,
+    content_chunk_id: Uuid,
+    model_name: String,
+    model_version: Option<String>,
+    generated_at: DateTime<Utc>,
+    embedding: SparseVector,
+}
+
+impl SparseEmbedding {
+    /// Keeps only the `MAX_SPARSE_NON_ZEROS` heaviest terms; the dropped
+    /// ones carry the least weight and barely move the score
+    pub fn new(
+        content_chunk_id: Uuid,
+        model_name: String,
+        model_version: Option<String>,
+        embedding: SparseVector,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            content_chunk_id,
+            model_name,
+            model_version,
+            generated_at: Utc::now(),
+            embedding: prune(embedding, MAX_SPARSE_NON_ZEROS),
+        }
+    }
+
+    pub fn from_database(
+        id: Uuid,
+        content_chunk_id: Uuid,
+        model_name: String,
+        model_version: Option<String>,
+        generated_at: DateTime<Utc>,
+        embedding: SparseVector,
+    ) -> Self {
+        Self {
+            id,
+            content_chunk_id,
+            model_name,
+            model_version,
+            generated_at,
+            embedding,
+        }
+    }
+
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    pub fn content_chunk_id(&self) -> Uuid {
+        self.content_chunk_id
+    }
+
+    pub fn model_name(&self) -> &str {
+        &self.model_name
+    }
+
+    pub fn model_version(&self) -> Option<&str> {
+        self.model_version.as_deref()
+    }
+
+    pub fn generated_at(&self) -> DateTime<Utc> {
+        self.generated_at
+    }
+
+    pub fn embedding(&self) -> &SparseVector {
+        &self.embedding
+    }
+
+    pub fn non_zeros(&self) -> usize {
+        self.embedding.indices().len()
+    }
+
+    /// The score sparse retrieval ranks by
+    pub fn inner_product(&self, query: &SparseVector) -> f32 {
+        let (indices, values) = (self.embedding.indices(), self.embedding.values());
+        let (query_indices, query_values) = (query.indices(), query.values());
+
+        // Both index lists are sorted, so one merge pass finds the shared terms
+        let (mut i, mut j, mut score) = (0, 0, 0.0);
+        while i < indices.len() && j < query_indices.len() {
+            match indices[i].cmp(&query_indices[j]) {
+                std::cmp::Ordering::Less => i += 1,
+                std::cmp::Ordering::Greater => j += 1,
+                std::cmp::Ordering::Equal => {
+                    score += values[i] * query_values[j];
+                    i += 1;
+                    j += 1;
+                }
+            }
+        }
+        score
+    }
+}
+
+fn prune(vector: SparseVector, max_non_zeros: usize) -> SparseVector {
+    if vector.indices().len() <= max_non_zeros {
+        return vector;
+    }
+
+    let mut terms: Vec<(&i32, &f32)> = vector.indices().iter().zip(vector.values()).collect();
+    terms.sort_by(|(_, a), (_, b)| b.abs().total_cmp(&a.abs()));
+    terms.truncate(max_non_zeros);
+    SparseVector::from_map(terms, vector.dimensions())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keeps_heaviest_terms() {
+        let weights: Vec<(i32, f32)> = (0..1200).map(|i| (i, (i + 1) as f32)).collect();
+        let vector = SparseVector::from_map(weights.iter().map(|(i, w)| (i, w)), 30522);
+
+        let embedding = SparseEmbedding::new(Uuid::new_v4(), "splade".to_string(), None, vector);
+
+        assert_eq!(embedding.non_zeros(), MAX_SPARSE_NON_ZEROS);
+        assert_eq!(embedding.embedding().dimensions(), 30522);
+        assert_eq!(embedding.embedding().indices()[0], 200);
+        assert_eq!(embedding.embedding().indices().last(), Some(&1199));
+    }
+
+    #[test]
+    fn test_inner_product() {
+        let document = SparseVector::from_map([(&3, &0.5), (&7, &2.0), (&9, &1.0)], 10);
+        let query = SparseVector::from_map([(&1, &4.0), (&7, &1.5), (&9, &2.0)], 10);
+        let embedding = SparseEmbedding::new(Uuid::new_v4(), "splade".to_string(), None, document);
+
+        assert_eq!(embedding.inner_product(&query), 5.0);
+        assert_eq!(
+            embedding.inner_product(&SparseVector::from_map([(&4, &1.0)], 10)),
+            0.0
+        );
+    }
+}
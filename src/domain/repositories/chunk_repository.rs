@@ -22,6 +22,14 @@ impl std::fmt::Display for ChunkRepositoryError {
 
 impl std::error::Error for ChunkRepositoryError {}
 
+/// Chunks `start..=end` by `chunk_index` within one file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChunkIndexRange {
+    pub file_id: Uuid,
+    pub start: i32,
+    pub end: i32,
+}
+
 #[async_trait]
 pub trait ChunkRepository: Send + Sync {
     // async fn save(&self, chunk: &ContentChunk) -> Result<(), ChunkRepositoryError>;
@@ -34,7 +42,28 @@ pub trait ChunkRepository: Send + Sync {
         skip: i64,
         limit: i64,
     ) -> Result<Vec<ContentChunk>, ChunkRepositoryError>;
+    /// Every chunk in any of the ranges, ordered by file and index, in one query
+    async fn find_by_index_ranges(
+        &self,
+        ranges: &[ChunkIndexRange],
+    ) -> Result<Vec<ContentChunk>, ChunkRepositoryError>;
     async fn delete(&self, id: Uuid) -> Result<bool, ChunkRepositoryError>;
     async fn delete_by_file_id(&self, file_id: Uuid) -> Result<i64, ChunkRepositoryError>;
     async fn count_by_file_id(&self, file_id: Uuid) -> Result<i64, ChunkRepositoryError>;
+    /// Chunk counts per language, most common first
+    async fn count_by_language(&self) -> Result<Vec<(String, i64)>, ChunkRepositoryError>;
+    /// Embeddable chunks (those that are nobody's parent) with no vector from
+    /// `model_name`, in id order starting after `after`
+    async fn find_missing_embeddings(
+        &self,
+        model_name: &str,
+        file_id: Option<Uuid>,
+        after: Option<Uuid>,
+        limit: i64,
+    ) -> Result<Vec<ContentChunk>, ChunkRepositoryError>;
+    async fn count_missing_embeddings(
+        &self,
+        model_name: &str,
+        file_id: Option<Uuid>,
+    ) -> Result<i64, ChunkRepositoryError>;
 }
@@ -0,0 +1,35 @@
+use async_trait::async_trait;
+use pgvector::Vector;
+
+use crate::domain::value_objects::EmbeddingCacheKey;
+
+#[derive(Debug)]
+pub enum EmbeddingCacheRepositoryError {
+    DatabaseError(String),
+}
+
+impl std::fmt::Display for EmbeddingCacheRepositoryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EmbeddingCacheRepositoryError::DatabaseError(msg) => {
+                write!(f, "Database error: {}", msg)
+            }
+        }
+    }
+}
+
+impl std::error::Error for EmbeddingCacheRepositoryError {}
+
+#[async_trait]
+pub trait EmbeddingCacheRepository: Send + Sync {
+    /// Cached vectors for whichever of `keys` are present
+    async fn find_many(
+        &self,
+        keys: &[EmbeddingCacheKey],
+    ) -> Result<Vec<(EmbeddingCacheKey, Vector)>, EmbeddingCacheRepositoryError>;
+    /// Stores the entries, keeping any that are already cached
+    async fn save_many(
+        &self,
+        entries: &[(EmbeddingCacheKey, Vector)],
+    ) -> Result<(), EmbeddingCacheRepositoryError>;
+}
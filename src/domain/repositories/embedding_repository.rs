@@ -7,6 +7,8 @@ use crate::domain::entities::Embedding;
 #[derive(Debug)]
 pub enum EmbeddingRepositoryError {
     NotFound(Uuid),
+    /// No embedding model is registered under this name
+    ModelNotFound(String),
     DatabaseError(String),
     ValidationError(String),
     // VectorError(String),
@@ -16,6 +18,9 @@ impl std::fmt::Display for EmbeddingRepositoryError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             EmbeddingRepositoryError::NotFound(id) => write!(f, "Embedding not found: {}", id),
+            EmbeddingRepositoryError::ModelNotFound(name) => {
+                write!(f, "Embedding model not registered: {}", name)
+            }
             EmbeddingRepositoryError::DatabaseError(msg) => write!(f, "Database error: {}", msg),
             EmbeddingRepositoryError::ValidationError(msg) => write!(f, "Validation error: {}", msg),
             // EmbeddingRepositoryError::VectorError(msg) => write!(f, "Vector error: {}", msg),
@@ -32,6 +37,17 @@ pub struct SimilaritySearchResult {
     pub chunk_id: Uuid,
 }
 
+/// Restricts a similarity search to part of the corpus
+#[derive(Debug, Clone, Default)]
+pub struct SimilaritySearchFilter {
+    pub file_id: Option<Uuid>,
+    /// Chunk languages to include; empty means all languages
+    pub languages: Vec<String>,
+    /// Only vectors from this embedding model; vectors of different models
+    /// cannot be compared
+    pub model_name: Option<String>,
+}
+
 #[async_trait]
 pub trait EmbeddingRepository: Send + Sync {
     async fn save(&self, embedding: &Embedding) -> Result<(), EmbeddingRepositoryError>;
@@ -39,6 +55,12 @@ pub trait EmbeddingRepository: Send + Sync {
     async fn find_by_id(&self, id: Uuid) -> Result<Option<Embedding>, EmbeddingRepositoryError>;
     async fn find_by_chunk_id(&self, chunk_id: Uuid) -> Result<Option<Embedding>, EmbeddingRepositoryError>;
     async fn find_by_file_id(&self, file_id: Uuid) -> Result<Vec<Embedding>, EmbeddingRepositoryError>;
+    /// The vectors `model_name` stored for any of `chunk_ids`
+    async fn find_by_chunk_ids(
+        &self,
+        chunk_ids: &[Uuid],
+        model_name: &str,
+    ) -> Result<Vec<Embedding>, EmbeddingRepositoryError>;
     async fn similarity_search(
         &self,
         query_vector: &Vector,
@@ -52,10 +74,29 @@ pub trait EmbeddingRepository: Send + Sync {
         limit: i32,
         similarity_threshold: Option<f32>,
     ) -> Result<Vec<SimilaritySearchResult>, EmbeddingRepositoryError>;
+    async fn similarity_search_filtered(
+        &self,
+        query_vector: &Vector,
+        limit: i32,
+        similarity_threshold: Option<f32>,
+        filter: &SimilaritySearchFilter,
+    ) -> Result<Vec<SimilaritySearchResult>, EmbeddingRepositoryError>;
     // async fn update(&self, embedding: &Embedding) -> Result<(), EmbeddingRepositoryError>;
     async fn delete(&self, id: Uuid) -> Result<bool, EmbeddingRepositoryError>;
     async fn delete_by_chunk_id(&self, chunk_id: Uuid) -> Result<bool, EmbeddingRepositoryError>;
     async fn delete_by_file_id(&self, file_id: Uuid) -> Result<i64, EmbeddingRepositoryError>;
     async fn count(&self) -> Result<i64, EmbeddingRepositoryError>;
+    /// Records that `model_name` stores vectors of `dimension` and prepares
+    /// its vector index. Returns the dimension the model was first registered
+    /// with, which differs from `dimension` if the model has changed.
+    async fn register_model(
+        &self,
+        model_name: &str,
+        dimension: usize,
+    ) -> Result<usize, EmbeddingRepositoryError>;
+    /// The model searches default to, if one has been activated
+    async fn active_model(&self) -> Result<Option<String>, EmbeddingRepositoryError>;
+    /// Makes `model_name` the only active model in one transaction
+    async fn activate_model(&self, model_name: &str) -> Result<(), EmbeddingRepositoryError>;
     // async fn count_by_model(&self, model_name: &str) -> Result<i64, EmbeddingRepositoryError>;
 }
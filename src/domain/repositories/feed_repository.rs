@@ -0,0 +1,40 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::entities::{Feed, FeedEntry};
+
+#[derive(Debug)]
+pub enum FeedRepositoryError {
+    NotFound(Uuid),
+    DatabaseError(String),
+}
+
+impl std::fmt::Display for FeedRepositoryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FeedRepositoryError::NotFound(id) => write!(f, "Feed not found: {}", id),
+            FeedRepositoryError::DatabaseError(msg) => write!(f, "Database error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for FeedRepositoryError {}
+
+#[async_trait]
+pub trait FeedRepository: Send + Sync {
+    async fn save(&self, feed: &Feed) -> Result<(), FeedRepositoryError>;
+    async fn find_by_id(&self, feed_id: Uuid) -> Result<Option<Feed>, FeedRepositoryError>;
+    async fn find_by_url(&self, url: &str) -> Result<Option<Feed>, FeedRepositoryError>;
+    async fn find_all(&self) -> Result<Vec<Feed>, FeedRepositoryError>;
+    async fn find_active(&self) -> Result<Vec<Feed>, FeedRepositoryError>;
+    async fn update(&self, feed: &Feed) -> Result<(), FeedRepositoryError>;
+    async fn delete(&self, feed_id: Uuid) -> Result<bool, FeedRepositoryError>;
+
+    async fn find_entry(
+        &self,
+        feed_id: Uuid,
+        guid: &str,
+    ) -> Result<Option<FeedEntry>, FeedRepositoryError>;
+    async fn find_entries(&self, feed_id: Uuid) -> Result<Vec<FeedEntry>, FeedRepositoryError>;
+    async fn save_entry(&self, entry: &FeedEntry) -> Result<(), FeedRepositoryError>;
+}
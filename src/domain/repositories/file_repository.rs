@@ -29,6 +29,8 @@ pub trait FileRepository: Send + Sync {
     async fn save(&self, file: &File) -> Result<Uuid, FileRepositoryError>;
     async fn find_by_id(&self, id: Uuid) -> Result<Option<File>, FileRepositoryError>;
     async fn find_by_hash(&self, hash: &str) -> Result<Option<File>, FileRepositoryError>;
+    async fn find_by_path(&self, path: &str) -> Result<Option<File>, FileRepositoryError>;
+    async fn find_by_path_prefix(&self, prefix: &str) -> Result<Vec<File>, FileRepositoryError>;
     async fn find_all(&self, skip: i64, limit: i64) -> Result<Vec<File>, FileRepositoryError>;
     async fn update(&self, file: &File) -> Result<(), FileRepositoryError>;
     async fn delete(&self, id: Uuid) -> Result<bool, FileRepositoryError>;
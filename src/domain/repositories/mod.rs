@@ -1,9 +1,15 @@
 pub mod file_repository;
 pub mod chunk_repository;
 pub mod embedding_repository;
+pub mod embedding_cache_repository;
 pub mod job_repository;
+pub mod feed_repository;
+pub mod sparse_embedding_repository;
 
 pub use file_repository::FileRepository;
 pub use chunk_repository::ChunkRepository;
 pub use embedding_repository::EmbeddingRepository;
+pub use embedding_cache_repository::EmbeddingCacheRepository;
 pub use job_repository::JobRepository;
+pub use feed_repository::FeedRepository;
+pub use sparse_embedding_repository::SparseEmbeddingRepository;
@@ -0,0 +1,39 @@
+use async_trait::async_trait;
+use pgvector::SparseVector;
+use uuid::Uuid;
+
+use crate::domain::entities::SparseEmbedding;
+use crate::domain::repositories::embedding_repository::{
+    EmbeddingRepositoryError, SimilaritySearchFilter,
+};
+
+#[derive(Debug, Clone)]
+pub struct SparseSearchResult {
+    pub chunk_id: Uuid,
+    /// Inner product with the query; unbounded, so only comparable within one search
+    pub score: f32,
+}
+
+#[async_trait]
+pub trait SparseEmbeddingRepository: Send + Sync {
+    async fn save_batch(
+        &self,
+        embeddings: &[SparseEmbedding],
+    ) -> Result<(), EmbeddingRepositoryError>;
+    /// Highest inner product first. `filter.model_name` must name the sparse
+    /// model whose vectors are searched.
+    async fn search(
+        &self,
+        query_vector: &SparseVector,
+        limit: i32,
+        filter: &SimilaritySearchFilter,
+    ) -> Result<Vec<SparseSearchResult>, EmbeddingRepositoryError>;
+    /// Records that `model_name` stores vectors over a vocabulary of
+    /// `dimension` and prepares its index. Returns the dimension the model
+    /// was first registered with.
+    async fn register_model(
+        &self,
+        model_name: &str,
+        dimension: usize,
+    ) -> Result<usize, EmbeddingRepositoryError>;
+}
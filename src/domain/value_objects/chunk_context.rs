@@ -0,0 +1,160 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::entities::ContentChunk;
+
+/// Shortest shared edge treated as window overlap rather than coincidence
+const MIN_OVERLAP_BYTES: usize = 16;
+
+/// Where a search hit sits inside stitched context, in characters
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HitSpan {
+    pub chunk_id: Uuid,
+    pub start: usize,
+    pub end: usize,
+}
+
+/// Consecutive chunks of one file stitched into a single passage around one
+/// or more search hits
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChunkContext {
+    pub file_id: Uuid,
+    pub first_chunk_index: i32,
+    pub last_chunk_index: i32,
+    pub text: String,
+    /// Offsets of the hits within `text`, in the order they were given
+    pub hits: Vec<HitSpan>,
+}
+
+impl ChunkContext {
+    /// Joins `chunks` (consecutive, in index order) into one text. Text that
+    /// overlapping windows share is written once; other chunks are separated
+    /// by a blank line.
+    pub fn stitch(chunks: &[ContentChunk], hit_ids: &[Uuid]) -> Option<Self> {
+        let first = chunks.first()?;
+        let last = chunks.last()?;
+
+        let mut text = String::new();
+        let mut text_chars = 0;
+        let mut spans = Vec::with_capacity(chunks.len());
+
+        for chunk in chunks {
+            let chunk_text = chunk.chunk_text().trim();
+            let overlap = if text.is_empty() {
+                0
+            } else {
+                overlap_len(&text, chunk_text)
+            };
+
+            let start = if overlap > 0 {
+                text_chars - chunk_text[..overlap].chars().count()
+            } else {
+                if !text.is_empty() {
+                    text.push_str("\n\n");
+                    text_chars += 2;
+                }
+                text_chars
+            };
+
+            let appended = &chunk_text[overlap..];
+            text.push_str(appended);
+            text_chars += appended.chars().count();
+            spans.push((chunk.id(), start, start + chunk_text.chars().count()));
+        }
+
+        let hits = hit_ids
+            .iter()
+            .filter_map(|hit_id| {
+                spans
+                    .iter()
+                    .find(|(chunk_id, _, _)| chunk_id == hit_id)
+                    .map(|(chunk_id, start, end)| HitSpan {
+                        chunk_id: *chunk_id,
+                        start: *start,
+                        end: *end,
+                    })
+            })
+            .collect();
+
+        Some(Self {
+            file_id: first.file_id(),
+            first_chunk_index: first.chunk_index(),
+            last_chunk_index: last.chunk_index(),
+            text,
+            hits,
+        })
+    }
+}
+
+/// Bytes at the start of `next` that repeat the end of `text`
+fn overlap_len(text: &str, next: &str) -> usize {
+    let window = text.len().min(next.len());
+    let mut tail_start = text.len() - window;
+    while !text.is_char_boundary(tail_start) {
+        tail_start += 1;
+    }
+    let tail = &text[tail_start..];
+
+    tail.char_indices()
+        .map(|(index, _)| index)
+        .take_while(|index| tail.len() - index >= MIN_OVERLAP_BYTES)
+        .find(|index| next.starts_with(&tail[*index..]))
+        .map_or(0, |index| tail.len() - index)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chunk(file_id: Uuid, index: i32, text: &str) -> ContentChunk {
+        ContentChunk::new(file_id, text.to_string(), index, None, None, None)
+    }
+
+    #[test]
+    fn test_stitch_writes_overlap_once() {
+        let file_id = Uuid::new_v4();
+        let chunks = vec![
+            chunk(file_id, 16, "The quick brown fox jumps over the lazy dog"),
+            chunk(
+                file_id,
+                17,
+                "jumps over the lazy dog and runs into the forest",
+            ),
+        ];
+
+        let context = ChunkContext::stitch(&chunks, &[chunks[1].id()]).unwrap();
+
+        assert_eq!(
+            context.text,
+            "The quick brown fox jumps over the lazy dog and runs into the forest"
+        );
+        let hit = &context.hits[0];
+        let hit_text: String = context
+            .text
+            .chars()
+            .skip(hit.start)
+            .take(hit.end - hit.start)
+            .collect();
+        assert_eq!(hit_text, chunks[1].chunk_text());
+        assert_eq!(
+            (context.first_chunk_index, context.last_chunk_index),
+            (16, 17)
+        );
+    }
+
+    #[test]
+    fn test_stitch_separates_distinct_chunks() {
+        let file_id = Uuid::new_v4();
+        let chunks = vec![
+            chunk(file_id, 0, "Première phrase."),
+            chunk(file_id, 1, "Second sentence."),
+        ];
+
+        let context = ChunkContext::stitch(&chunks, &[chunks[1].id()]).unwrap();
+
+        assert_eq!(context.text, "Première phrase.\n\nSecond sentence.");
+        assert_eq!(context.hits[0].start, 18);
+        assert_eq!(context.hits[0].end, 34);
+        assert!(ChunkContext::stitch(&[], &[]).is_none());
+    }
+}
@@ -0,0 +1,159 @@
+use serde::{Deserialize, Serialize};
+
+use super::EmbeddingTextTemplate;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ChunkSizeUnit {
+    /// Tokens of the embedding model's tokenizer
+    #[default]
+    Tokens,
+    Words,
+    Characters,
+}
+
+impl ChunkSizeUnit {
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value.trim().to_lowercase().as_str() {
+            "tokens" | "token" => Ok(ChunkSizeUnit::Tokens),
+            "words" | "word" => Ok(ChunkSizeUnit::Words),
+            "characters" | "character" | "chars" => Ok(ChunkSizeUnit::Characters),
+            other => Err(format!(
+                "Unknown chunk unit '{}'; expected tokens, words or characters",
+                other
+            )),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ChunkSizeUnit::Tokens => "tokens",
+            ChunkSizeUnit::Words => "words",
+            ChunkSizeUnit::Characters => "characters",
+        }
+    }
+}
+
+/// How a document is split into chunks. Unset fields fall back to the
+/// server defaults; the resolved config is recorded on the file so
+/// reprocessing chunks it the same way.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ChunkingConfig {
+    pub strategy: Option<String>,
+    pub chunk_size: Option<usize>,
+    pub chunk_overlap: Option<usize>,
+    #[serde(default)]
+    pub unit: ChunkSizeUnit,
+    /// When set, chunks are cut from parent chunks of this size (in `unit`);
+    /// only the children are embedded and search can expand them to the parent
+    pub parent_chunk_size: Option<usize>,
+    /// Embed chunks behind a `title > section_path` header
+    #[serde(default)]
+    pub contextual_headers: bool,
+}
+
+impl ChunkingConfig {
+    pub const STRATEGIES: [&'static str; 5] = [
+        "token_window",
+        "recursive",
+        "sentence",
+        "markdown",
+        "semantic",
+    ];
+
+    pub fn embedding_template(&self) -> EmbeddingTextTemplate {
+        if self.contextual_headers {
+            EmbeddingTextTemplate::ContextualHeader
+        } else {
+            EmbeddingTextTemplate::ChunkOnly
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if let Some(strategy) = &self.strategy
+            && !Self::STRATEGIES.contains(&strategy.as_str())
+        {
+            return Err(format!(
+                "Unknown chunking strategy '{}'; expected one of {}",
+                strategy,
+                Self::STRATEGIES.join(", ")
+            ));
+        }
+
+        if let Some(parent_size) = self.parent_chunk_size
+            && parent_size <= self.chunk_size.unwrap_or(0)
+        {
+            return Err("parent_chunk_size must be larger than chunk_size".to_string());
+        }
+
+        match (self.chunk_size, self.chunk_overlap) {
+            (Some(0), _) => Err("chunk_size must be greater than 0".to_string()),
+            (Some(size), Some(overlap)) if overlap >= size => {
+                Err("chunk_overlap must be smaller than chunk_size".to_string())
+            }
+            (None, _) if self.unit != ChunkSizeUnit::Tokens => Err(format!(
+                "chunk_size is required when the unit is {}",
+                self.unit.as_str()
+            )),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate() {
+        assert!(ChunkingConfig::default().validate().is_ok());
+
+        let config = ChunkingConfig {
+            strategy: Some("markdown".to_string()),
+            chunk_size: Some(200),
+            chunk_overlap: Some(20),
+            unit: ChunkSizeUnit::Words,
+            parent_chunk_size: Some(800),
+            contextual_headers: true,
+        };
+        assert!(config.validate().is_ok());
+
+        let small_parent = ChunkingConfig {
+            parent_chunk_size: Some(100),
+            ..config.clone()
+        };
+        assert!(small_parent.validate().is_err());
+
+        let unknown = ChunkingConfig {
+            strategy: Some("paragraph".to_string()),
+            ..Default::default()
+        };
+        assert!(unknown.validate().is_err());
+
+        let overlapping = ChunkingConfig {
+            chunk_size: Some(100),
+            chunk_overlap: Some(100),
+            ..Default::default()
+        };
+        assert!(overlapping.validate().is_err());
+
+        let unsized_words = ChunkingConfig {
+            unit: ChunkSizeUnit::Words,
+            ..Default::default()
+        };
+        assert!(unsized_words.validate().is_err());
+    }
+
+    #[test]
+    fn test_unit_round_trip() {
+        assert_eq!(
+            ChunkSizeUnit::parse("Chars").unwrap(),
+            ChunkSizeUnit::Characters
+        );
+        assert_eq!(
+            serde_json::to_value(ChunkSizeUnit::Words).unwrap(),
+            serde_json::json!("words")
+        );
+        assert!(ChunkSizeUnit::parse("pages").is_err());
+    }
+}
@@ -0,0 +1,52 @@
+use sha2::{Digest, Sha256};
+
+/// Identifies a reusable embedding: the same input text embedded by the same
+/// model under the same template always yields the same vector
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct EmbeddingCacheKey {
+    /// SHA-256 of the whitespace-normalised text sent to the model
+    pub text_hash: String,
+    pub model_name: String,
+    pub model_version: Option<String>,
+    pub template: String,
+}
+
+impl EmbeddingCacheKey {
+    pub fn new(text: &str, model_name: &str, model_version: Option<&str>, template: &str) -> Self {
+        let mut hasher = Sha256::new();
+        hasher.update(Self::normalize(text).as_bytes());
+
+        Self {
+            text_hash: format!("{:x}", hasher.finalize()),
+            model_name: model_name.to_string(),
+            model_version: model_version.map(String::from),
+            template: template.to_string(),
+        }
+    }
+
+    /// Whitespace differences do not change what a chunk means, so they do
+    /// not get their own cache entry
+    fn normalize(text: &str) -> String {
+        text.split_whitespace().collect::<Vec<_>>().join(" ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_key_ignores_whitespace() {
+        let key = EmbeddingCacheKey::new("Hello  world\n", "bge-m3", None, "{chunk_text}");
+        let same = EmbeddingCacheKey::new(" Hello world", "bge-m3", None, "{chunk_text}");
+        assert_eq!(key, same);
+        assert_eq!(key.text_hash.len(), 64);
+
+        let other_case = EmbeddingCacheKey::new("hello world", "bge-m3", None, "{chunk_text}");
+        assert_ne!(key, other_case);
+
+        let other_model =
+            EmbeddingCacheKey::new("Hello world", "bge-m3", Some("2"), "{chunk_text}");
+        assert_ne!(key, other_model);
+    }
+}
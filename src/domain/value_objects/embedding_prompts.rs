@@ -0,0 +1,109 @@
+use serde::Deserialize;
+
+/// What a text is embedded for; asymmetric models such as E5 or
+/// Qwen3-Embedding expect queries and passages to be marked differently
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum EmbeddingPurpose {
+    Query,
+    #[default]
+    Document,
+}
+
+/// How one model is told what a text is for: either a prompt the server
+/// applies by name (TEI's `prompt_name`), or a template the text is rendered
+/// into before it is sent. Templates mark the text with `{text}`; without
+/// it they are used as a prefix.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct EmbeddingPrompts {
+    pub query_prompt_name: Option<String>,
+    pub document_prompt_name: Option<String>,
+    pub query_template: Option<String>,
+    pub document_template: Option<String>,
+}
+
+impl EmbeddingPrompts {
+    pub fn prompt_name(&self, purpose: EmbeddingPurpose) -> Option<&str> {
+        match purpose {
+            EmbeddingPurpose::Query => self.query_prompt_name.as_deref(),
+            EmbeddingPurpose::Document => self.document_prompt_name.as_deref(),
+        }
+    }
+
+    pub fn has_prompt_names(&self) -> bool {
+        self.query_prompt_name.is_some() || self.document_prompt_name.is_some()
+    }
+
+    /// The text to send for `purpose`, rendered into its template if it has one
+    pub fn render(&self, purpose: EmbeddingPurpose, text: &str) -> String {
+        let template = match purpose {
+            EmbeddingPurpose::Query => self.query_template.as_deref(),
+            EmbeddingPurpose::Document => self.document_template.as_deref(),
+        };
+
+        match template {
+            Some(template) if template.contains("{text}") => template.replace("{text}", text),
+            Some(prefix) => format!("{}{}", prefix, text),
+            None => text.to_string(),
+        }
+    }
+
+    /// Identifies the instruction applied for `purpose`, so vectors produced
+    /// under different instructions are never mistaken for one another
+    pub fn instruction(&self, purpose: EmbeddingPurpose) -> Option<String> {
+        let template = match purpose {
+            EmbeddingPurpose::Query => self.query_template.as_deref(),
+            EmbeddingPurpose::Document => self.document_template.as_deref(),
+        };
+
+        match (self.prompt_name(purpose), template) {
+            (None, None) => None,
+            (Some(name), None) => Some(format!("prompt_name:{}", name)),
+            (None, Some(template)) => Some(template.to_string()),
+            (Some(name), Some(template)) => Some(format!("prompt_name:{} {}", name, template)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_templates() {
+        let prompts: EmbeddingPrompts = serde_json::from_str(
+            r#"{"query_template": "query: ", "document_template": "Passage: {text}\n"}"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            prompts.render(EmbeddingPurpose::Query, "what is rust"),
+            "query: what is rust"
+        );
+        assert_eq!(
+            prompts.render(EmbeddingPurpose::Document, "Rust is a language."),
+            "Passage: Rust is a language.\n"
+        );
+        assert_eq!(
+            EmbeddingPrompts::default().render(EmbeddingPurpose::Query, "what is rust"),
+            "what is rust"
+        );
+        assert!(!prompts.has_prompt_names());
+    }
+
+    #[test]
+    fn test_instruction() {
+        let prompts = EmbeddingPrompts {
+            query_prompt_name: Some("query".to_string()),
+            ..Default::default()
+        };
+
+        assert_eq!(prompts.prompt_name(EmbeddingPurpose::Query), Some("query"));
+        assert_eq!(
+            prompts.instruction(EmbeddingPurpose::Query).as_deref(),
+            Some("prompt_name:query")
+        );
+        assert_eq!(prompts.instruction(EmbeddingPurpose::Document), None);
+        assert!(serde_json::from_str::<EmbeddingPrompts>(r#"{"query_prefix": "q: "}"#).is_err());
+    }
+}
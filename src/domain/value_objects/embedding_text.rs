@@ -0,0 +1,78 @@
+use serde_json::json;
+
+use crate::domain::entities::ContentChunk;
+
+/// How a chunk is turned into the text sent to the embedding model. The
+/// stored chunk text is never changed, only what gets embedded.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum EmbeddingTextTemplate {
+    #[default]
+    ChunkOnly,
+    /// Prefixes the document title and section path; parts that are
+    /// missing are left out together with their separator
+    ContextualHeader,
+}
+
+impl EmbeddingTextTemplate {
+    pub fn template(&self) -> &'static str {
+        match self {
+            EmbeddingTextTemplate::ChunkOnly => "{chunk_text}",
+            EmbeddingTextTemplate::ContextualHeader => "{title} > {section_path} > {chunk_text}",
+        }
+    }
+
+    pub fn render(&self, title: Option<&str>, chunk: &ContentChunk) -> String {
+        match self {
+            EmbeddingTextTemplate::ChunkOnly => chunk.chunk_text().to_string(),
+            EmbeddingTextTemplate::ContextualHeader => [title, chunk.section_path()]
+                .into_iter()
+                .flatten()
+                .map(str::trim)
+                .filter(|part| !part.is_empty())
+                .chain(std::iter::once(chunk.chunk_text()))
+                .collect::<Vec<_>>()
+                .join(" > "),
+        }
+    }
+
+    /// Recorded with every embedding so it can be regenerated from the chunk
+    pub fn generation_parameters(&self) -> serde_json::Value {
+        json!({ "text_template": self.template() })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    #[test]
+    fn test_contextual_header() {
+        let chunk = ContentChunk::new(
+            Uuid::new_v4(),
+            "Run the installer.".to_string(),
+            3,
+            None,
+            None,
+            Some("Guide > Install".to_string()),
+        );
+        let template = EmbeddingTextTemplate::ContextualHeader;
+
+        assert_eq!(
+            template.render(Some("User Manual"), &chunk),
+            "User Manual > Guide > Install > Run the installer."
+        );
+        assert_eq!(
+            template.render(None, &chunk),
+            "Guide > Install > Run the installer."
+        );
+        assert_eq!(
+            EmbeddingTextTemplate::ChunkOnly.render(Some("User Manual"), &chunk),
+            "Run the installer."
+        );
+        assert_eq!(
+            template.generation_parameters()["text_template"],
+            "{title} > {section_path} > {chunk_text}"
+        );
+    }
+}
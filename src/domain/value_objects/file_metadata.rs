@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+use crate::domain::value_objects::ChunkingConfig;
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct FileMetadata {
     properties: HashMap<String, serde_json::Value>,
@@ -87,6 +89,41 @@ impl FileMetadata {
             .map(|s| s.to_string())
     }
 
+    /// Content type sent by the client; a hint only, the detected type is the file type
+    pub fn set_declared_content_type(&mut self, content_type: String) {
+        self.set_property(
+            "declared_content_type".to_string(),
+            serde_json::Value::String(content_type),
+        );
+    }
+
+    pub fn get_declared_content_type(&self) -> Option<String> {
+        self.get_property("declared_content_type")
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string())
+    }
+
+    pub fn set_encoding(&mut self, encoding: String) {
+        self.set_property("encoding".to_string(), serde_json::Value::String(encoding));
+    }
+
+    pub fn get_encoding(&self) -> Option<String> {
+        self.get_property("encoding")
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string())
+    }
+
+    pub fn set_chunking_config(&mut self, config: &ChunkingConfig) {
+        if let Ok(value) = serde_json::to_value(config) {
+            self.set_property("chunking".to_string(), value);
+        }
+    }
+
+    pub fn get_chunking_config(&self) -> Option<ChunkingConfig> {
+        self.get_property("chunking")
+            .and_then(|v| serde_json::from_value(v.clone()).ok())
+    }
+
     pub fn merge(&mut self, other: FileMetadata) {
         for (key, value) in other.properties {
             self.properties.insert(key, value);
@@ -158,11 +195,23 @@ mod tests {
         metadata.set_title("Test Document".to_string());
         metadata.set_page_count(10);
         metadata.set_language("en".to_string());
+        metadata.set_declared_content_type("text/plain".to_string());
+        metadata.set_encoding("UTF-8".to_string());
         
         assert_eq!(metadata.get_author().unwrap(), "John Doe");
         assert_eq!(metadata.get_title().unwrap(), "Test Document");
         assert_eq!(metadata.get_page_count().unwrap(), 10);
         assert_eq!(metadata.get_language().unwrap(), "en");
+        assert_eq!(metadata.get_declared_content_type().unwrap(), "text/plain");
+        assert_eq!(metadata.get_encoding().unwrap(), "UTF-8");
+
+        let chunking = ChunkingConfig {
+            strategy: Some("sentence".to_string()),
+            chunk_size: Some(200),
+            ..Default::default()
+        };
+        metadata.set_chunking_config(&chunking);
+        assert_eq!(metadata.get_chunking_config().unwrap(), chunking);
     }
 
     #[test]
@@ -0,0 +1,73 @@
+use serde::{Deserialize, Serialize};
+
+/// A natural language, stored as ISO 639-1 ("en") when one exists and
+/// ISO 639-3 ("cmn") otherwise.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct LanguageCode(String);
+
+impl LanguageCode {
+    /// Accepts ISO 639-1 or 639-3 codes, locales like "en-US" and English names
+    pub fn parse(value: &str) -> Result<Self, String> {
+        let value = value.trim().to_lowercase();
+        let base = value.split(['-', '_']).next().unwrap_or_default();
+
+        let language = match base.len() {
+            2 => isolang::Language::from_639_1(base),
+            3 => isolang::Language::from_639_3(base),
+            _ => {
+                let mut chars = value.chars();
+                let name: String = chars
+                    .next()
+                    .map(|first| first.to_uppercase().chain(chars).collect())
+                    .unwrap_or_default();
+                isolang::Language::from_name(&name)
+            }
+        }
+        .ok_or_else(|| format!("Unknown language: {}", value))?;
+
+        Ok(Self::from(language))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<isolang::Language> for LanguageCode {
+    fn from(language: isolang::Language) -> Self {
+        let code = language.to_639_1().unwrap_or_else(|| language.to_639_3());
+        Self(code.to_string())
+    }
+}
+
+impl std::fmt::Display for LanguageCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<LanguageCode> for String {
+    fn from(code: LanguageCode) -> Self {
+        code.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_normalizes_codes() {
+        assert_eq!(LanguageCode::parse("en").unwrap().as_str(), "en");
+        assert_eq!(LanguageCode::parse("FRA").unwrap().as_str(), "fr");
+        assert_eq!(LanguageCode::parse("de-AT").unwrap().as_str(), "de");
+        assert_eq!(LanguageCode::parse("Spanish").unwrap().as_str(), "es");
+        assert_eq!(LanguageCode::parse("cmn").unwrap().as_str(), "cmn");
+    }
+
+    #[test]
+    fn test_parse_rejects_unknown() {
+        assert!(LanguageCode::parse("pdf").is_err());
+        assert!(LanguageCode::parse("").is_err());
+    }
+}
@@ -1,7 +1,21 @@
+pub mod chunk_context;
+pub mod chunking_config;
+pub mod embedding_cache_key;
+pub mod embedding_prompts;
+pub mod embedding_text;
 pub mod file_hash;
 pub mod file_metadata;
+pub mod language_code;
 pub mod processing_status;
+pub mod vector_storage;
 
+pub use chunk_context::ChunkContext;
+pub use chunking_config::{ChunkSizeUnit, ChunkingConfig};
+pub use embedding_cache_key::EmbeddingCacheKey;
+pub use embedding_prompts::{EmbeddingPrompts, EmbeddingPurpose};
+pub use embedding_text::EmbeddingTextTemplate;
 pub use file_hash::FileHash;
 pub use file_metadata::FileMetadata;
+pub use language_code::LanguageCode;
 pub use processing_status::ProcessingStatus;
+pub use vector_storage::VectorStorage;
@@ -0,0 +1,97 @@
+/// How stored vectors are indexed for the coarse phase of a search. Full
+/// precision vectors are always kept, so quantized modes rescore their
+/// candidates exactly and the mode can be changed without re-embedding.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum VectorStorage {
+    /// float32 index; a single exact phase
+    #[default]
+    Full,
+    /// Half-precision (`halfvec`) index, half the size of a full one
+    HalfPrecision,
+    /// One bit per dimension, compared by Hamming distance; 32 times smaller
+    Binary,
+}
+
+impl VectorStorage {
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value.trim().to_lowercase().as_str() {
+            "full" | "float32" | "vector" => Ok(VectorStorage::Full),
+            "halfvec" | "half" | "float16" => Ok(VectorStorage::HalfPrecision),
+            "binary" | "bit" => Ok(VectorStorage::Binary),
+            other => Err(format!(
+                "Unknown vector storage '{}'; expected full, halfvec or binary",
+                other
+            )),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            VectorStorage::Full => "full",
+            VectorStorage::HalfPrecision => "halfvec",
+            VectorStorage::Binary => "binary",
+        }
+    }
+
+    pub fn is_quantized(&self) -> bool {
+        *self != VectorStorage::Full
+    }
+
+    /// Candidates fetched per requested result before rescoring; coarser
+    /// quantization needs a wider net to keep the true neighbours
+    pub fn default_rescore_factor(&self) -> usize {
+        match self {
+            VectorStorage::Full => 1,
+            VectorStorage::HalfPrecision => 2,
+            VectorStorage::Binary => 8,
+        }
+    }
+
+    /// The most dimensions pgvector's HNSW index accepts for this storage
+    pub fn max_index_dimension(&self) -> usize {
+        match self {
+            VectorStorage::Full => 2000,
+            VectorStorage::HalfPrecision => 4000,
+            VectorStorage::Binary => 64000,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        for storage in [
+            VectorStorage::Full,
+            VectorStorage::HalfPrecision,
+            VectorStorage::Binary,
+        ] {
+            assert_eq!(VectorStorage::parse(storage.as_str()), Ok(storage));
+        }
+        assert_eq!(
+            VectorStorage::parse(" Float16 "),
+            Ok(VectorStorage::HalfPrecision)
+        );
+        assert!(VectorStorage::parse("int8").is_err());
+    }
+
+    #[test]
+    fn test_rescore_factor() {
+        assert!(!VectorStorage::Full.is_quantized());
+        assert_eq!(VectorStorage::Full.default_rescore_factor(), 1);
+        assert!(
+            VectorStorage::Binary.default_rescore_factor()
+                > VectorStorage::HalfPrecision.default_rescore_factor()
+        );
+    }
+
+    #[test]
+    fn test_max_index_dimension() {
+        // text-embedding-3-large only fits a quantized index
+        assert!(VectorStorage::Full.max_index_dimension() < 3072);
+        assert!(VectorStorage::HalfPrecision.max_index_dimension() >= 3072);
+        assert!(VectorStorage::Binary.max_index_dimension() >= 3072);
+    }
+}
@@ -1,35 +1,56 @@
-use std::{path::PathBuf, sync::Arc};
+use std::{collections::HashMap, num::NonZeroUsize, path::PathBuf, sync::Arc, time::Duration};
 
 use crate::{
     application::{
-        ports::{DocumentExtractor, EmbeddingProvider, FileStorage, JobQueue},
-        services::{DocumentProcessorService, EmbeddingService, SearchService},
+        ports::{
+            ChunkingStrategyFactory, ContentDetector, DocumentExtractor, EmbeddingProvider,
+            FeedFetcher, FileStorage, JobQueue, LanguageDetector, SparseEmbeddingProvider,
+            Tokenizer, TranslationProvider,
+        },
+        services::{
+            DocumentProcessorService, EmbeddingModelRegistry, EmbeddingService, SearchService,
+        },
         use_cases::{
-            CancelJobUseCase, GetFileUseCase, GetJobStatusUseCase,
-            ListFilesUseCase, ProcessDocumentUseCase, ProcessUrlDirectUseCase,
-            ProcessYoutubeDirectUseCase, QueueProcessingJobUseCase, SearchContentUseCase,
-            UploadFileUseCase, UploadWithProcessingUseCase,
+            CancelJobUseCase, GetFileUseCase, GetJobStatusUseCase, ListFilesUseCase,
+            PollFeedUseCase, ProcessDocumentUseCase, ProcessUrlDirectUseCase,
+            ProcessYoutubeDirectUseCase, QueueProcessingJobUseCase, QueueReembedUseCase,
+            SearchContentUseCase, SubscribeFeedUseCase, SyncWatchedFileUseCase, UploadFileUseCase,
+            UploadWithProcessingUseCase,
         },
     },
-    domain::repositories::{ChunkRepository, EmbeddingRepository, FileRepository, JobRepository},
+    domain::repositories::{
+        ChunkRepository, EmbeddingCacheRepository, EmbeddingRepository, FeedRepository,
+        FileRepository, JobRepository, SparseEmbeddingRepository,
+    },
+    domain::value_objects::{EmbeddingPrompts, VectorStorage},
     infrastructure::{
         database::{
             create_connection_pool, get_database_connection,
             repositories::{
-                PostgresChunkRepository, PostgresEmbeddingRepository, PostgresFileRepository,
-                PostgresJobRepository,
+                LruEmbeddingCacheRepository, PostgresChunkRepository,
+                PostgresEmbeddingCacheRepository, PostgresEmbeddingRepository,
+                PostgresFeedRepository, PostgresFileRepository, PostgresJobRepository,
+                PostgresSparseEmbeddingRepository,
             },
             run_migrations,
         },
         external_services::{
-            InferenceEmbeddingProvider, document_extractors::CompositeDocumentExtractor,
+            BatchingEmbeddingProvider, ContentSniffer, EmbeddingBatchConfig, HashEmbeddingProvider,
+            HttpFeedFetcher, InferenceEmbeddingProvider, MatryoshkaEmbeddingProvider,
+            MockTranslationProvider, OpenAiEmbeddingProvider, WhatlangDetector,
+            chunking::ConfiguredChunkingFactory,
+            document_extractors::CompositeDocumentExtractor,
+            inference_client::{
+                EmbeddingsClientConfig, InferenceClient, InferenceSparseEmbeddingProvider,
+            },
+            tokenization::{EstimatedTokenizer, HuggingFaceTokenizer},
         },
-        file_system::LocalFileStorage,
-        messaging::{BackgroundProcessor, MpscJobQueue},
+        file_system::{FolderWatcher, LocalFileStorage},
+        messaging::{BackgroundProcessor, FeedScheduler, MpscJobQueue},
     },
     presentation::http::handlers::{
-        ChunkHandler, ContentHandler, EmbeddingHandler, FileHandler, JobHandler, SearchHandler,
-        SseHandler,
+        ChunkHandler, ContentHandler, EmbeddingHandler, FeedHandler, FileHandler, JobHandler,
+        SearchHandler, SseHandler,
     },
 };
 
@@ -39,15 +60,25 @@ pub struct AppContainer {
     pub chunk_repository: Arc<dyn ChunkRepository>,
     pub embedding_repository: Arc<dyn EmbeddingRepository>,
     pub job_repository: Arc<dyn JobRepository>,
+    pub feed_repository: Arc<dyn FeedRepository>,
 
     // External Services
     pub embedding_provider: Arc<dyn EmbeddingProvider>,
+    pub embedding_models: Arc<EmbeddingModelRegistry>,
     pub file_storage: Arc<dyn FileStorage>,
     pub document_extractor: Arc<dyn DocumentExtractor>,
+    pub content_detector: Arc<dyn ContentDetector>,
+    pub language_detector: Arc<dyn LanguageDetector>,
+    pub translation_provider: Arc<dyn TranslationProvider>,
+    pub tokenizer: Arc<dyn Tokenizer>,
+    pub chunking_factory: Arc<dyn ChunkingStrategyFactory>,
+    pub feed_fetcher: Arc<dyn FeedFetcher>,
 
     // Job Queue and Background Processing
     pub job_queue: Arc<dyn JobQueue>,
     pub background_processor: Arc<BackgroundProcessor>,
+    pub feed_scheduler: Arc<FeedScheduler>,
+    pub folder_watcher: Arc<FolderWatcher>,
 
     // Application Services
     pub document_processor: Arc<DocumentProcessorService>,
@@ -63,8 +94,12 @@ pub struct AppContainer {
     pub process_youtube_direct_use_case: Arc<ProcessYoutubeDirectUseCase>,
     pub search_content_use_case: Arc<SearchContentUseCase>,
     pub queue_job_use_case: Arc<QueueProcessingJobUseCase>,
+    pub queue_reembed_use_case: Arc<QueueReembedUseCase>,
     pub get_job_status_use_case: Arc<GetJobStatusUseCase>,
     pub cancel_job_use_case: Arc<CancelJobUseCase>,
+    pub subscribe_feed_use_case: Arc<SubscribeFeedUseCase>,
+    pub poll_feed_use_case: Arc<PollFeedUseCase>,
+    pub sync_watched_file_use_case: Arc<SyncWatchedFileUseCase>,
 
     // HTTP Handlers
     pub file_handler: Arc<FileHandler>,
@@ -74,6 +109,7 @@ pub struct AppContainer {
     pub sse_handler: Arc<SseHandler>,
     pub chunk_handler: Arc<ChunkHandler>,
     pub embedding_handler: Arc<EmbeddingHandler>,
+    pub feed_handler: Arc<FeedHandler>,
 }
 
 impl AppContainer {
@@ -90,45 +126,584 @@ impl AppContainer {
             Arc::new(PostgresFileRepository::new(db_pool.clone()));
         let chunk_repository: Arc<dyn ChunkRepository> =
             Arc::new(PostgresChunkRepository::new(db_pool.clone()));
-        let embedding_repository: Arc<dyn EmbeddingRepository> =
-            Arc::new(PostgresEmbeddingRepository::new(db_pool.clone()));
-        let job_repository: Arc<dyn JobRepository> = Arc::new(PostgresJobRepository::new(db_pool));
+        // EMBEDDING_STORAGE picks the index searches scan first: full (default),
+        // halfvec or binary; quantized candidates are rescored exactly, with
+        // EMBEDDING_RESCORE_FACTOR candidates fetched per result
+        let vector_storage = match std::env::var("EMBEDDING_STORAGE") {
+            Ok(value) if !value.trim().is_empty() => VectorStorage::parse(&value)?,
+            _ => VectorStorage::default(),
+        };
+        let rescore_factor = std::env::var("EMBEDDING_RESCORE_FACTOR")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or_else(|| vector_storage.default_rescore_factor());
+        let embedding_repository: Arc<dyn EmbeddingRepository> = Arc::new(
+            PostgresEmbeddingRepository::new(db_pool.clone())
+                .with_storage(vector_storage, rescore_factor),
+        );
+        let job_repository: Arc<dyn JobRepository> =
+            Arc::new(PostgresJobRepository::new(db_pool.clone()));
+        let feed_repository: Arc<dyn FeedRepository> =
+            Arc::new(PostgresFeedRepository::new(db_pool.clone()));
+        let sparse_embedding_repository: Arc<dyn SparseEmbeddingRepository> =
+            Arc::new(PostgresSparseEmbeddingRepository::new(db_pool.clone()));
+
+        // Embedding cache, on unless EMBEDDING_CACHE=false; EMBEDDING_CACHE_LRU_SIZE=0
+        // drops the in-memory layer
+        let embedding_cache: Option<Arc<dyn EmbeddingCacheRepository>> =
+            if std::env::var("EMBEDDING_CACHE").is_ok_and(|v| v == "false" || v == "0") {
+                None
+            } else {
+                let postgres_cache: Arc<dyn EmbeddingCacheRepository> =
+                    Arc::new(PostgresEmbeddingCacheRepository::new(db_pool));
+                let lru_size = std::env::var("EMBEDDING_CACHE_LRU_SIZE")
+                    .ok()
+                    .and_then(|s| s.parse().ok())
+                    .unwrap_or(10_000);
+                match NonZeroUsize::new(lru_size) {
+                    Some(capacity) => Some(Arc::new(LruEmbeddingCacheRepository::new(
+                        postgres_cache,
+                        capacity,
+                    ))),
+                    None => Some(postgres_cache),
+                }
+            };
 
         // Create external services
-        let embedding_provider: Arc<dyn EmbeddingProvider> =
-            Arc::new(InferenceEmbeddingProvider::from_env()?);
+        // Sizes chunks, and budgets the tokens sent per embedding request
+        let tokenizer: Arc<dyn Tokenizer> = match std::env::var("TOKENIZER_PATH") {
+            Ok(path) => Arc::new(HuggingFaceTokenizer::from_file(&PathBuf::from(path))?),
+            Err(_) if PathBuf::from("tokenizer.json").exists() => Arc::new(
+                HuggingFaceTokenizer::from_file(&PathBuf::from("tokenizer.json"))?,
+            ),
+            Err(_) => {
+                eprintln!(
+                    "No tokenizer.json found; token counts are estimated at 1.5 per word. \
+                     Set TOKENIZER_PATH to the embedding model's tokenizer."
+                );
+                Arc::new(EstimatedTokenizer::new())
+            }
+        };
+
+        // EMBEDDING_PROVIDERS lists every model documents are embedded with,
+        // comma separated, the first being searched by default until a
+        // re-embedding job activates another; a single
+        // EMBEDDING_PROVIDER is accepted too. Each is `tei` (default),
+        // `openai`, `local` when built with the local-embeddings feature,
+        // or `hash`, a model-free stand-in for trying things out offline.
+        let provider_kinds: Vec<String> = std::env::var("EMBEDDING_PROVIDERS")
+            .or_else(|_| std::env::var("EMBEDDING_PROVIDER"))
+            .unwrap_or_else(|_| "tei".to_string())
+            .split(',')
+            .map(|kind| kind.trim().to_string())
+            .filter(|kind| !kind.is_empty())
+            .collect();
+
+        // EMBEDDING_PROMPTS maps model names to the instructions marking
+        // queries and documents for asymmetric models, e.g.
+        // {"intfloat/e5-large-v2": {"query_template": "query: ", "document_template": "passage: "}}
+        let mut embedding_prompts: HashMap<String, EmbeddingPrompts> =
+            match std::env::var("EMBEDDING_PROMPTS") {
+                Ok(json) if !json.trim().is_empty() => serde_json::from_str(&json)
+                    .map_err(|e| format!("Invalid EMBEDDING_PROMPTS: {}", e))?,
+                _ => HashMap::new(),
+            };
+
+        // EMBEDDING_DIMENSIONS maps Matryoshka-trained model names to the
+        // shorter vector size to serve them at, e.g.
+        // {"nomic-ai/nomic-embed-text-v1.5": 256}; the vectors are stored as
+        // model `<name>@<dimension>`, and a re-embedding job for that model
+        // derives them from stored full-size vectors
+        let mut embedding_dimensions: HashMap<String, usize> =
+            match std::env::var("EMBEDDING_DIMENSIONS") {
+                Ok(json) if !json.trim().is_empty() => serde_json::from_str(&json)
+                    .map_err(|e| format!("Invalid EMBEDDING_DIMENSIONS: {}", e))?,
+                _ => HashMap::new(),
+            };
+
+        // Request limits apply to each model separately, shared by every worker
+        let batch_config = EmbeddingBatchConfig::from_env();
+        let mut embedding_models: Option<EmbeddingModelRegistry> = None;
+        for kind in &provider_kinds {
+            let provider: Arc<dyn EmbeddingProvider> = Arc::new(BatchingEmbeddingProvider::new(
+                Self::create_embedding_provider(
+                    kind,
+                    &mut embedding_prompts,
+                    &mut embedding_dimensions,
+                )
+                .await?,
+                tokenizer.clone(),
+                batch_config.clone(),
+            ));
+            let (model_name, _) = provider.model_info();
+
+            embedding_models = Some(match embedding_models {
+                None => EmbeddingModelRegistry::new(provider),
+                Some(registry) if registry.get(&model_name).is_some() => {
+                    return Err(format!(
+                        "Embedding model {} is configured more than once in EMBEDDING_PROVIDERS",
+                        model_name
+                    )
+                    .into());
+                }
+                Some(registry) => registry.with_model(provider),
+            });
+        }
+        let embedding_models =
+            Arc::new(embedding_models.ok_or("EMBEDDING_PROVIDERS does not name any provider")?);
+
+        // SPARSE_EMBEDDINGS_SERVICE_URL points at a TEI server running a sparse
+        // model such as naver/splade-v3; new chunks then also get term-weight
+        // vectors, and searches can run in sparse or hybrid mode.
+        // SPARSE_EMBEDDINGS_DIMENSION is the model's vocabulary size.
+        let sparse_embedding_provider: Option<Arc<dyn SparseEmbeddingProvider>> =
+            match std::env::var("SPARSE_EMBEDDINGS_SERVICE_URL") {
+                Ok(urls) if !urls.trim().is_empty() => {
+                    let dimension = std::env::var("SPARSE_EMBEDDINGS_DIMENSION")
+                        .ok()
+                        .and_then(|s| s.parse().ok())
+                        .unwrap_or(30522);
+                    let client = InferenceClient::new(EmbeddingsClientConfig::for_urls(&urls))
+                        .map_err(|e| format!("Failed to create sparse embeddings client: {}", e))?;
+                    let mut provider =
+                        InferenceSparseEmbeddingProvider::discover(client, dimension)
+                            .await?
+                            .with_batch_size(batch_config.max_batch_size);
+                    let (model_name, _) = provider.model_info();
+                    if let Some(prompts) = embedding_prompts.remove(&model_name) {
+                        provider = provider.with_prompts(prompts);
+                    }
+
+                    let registered = sparse_embedding_repository
+                        .register_model(&model_name, provider.dimension())
+                        .await?;
+                    if registered != provider.dimension() {
+                        return Err(format!(
+                            "Sparse model {} is configured with a vocabulary of {}, but its stored \
+                             vectors have {} dimensions; set SPARSE_EMBEDDINGS_DIMENSION={}",
+                            model_name, dimension, registered, registered
+                        )
+                        .into());
+                    }
+                    Some(Arc::new(provider))
+                }
+                _ => None,
+            };
+        if let Some(model_name) = embedding_dimensions.keys().next() {
+            return Err(format!(
+                "EMBEDDING_DIMENSIONS names model {}, which is not served by EMBEDDING_PROVIDERS",
+                model_name
+            )
+            .into());
+        }
+        // A misspelt model name would otherwise silently embed without instructions
+        if let Some(model_name) = embedding_prompts.keys().next() {
+            return Err(format!(
+                "EMBEDDING_PROMPTS names model {}, which is not served by EMBEDDING_PROVIDERS; \
+                 configured models: {}",
+                model_name,
+                embedding_models.model_names().join(", ")
+            )
+            .into());
+        }
 
         let upload_dir =
             PathBuf::from(std::env::var("UPLOAD_DIR").unwrap_or_else(|_| "./uploads".to_string()));
         let file_storage: Arc<dyn FileStorage> = Arc::new(LocalFileStorage::new(upload_dir));
 
+        // Folders ingested in place, comma separated
+        let watch_dirs: Vec<PathBuf> = std::env::var("WATCH_DIRS")
+            .unwrap_or_default()
+            .split(',')
+            .map(str::trim)
+            .filter(|dir| !dir.is_empty())
+            .map(PathBuf::from)
+            .collect();
+        let watch_debounce_ms = std::env::var("WATCH_DEBOUNCE_MS")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(2000);
+
+        let chunking_strategy =
+            std::env::var("CHUNKING_STRATEGY").unwrap_or_else(|_| "token_window".to_string());
+        let feed_poll_tick = std::env::var("FEED_POLL_TICK_SECONDS")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(30);
+
+        let mut builder = AppContainerBuilder::new(
+            file_repository,
+            chunk_repository,
+            embedding_repository,
+            job_repository,
+            feed_repository,
+            embedding_models,
+            file_storage,
+        )
+        .with_tokenizer(tokenizer)
+        .with_chunking_strategy(&chunking_strategy)
+        .with_watch_dirs(watch_dirs, Duration::from_millis(watch_debounce_ms))
+        .with_feed_poll_tick(Duration::from_secs(feed_poll_tick));
+        if let Some(cache) = embedding_cache {
+            builder = builder.with_embedding_cache(cache);
+        }
+        if let Some(provider) = sparse_embedding_provider {
+            builder = builder.with_sparse_embeddings(provider, sparse_embedding_repository);
+        }
+        builder.build().await
+    }
+
+    /// Builds a provider and hands it the prompts and reduced dimension
+    /// configured for its model, taking them out of `prompts` and
+    /// `dimensions` so unclaimed entries can be reported
+    async fn create_embedding_provider(
+        kind: &str,
+        prompts: &mut HashMap<String, EmbeddingPrompts>,
+        dimensions: &mut HashMap<String, usize>,
+    ) -> Result<Arc<dyn EmbeddingProvider>, Box<dyn std::error::Error>> {
+        // Only TEI can apply a model's own prompts by name
+        fn template_prompts(
+            prompts: &mut HashMap<String, EmbeddingPrompts>,
+            provider: &dyn EmbeddingProvider,
+        ) -> Result<EmbeddingPrompts, String> {
+            let (model_name, _) = provider.model_info();
+            let model_prompts = prompts.remove(&model_name).unwrap_or_default();
+            if model_prompts.has_prompt_names() {
+                return Err(format!(
+                    "EMBEDDING_PROMPTS sets prompt names for {}, but only the tei provider \
+                     supports them; use query_template and document_template instead",
+                    model_name
+                ));
+            }
+            Ok(model_prompts)
+        }
+
+        // Servers that can shorten vectors are asked to; the wrapper truncates
+        // and renames either way
+        let (provider, truncation): (Arc<dyn EmbeddingProvider>, _) = match kind {
+            "openai" => {
+                let mut provider = OpenAiEmbeddingProvider::from_env()?.discover().await?;
+                let model_prompts = template_prompts(prompts, &provider)?;
+                let full_dimension = provider.embedding_dimension();
+                let truncation = dimensions.remove(&provider.model_info().0);
+                if let Some(dimension) = truncation.filter(|d| *d < full_dimension) {
+                    provider = provider.with_dimensions(dimension);
+                }
+                (
+                    Arc::new(provider.with_prompts(model_prompts)),
+                    truncation.map(|dimension| (full_dimension, dimension)),
+                )
+            }
+            "tei" => {
+                let mut provider = InferenceEmbeddingProvider::from_env().await?;
+                let model_prompts = prompts.remove(&provider.model_info().0).unwrap_or_default();
+                let full_dimension = provider.embedding_dimension();
+                let truncation = dimensions.remove(&provider.model_info().0);
+                if let Some(dimension) = truncation.filter(|d| *d < full_dimension) {
+                    provider = provider.with_dimensions(dimension);
+                }
+                (
+                    Arc::new(provider.with_prompts(model_prompts)),
+                    truncation.map(|dimension| (full_dimension, dimension)),
+                )
+            }
+            "hash" => {
+                let dimension = std::env::var("HASH_EMBEDDING_DIMENSION")
+                    .ok()
+                    .and_then(|s| s.parse().ok())
+                    .unwrap_or(384);
+                let provider = HashEmbeddingProvider::new("hash-embedding", dimension);
+                let model_prompts = template_prompts(prompts, &provider)?;
+                let truncation = dimensions
+                    .remove(&provider.model_info().0)
+                    .map(|dimension| (provider.embedding_dimension(), dimension));
+                (Arc::new(provider.with_prompts(model_prompts)), truncation)
+            }
+            #[cfg(feature = "local-embeddings")]
+            "local" => {
+                let provider =
+                    crate::infrastructure::external_services::LocalEmbeddingProvider::from_env()?;
+                let model_prompts = template_prompts(prompts, &provider)?;
+                let truncation = dimensions
+                    .remove(&provider.model_info().0)
+                    .map(|dimension| (provider.embedding_dimension(), dimension));
+                (Arc::new(provider.with_prompts(model_prompts)), truncation)
+            }
+            #[cfg(not(feature = "local-embeddings"))]
+            "local" => {
+                return Err(
+                    "The local embedding provider needs a build with the local-embeddings feature"
+                        .into(),
+                );
+            }
+            other => {
+                return Err(format!(
+                    "Unknown embedding provider '{}'; expected tei, openai, local or hash",
+                    other
+                )
+                .into());
+            }
+        };
+
+        Ok(match truncation {
+            Some((full_dimension, dimension)) => Arc::new(MatryoshkaEmbeddingProvider::new(
+                provider,
+                full_dimension,
+                dimension,
+            )?),
+            None => provider,
+        })
+    }
+}
+
+/// Wires services, use cases and handlers around a set of repositories and
+/// embedding models. `AppContainer::new` fills it from the environment with
+/// Postgres and the configured providers; tests fill it with in-memory parts.
+pub struct AppContainerBuilder {
+    file_repository: Arc<dyn FileRepository>,
+    chunk_repository: Arc<dyn ChunkRepository>,
+    embedding_repository: Arc<dyn EmbeddingRepository>,
+    job_repository: Arc<dyn JobRepository>,
+    feed_repository: Arc<dyn FeedRepository>,
+    embedding_models: Arc<EmbeddingModelRegistry>,
+    file_storage: Arc<dyn FileStorage>,
+    embedding_cache: Option<Arc<dyn EmbeddingCacheRepository>>,
+    sparse_embeddings: Option<(
+        Arc<dyn SparseEmbeddingProvider>,
+        Arc<dyn SparseEmbeddingRepository>,
+    )>,
+    tokenizer: Arc<dyn Tokenizer>,
+    chunking_strategy: String,
+    watch_dirs: Vec<PathBuf>,
+    watch_debounce: Duration,
+    feed_poll_tick: Duration,
+    worker_count: usize,
+}
+
+impl AppContainerBuilder {
+    pub fn new(
+        file_repository: Arc<dyn FileRepository>,
+        chunk_repository: Arc<dyn ChunkRepository>,
+        embedding_repository: Arc<dyn EmbeddingRepository>,
+        job_repository: Arc<dyn JobRepository>,
+        feed_repository: Arc<dyn FeedRepository>,
+        embedding_models: Arc<EmbeddingModelRegistry>,
+        file_storage: Arc<dyn FileStorage>,
+    ) -> Self {
+        Self {
+            file_repository,
+            chunk_repository,
+            embedding_repository,
+            job_repository,
+            feed_repository,
+            embedding_models,
+            file_storage,
+            embedding_cache: None,
+            sparse_embeddings: None,
+            tokenizer: Arc::new(EstimatedTokenizer::new()),
+            chunking_strategy: "token_window".to_string(),
+            watch_dirs: Vec::new(),
+            watch_debounce: Duration::from_millis(2000),
+            feed_poll_tick: Duration::from_secs(30),
+            worker_count: 3,
+        }
+    }
+
+    /// Everything in process: in-memory repositories over one shared
+    /// database and a deterministic hash-based embedding model, with
+    /// uploads stored under `upload_dir`. Needs neither Postgres nor an
+    /// embedding server.
+    #[cfg(test)]
+    pub fn in_memory(upload_dir: PathBuf) -> Self {
+        use crate::infrastructure::database::{
+            in_memory::InMemoryDatabase,
+            repositories::{
+                InMemoryChunkRepository, InMemoryEmbeddingRepository, InMemoryFeedRepository,
+                InMemoryFileRepository, InMemoryJobRepository,
+            },
+        };
+
+        let database = InMemoryDatabase::new();
+        Self::new(
+            Arc::new(InMemoryFileRepository::new(database.clone())),
+            Arc::new(InMemoryChunkRepository::new(database.clone())),
+            Arc::new(InMemoryEmbeddingRepository::new(database.clone())),
+            Arc::new(InMemoryJobRepository::new(database.clone())),
+            Arc::new(InMemoryFeedRepository::new(database)),
+            Arc::new(EmbeddingModelRegistry::new(Arc::new(
+                HashEmbeddingProvider::new("hash-embedding", 384),
+            ))),
+            Arc::new(LocalFileStorage::new(upload_dir)),
+        )
+    }
+
+    /// Sizes chunks and budgets embedding requests; estimated from words by default
+    pub fn with_tokenizer(mut self, tokenizer: Arc<dyn Tokenizer>) -> Self {
+        self.tokenizer = tokenizer;
+        self
+    }
+
+    pub fn with_embedding_cache(mut self, cache: Arc<dyn EmbeddingCacheRepository>) -> Self {
+        self.embedding_cache = Some(cache);
+        self
+    }
+
+    pub fn with_sparse_embeddings(
+        mut self,
+        provider: Arc<dyn SparseEmbeddingProvider>,
+        repository: Arc<dyn SparseEmbeddingRepository>,
+    ) -> Self {
+        self.sparse_embeddings = Some((provider, repository));
+        self
+    }
+
+    pub fn with_chunking_strategy(mut self, strategy: &str) -> Self {
+        self.chunking_strategy = strategy.to_string();
+        self
+    }
+
+    pub fn with_watch_dirs(mut self, watch_dirs: Vec<PathBuf>, debounce: Duration) -> Self {
+        self.watch_dirs = watch_dirs;
+        self.watch_debounce = debounce;
+        self
+    }
+
+    pub fn with_feed_poll_tick(mut self, tick: Duration) -> Self {
+        self.feed_poll_tick = tick;
+        self
+    }
+
+    #[cfg(test)]
+    pub fn with_worker_count(mut self, count: usize) -> Self {
+        self.worker_count = count;
+        self
+    }
+
+    /// Registers the embedding models with the repository, restores the
+    /// active one and wires everything else
+    pub async fn build(self) -> Result<AppContainer, Box<dyn std::error::Error>> {
+        let Self {
+            file_repository,
+            chunk_repository,
+            embedding_repository,
+            job_repository,
+            feed_repository,
+            embedding_models,
+            file_storage,
+            embedding_cache,
+            sparse_embeddings,
+            tokenizer,
+            chunking_strategy,
+            watch_dirs,
+            watch_debounce,
+            feed_poll_tick,
+            worker_count,
+        } = self;
+
+        // Stored vectors of another size could not be searched with a model
+        for provider in embedding_models.providers() {
+            let (model_name, _) = provider.model_info();
+            let dimension = provider.embedding_dimension();
+            let registered = embedding_repository
+                .register_model(&model_name, dimension)
+                .await?;
+            if registered != dimension {
+                return Err(format!(
+                    "Embedding model {} produces {}-dimensional vectors, but its stored \
+                     embeddings are {}-dimensional. Point the server back at the original \
+                     model, or serve the new one under a different model name.",
+                    model_name, dimension, registered
+                )
+                .into());
+            }
+        }
+
+        // The active model survives restarts; the first start activates the first configured
+        match embedding_repository.active_model().await? {
+            Some(active) if embedding_models.set_active_model(&active) => {}
+            Some(active) => eprintln!(
+                "Active embedding model {} is not configured; searching with {} by default",
+                active,
+                embedding_models.active_model()
+            ),
+            None => {
+                embedding_repository
+                    .activate_model(&embedding_models.active_model())
+                    .await?
+            }
+        }
+        let embedding_provider = embedding_models.active_provider();
+
         // Create document extractor
+        let content_detector: Arc<dyn ContentDetector> = Arc::new(ContentSniffer::new());
         let document_extractor: Arc<dyn DocumentExtractor> = Arc::new(
             CompositeDocumentExtractor::new()
-                .map_err(|e| format!("Failed to create document extractor: {}", e))?,
+                .map_err(|e| format!("Failed to create document extractor: {}", e))?
+                .with_content_detector(content_detector.clone()),
+        );
+
+        let feed_fetcher: Arc<dyn FeedFetcher> = Arc::new(
+            HttpFeedFetcher::new().map_err(|e| format!("Failed to create feed fetcher: {}", e))?,
+        );
+
+        let language_detector: Arc<dyn LanguageDetector> = Arc::new(WhatlangDetector::new());
+        let translation_provider: Arc<dyn TranslationProvider> =
+            Arc::new(MockTranslationProvider::new());
+
+        let chunking_factory: Arc<dyn ChunkingStrategyFactory> = Arc::new(
+            ConfiguredChunkingFactory::new(
+                &chunking_strategy,
+                tokenizer.clone(),
+                embedding_models.max_input_length(),
+                embedding_provider.clone(),
+            )?
+            .with_instruction_prompts(
+                embedding_models
+                    .providers()
+                    .iter()
+                    .map(|provider| provider.prompts()),
+            ),
         );
 
         // Create application services
         let embedding_service = Arc::new(EmbeddingService::new(embedding_provider.clone()));
-        let search_service = Arc::new(SearchService::new(
-            embedding_provider.clone(),
+        let mut search_service = SearchService::new(
+            embedding_models.clone(),
             embedding_repository.clone(),
             chunk_repository.clone(),
-        ));
+            language_detector.clone(),
+            translation_provider.clone(),
+        );
+        if let Some((provider, repository)) = &sparse_embeddings {
+            search_service =
+                search_service.with_sparse_embeddings(provider.clone(), repository.clone());
+        }
+        let search_service = Arc::new(search_service);
 
         // Create document processor service
-        let document_processor = Arc::new(DocumentProcessorService::new(
+
+        let mut document_processor = DocumentProcessorService::new(
             document_extractor.clone(),
-            embedding_provider.clone(),
+            embedding_models.clone(),
             chunk_repository.clone(),
             embedding_repository.clone(),
             file_repository.clone(),
-        ));
+            language_detector.clone(),
+            chunking_factory.clone(),
+        );
+        if let Some(cache) = embedding_cache {
+            document_processor = document_processor.with_embedding_cache(cache);
+        }
+        if let Some((provider, repository)) = sparse_embeddings {
+            document_processor = document_processor.with_sparse_embeddings(provider, repository);
+        }
+        let document_processor = Arc::new(document_processor);
 
         // Create use cases
         let upload_file_use_case = Arc::new(UploadFileUseCase::new(
             file_repository.clone(),
             file_storage.clone(),
+            content_detector.clone(),
         ));
 
         let list_files_use_case = Arc::new(ListFilesUseCase::new(file_repository.clone()));
@@ -154,12 +729,12 @@ impl AppContainer {
                 file_repository.clone(),
                 document_processor.clone(),
                 document_extractor.clone(),
-                embedding_provider.clone(),
+                embedding_models.clone(),
                 file_storage.clone(),
                 chunk_repository.clone(),
                 embedding_repository.clone(),
             )
-            .with_worker_count(3),
+            .with_worker_count(worker_count),
         );
 
         // Create async use cases
@@ -175,6 +750,13 @@ impl AppContainer {
             file_repository.clone(),
         ));
 
+        let queue_reembed_use_case = Arc::new(QueueReembedUseCase::new(
+            job_repository.clone(),
+            job_queue.clone(),
+            file_repository.clone(),
+            embedding_models.clone(),
+        ));
+
         let get_job_status_use_case = Arc::new(GetJobStatusUseCase::new(job_repository.clone()));
 
         let cancel_job_use_case = Arc::new(CancelJobUseCase::new(
@@ -192,6 +774,37 @@ impl AppContainer {
             queue_job_use_case.clone(),
         ));
 
+        let subscribe_feed_use_case = Arc::new(SubscribeFeedUseCase::new(
+            feed_repository.clone(),
+            feed_fetcher.clone(),
+        ));
+
+        let poll_feed_use_case = Arc::new(PollFeedUseCase::new(
+            feed_repository.clone(),
+            file_repository.clone(),
+            feed_fetcher.clone(),
+            queue_job_use_case.clone(),
+        ));
+
+        let feed_scheduler = Arc::new(
+            FeedScheduler::new(poll_feed_use_case.clone()).with_tick_interval(feed_poll_tick),
+        );
+
+        let sync_watched_file_use_case = Arc::new(SyncWatchedFileUseCase::new(
+            file_repository.clone(),
+            queue_job_use_case.clone(),
+        ));
+
+        let folder_watcher = Arc::new(
+            FolderWatcher::new(
+                watch_dirs,
+                sync_watched_file_use_case.clone(),
+                document_extractor.clone(),
+                content_detector.clone(),
+            )
+            .with_debounce(watch_debounce),
+        );
+
         // Create HTTP handlers
         let file_handler = Arc::new(FileHandler::new(
             upload_file_use_case.clone(),
@@ -206,6 +819,7 @@ impl AppContainer {
 
         let job_handler = Arc::new(JobHandler::new(
             queue_job_use_case.clone(),
+            queue_reembed_use_case.clone(),
             get_job_status_use_case.clone(),
             cancel_job_use_case.clone(),
         ));
@@ -218,18 +832,36 @@ impl AppContainer {
         ));
 
         let chunk_handler = Arc::new(ChunkHandler::new(chunk_repository.clone()));
-        let embedding_handler = Arc::new(EmbeddingHandler::new(embedding_repository.clone()));
+        let embedding_handler = Arc::new(EmbeddingHandler::new(
+            embedding_repository.clone(),
+            embedding_models.clone(),
+        ));
+        let feed_handler = Arc::new(FeedHandler::new(
+            subscribe_feed_use_case.clone(),
+            poll_feed_use_case.clone(),
+            feed_repository.clone(),
+        ));
 
-        Ok(Self {
+        Ok(AppContainer {
             file_repository,
             chunk_repository,
             embedding_repository,
             job_repository,
+            feed_repository,
             embedding_provider,
+            embedding_models,
             file_storage,
             document_extractor,
+            content_detector,
+            language_detector,
+            translation_provider,
+            tokenizer,
+            chunking_factory,
+            feed_fetcher,
             job_queue,
             background_processor,
+            feed_scheduler,
+            folder_watcher,
             document_processor,
             embedding_service,
             search_service,
@@ -241,8 +873,12 @@ impl AppContainer {
             process_youtube_direct_use_case,
             search_content_use_case,
             queue_job_use_case,
+            queue_reembed_use_case,
             get_job_status_use_case,
             cancel_job_use_case,
+            subscribe_feed_use_case,
+            poll_feed_use_case,
+            sync_watched_file_use_case,
             file_handler,
             content_handler,
             search_handler,
@@ -250,6 +886,136 @@ impl AppContainer {
             sse_handler,
             chunk_handler,
             embedding_handler,
+            feed_handler,
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::services::search_service::RetrievalMode;
+    use crate::application::use_cases::search_content::SearchContentRequest;
+    use crate::application::use_cases::upload_with_processing::UploadWithProcessingRequest;
+    use crate::domain::value_objects::ProcessingStatus;
+
+    #[tokio::test]
+    async fn test_upload_process_search_offline() {
+        let upload_dir = std::env::temp_dir().join(format!("polyrag-{}", uuid::Uuid::new_v4()));
+        let container = AppContainerBuilder::in_memory(upload_dir.clone())
+            .with_worker_count(1)
+            .build()
+            .await
+            .unwrap();
+        let processor = container.background_processor.clone();
+        tokio::spawn(async move { processor.start().await });
+
+        let mut uploads = Vec::new();
+        for (name, text) in [
+            (
+                "rust.txt",
+                "The borrow checker enforces ownership rules in Rust programs.",
+            ),
+            (
+                "bread.txt",
+                "Sourdough bread needs a long cold fermentation in the fridge.",
+            ),
+        ] {
+            uploads.push(
+                container
+                    .upload_with_processing_use_case
+                    .execute(UploadWithProcessingRequest {
+                        file_data: text.as_bytes().to_vec(),
+                        file_name: name.to_string(),
+                        content_type: Some("text/plain".to_string()),
+                        auto_process: true,
+                        metadata: None,
+                        chunking_config: None,
+                    })
+                    .await
+                    .unwrap(),
+            );
+        }
+
+        for upload in &uploads {
+            let job_id = upload.job_id.unwrap();
+            let status = tokio::time::timeout(Duration::from_secs(10), async {
+                loop {
+                    let job = container.job_repository.find_by_id(job_id).await.unwrap();
+                    match job.map(|job| job.status().clone()) {
+                        Some(status) if status.is_terminal() => break status,
+                        _ => tokio::time::sleep(Duration::from_millis(20)).await,
+                    }
+                }
+            })
+            .await
+            .unwrap();
+            assert_eq!(status, ProcessingStatus::Completed);
+        }
+
+        let response = container
+            .search_content_use_case
+            .execute(SearchContentRequest {
+                query: "sourdough fermentation".to_string(),
+                limit: Some(5),
+                similarity_threshold: None,
+                file_id_filter: None,
+                languages: Vec::new(),
+                cross_lingual: false,
+                max_languages: None,
+                expand_parents: false,
+                context_window: 0,
+                model: None,
+                mode: RetrievalMode::Dense,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(response.model, "hash-embedding");
+        assert_eq!(response.results.len(), 2);
+        assert_eq!(response.results[0].file_id, uploads[1].file_id);
+        assert!(response.results[0].similarity_score > response.results[1].similarity_score);
+
+        let _ = std::fs::remove_dir_all(upload_dir);
+    }
+
+    #[tokio::test]
+    async fn test_refuses_to_start_when_discovered_dimension_changed() {
+        use crate::infrastructure::database::{
+            in_memory::InMemoryDatabase,
+            repositories::{
+                InMemoryChunkRepository, InMemoryEmbeddingRepository, InMemoryFeedRepository,
+                InMemoryFileRepository, InMemoryJobRepository,
+            },
+        };
+        use crate::infrastructure::external_services::inference_client::spawn_mock_tei;
+
+        let url = spawn_mock_tei("BAAI/bge-small-en-v1.5", 4).await;
+        let client = InferenceClient::new(EmbeddingsClientConfig::for_urls(&url)).unwrap();
+        let provider = InferenceEmbeddingProvider::discover(client).await.unwrap();
+
+        let database = InMemoryDatabase::new();
+        let embedding_repository = Arc::new(InMemoryEmbeddingRepository::new(database.clone()));
+        embedding_repository
+            .register_model("BAAI/bge-small-en-v1.5", 8)
+            .await
+            .unwrap();
+
+        let upload_dir = std::env::temp_dir().join(format!("polyrag-{}", uuid::Uuid::new_v4()));
+        let result = AppContainerBuilder::new(
+            Arc::new(InMemoryFileRepository::new(database.clone())),
+            Arc::new(InMemoryChunkRepository::new(database.clone())),
+            embedding_repository,
+            Arc::new(InMemoryJobRepository::new(database.clone())),
+            Arc::new(InMemoryFeedRepository::new(database)),
+            Arc::new(EmbeddingModelRegistry::new(Arc::new(provider))),
+            Arc::new(LocalFileStorage::new(upload_dir)),
+        )
+        .build()
+        .await;
+
+        let error = result.err().unwrap().to_string();
+        assert!(error.contains("produces 4-dimensional vectors"));
+        assert!(error.contains("are 8-dimensional"));
+    }
+}
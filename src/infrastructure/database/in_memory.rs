@@ -0,0 +1,88 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, MutexGuard};
+use uuid::Uuid;
+
+use crate::domain::entities::{ContentChunk, Embedding, Feed, FeedEntry, File, ProcessingJob};
+
+/// Tables held in process, shared by the in-memory repositories the way a
+/// connection pool is shared by the Postgres ones. Deleting a file or chunk
+/// cascades to its chunks, vectors and jobs as the schema's foreign keys do.
+#[derive(Clone, Default)]
+pub struct InMemoryDatabase {
+    tables: Arc<Mutex<InMemoryTables>>,
+}
+
+/// Rows in insertion order
+#[derive(Default)]
+pub struct InMemoryTables {
+    pub files: Vec<File>,
+    pub chunks: Vec<ContentChunk>,
+    pub embeddings: Vec<Embedding>,
+    /// Registered embedding models and their dimension
+    pub embedding_models: HashMap<String, usize>,
+    pub active_model: Option<String>,
+    pub jobs: Vec<ProcessingJob>,
+    pub feeds: Vec<Feed>,
+    pub feed_entries: Vec<FeedEntry>,
+}
+
+impl InMemoryDatabase {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn tables(&self) -> MutexGuard<'_, InMemoryTables> {
+        // A test that panicked mid-write leaves nothing half-applied worth refusing
+        self.tables
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl InMemoryTables {
+    pub fn delete_file(&mut self, file_id: Uuid) -> bool {
+        let before = self.files.len();
+        self.files.retain(|file| file.id() != file_id);
+        if self.files.len() == before {
+            return false;
+        }
+
+        self.jobs.retain(|job| job.file_id() != Some(file_id));
+        let chunk_ids: Vec<Uuid> = self
+            .chunks
+            .iter()
+            .filter(|chunk| chunk.file_id() == file_id)
+            .map(ContentChunk::id)
+            .collect();
+        self.delete_chunks(&chunk_ids);
+        true
+    }
+
+    /// Removes the chunks with their children and vectors; returns how many
+    /// of `chunk_ids` existed
+    pub fn delete_chunks(&mut self, chunk_ids: &[Uuid]) -> usize {
+        let mut doomed: Vec<Uuid> = chunk_ids
+            .iter()
+            .copied()
+            .filter(|chunk_id| self.chunks.iter().any(|chunk| chunk.id() == *chunk_id))
+            .collect();
+        let deleted = doomed.len();
+
+        let mut next = 0;
+        while next < doomed.len() {
+            let parent = doomed[next];
+            doomed.extend(
+                self.chunks
+                    .iter()
+                    .filter(|chunk| chunk.parent_chunk_id() == Some(parent))
+                    .map(ContentChunk::id),
+            );
+            next += 1;
+        }
+
+        self.chunks.retain(|chunk| !doomed.contains(&chunk.id()));
+        self.embeddings
+            .retain(|embedding| !doomed.contains(&embedding.content_chunk_id()));
+        deleted
+    }
+}
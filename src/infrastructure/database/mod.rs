@@ -1,4 +1,6 @@
 pub mod connection;
+#[cfg(test)]
+pub mod in_memory;
 pub mod models;
 pub mod repositories;
 pub mod schema;
@@ -19,6 +19,8 @@ pub struct ContentChunkModel {
     pub page_number: Option<i32>,
     pub section_path: Option<String>,
     pub created_at: Option<DateTime<Utc>>,
+    pub language: Option<String>,
+    pub parent_chunk_id: Option<Uuid>,
 }
 
 #[derive(Debug, Insertable, AsChangeset)]
@@ -33,6 +35,8 @@ pub struct NewContentChunkModel {
     pub page_number: Option<i32>,
     pub section_path: Option<String>,
     pub created_at: Option<DateTime<Utc>>,
+    pub language: Option<String>,
+    pub parent_chunk_id: Option<Uuid>,
 }
 
 impl From<&DomainChunk> for NewContentChunkModel {
@@ -46,19 +50,25 @@ impl From<&DomainChunk> for NewContentChunkModel {
             page_number: domain_chunk.page_number(),
             section_path: domain_chunk.section_path().map(|s| s.to_string()),
             created_at: Some(domain_chunk.created_at()),
+            language: domain_chunk.language().map(|s| s.to_string()),
+            parent_chunk_id: domain_chunk.parent_chunk_id(),
         }
     }
 }
 
 impl From<ContentChunkModel> for DomainChunk {
     fn from(model: ContentChunkModel) -> Self {
-        DomainChunk::new(
+        DomainChunk::with_id(
+            model.id,
             model.file_id,
             model.chunk_text,
             model.chunk_index,
             model.token_count,
             model.page_number,
             model.section_path,
+            model.language,
+            model.created_at.unwrap_or_else(Utc::now),
         )
+        .with_parent_chunk_id(model.parent_chunk_id)
     }
 }
@@ -0,0 +1,39 @@
+use diesel::prelude::*;
+use pgvector::Vector;
+
+use crate::domain::value_objects::EmbeddingCacheKey;
+use crate::infrastructure::database::schema::embedding_cache;
+
+#[derive(Debug, Clone, Queryable, Selectable, Insertable)]
+#[diesel(table_name = embedding_cache)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct EmbeddingCacheModel {
+    pub text_hash: String,
+    pub model_name: String,
+    /// Empty for models without a version
+    pub model_version: String,
+    pub template: String,
+    pub embedding: Vector,
+}
+
+impl EmbeddingCacheModel {
+    pub fn new(key: &EmbeddingCacheKey, embedding: Vector) -> Self {
+        Self {
+            text_hash: key.text_hash.clone(),
+            model_name: key.model_name.clone(),
+            model_version: key.model_version.clone().unwrap_or_default(),
+            template: key.template.clone(),
+            embedding,
+        }
+    }
+
+    pub fn into_entry(self) -> (EmbeddingCacheKey, Vector) {
+        let key = EmbeddingCacheKey {
+            text_hash: self.text_hash,
+            model_name: self.model_name,
+            model_version: Some(self.model_version).filter(|version| !version.is_empty()),
+            template: self.template,
+        };
+        (key, self.embedding)
+    }
+}
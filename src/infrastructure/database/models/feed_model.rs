@@ -0,0 +1,103 @@
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use uuid::Uuid;
+
+use crate::domain::entities::{Feed, FeedEntry};
+use crate::infrastructure::database::schema::{feed_entries, feeds};
+
+#[derive(Debug, Queryable, Identifiable, Selectable, Insertable, AsChangeset)]
+#[diesel(table_name = feeds)]
+#[diesel(primary_key(id))]
+pub struct FeedModel {
+    pub id: Uuid,
+    pub url: String,
+    pub name: String,
+    pub poll_interval_seconds: i32,
+    pub is_active: bool,
+    pub last_polled_at: Option<DateTime<Utc>>,
+    pub last_error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Queryable, Identifiable, Selectable, Insertable, AsChangeset)]
+#[diesel(table_name = feed_entries)]
+#[diesel(primary_key(id))]
+pub struct FeedEntryModel {
+    pub id: Uuid,
+    pub feed_id: Uuid,
+    pub guid: String,
+    pub link: String,
+    pub title: Option<String>,
+    pub published_at: Option<DateTime<Utc>>,
+    pub content_hash: String,
+    pub file_id: Option<Uuid>,
+    pub first_seen_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<&Feed> for FeedModel {
+    fn from(feed: &Feed) -> Self {
+        Self {
+            id: feed.id(),
+            url: feed.url().to_string(),
+            name: feed.name().to_string(),
+            poll_interval_seconds: feed.poll_interval_seconds(),
+            is_active: feed.is_active(),
+            last_polled_at: feed.last_polled_at(),
+            last_error: feed.last_error().map(|s| s.to_string()),
+            created_at: feed.created_at(),
+            updated_at: feed.updated_at(),
+        }
+    }
+}
+
+impl From<FeedModel> for Feed {
+    fn from(model: FeedModel) -> Self {
+        Feed::from_database(
+            model.id,
+            model.url,
+            model.name,
+            model.poll_interval_seconds,
+            model.is_active,
+            model.last_polled_at,
+            model.last_error,
+            model.created_at,
+            model.updated_at,
+        )
+    }
+}
+
+impl From<&FeedEntry> for FeedEntryModel {
+    fn from(entry: &FeedEntry) -> Self {
+        Self {
+            id: entry.id(),
+            feed_id: entry.feed_id(),
+            guid: entry.guid().to_string(),
+            link: entry.link().to_string(),
+            title: entry.title().map(|s| s.to_string()),
+            published_at: entry.published_at(),
+            content_hash: entry.content_hash().to_string(),
+            file_id: entry.file_id(),
+            first_seen_at: entry.first_seen_at(),
+            updated_at: entry.updated_at(),
+        }
+    }
+}
+
+impl From<FeedEntryModel> for FeedEntry {
+    fn from(model: FeedEntryModel) -> Self {
+        FeedEntry::from_database(
+            model.id,
+            model.feed_id,
+            model.guid,
+            model.link,
+            model.title,
+            model.published_at,
+            model.content_hash,
+            model.file_id,
+            model.first_seen_at,
+            model.updated_at,
+        )
+    }
+}
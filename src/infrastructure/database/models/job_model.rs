@@ -7,7 +7,7 @@ use crate::domain::entities::{
     ProcessingJob,
     processing_job::{JobResult, JobType},
 };
-use crate::domain::value_objects::ProcessingStatus;
+use crate::domain::value_objects::{ChunkingConfig, ProcessingStatus};
 use crate::infrastructure::database::schema::processing_jobs;
 
 #[derive(Debug, Queryable, Identifiable, Selectable)]
@@ -15,7 +15,7 @@ use crate::infrastructure::database::schema::processing_jobs;
 #[diesel(primary_key(id))]
 pub struct JobModel {
     pub id: Uuid,
-    pub file_id: Uuid,
+    pub file_id: Option<Uuid>,
     pub job_type: String,
     pub job_data: Option<serde_json::Value>, // For storing URL or other job-specific data
     pub status: String,
@@ -31,7 +31,7 @@ pub struct JobModel {
 #[diesel(table_name = processing_jobs)]
 pub struct NewJobModel {
     pub id: Uuid,
-    pub file_id: Uuid,
+    pub file_id: Option<Uuid>,
     pub job_type: String,
     pub job_data: Option<serde_json::Value>,
     pub status: String,
@@ -56,7 +56,7 @@ pub struct UpdateJobModel {
 
 impl From<ProcessingJob> for NewJobModel {
     fn from(job: ProcessingJob) -> Self {
-        let (job_type_str, job_data) = match job.job_type() {
+        let (job_type_str, mut job_data) = match job.job_type() {
             JobType::FileProcessing => ("file_processing".to_string(), None),
             JobType::UrlExtraction { url } => (
                 "url_extraction".to_string(),
@@ -66,8 +66,21 @@ impl From<ProcessingJob> for NewJobModel {
                 "youtube_extraction".to_string(),
                 Some(serde_json::json!({"url": url})),
             ),
+            JobType::Reembed { model_name } => (
+                "reembed".to_string(),
+                Some(serde_json::json!({"model": model_name})),
+            ),
         };
 
+        if let Some(config) = job.chunking_config()
+            && let Ok(value) = serde_json::to_value(config)
+            && let Some(data) = job_data
+                .get_or_insert_with(|| serde_json::json!({}))
+                .as_object_mut()
+        {
+            data.insert("chunking".to_string(), value);
+        }
+
         // For failed status, store error details in error_message field
         let error_message = match job.status() {
             ProcessingStatus::Failed(error) => Some(error.clone()),
@@ -140,6 +153,16 @@ impl TryFrom<JobModel> for ProcessingJob {
                     .to_string();
                 JobType::YoutubeExtraction { url }
             }
+            "reembed" => {
+                let model_name = model
+                    .job_data
+                    .as_ref()
+                    .and_then(|data| data.get("model"))
+                    .and_then(|model_name| model_name.as_str())
+                    .ok_or("Missing model in job data")?
+                    .to_string();
+                JobType::Reembed { model_name }
+            }
             _ => return Err(format!("Unknown job type: {}", model.job_type)),
         };
 
@@ -164,6 +187,20 @@ impl TryFrom<JobModel> for ProcessingJob {
             _ => return Err(format!("Unknown status: {}", model.status)),
         };
 
+        // Jobs queued before chunking configs only stored a strategy name
+        let chunking_config = model.job_data.as_ref().and_then(|data| {
+            data.get("chunking")
+                .and_then(|config| serde_json::from_value::<ChunkingConfig>(config.clone()).ok())
+                .or_else(|| {
+                    data.get("chunking_strategy")
+                        .and_then(|strategy| strategy.as_str())
+                        .map(|strategy| ChunkingConfig {
+                            strategy: Some(strategy.to_string()),
+                            ..Default::default()
+                        })
+                })
+        });
+
         let result_summary = if let Some(result_json) = model.result_summary {
             Some(
                 serde_json::from_value::<JobResult>(result_json)
@@ -185,7 +222,8 @@ impl TryFrom<JobModel> for ProcessingJob {
             model.completed_at,
             model.error_message,
             result_summary,
-        );
+        )
+        .with_chunking_config(chunking_config);
 
         Ok(job)
     }
@@ -1,9 +1,15 @@
 pub mod chunk_model;
+pub mod embedding_cache_model;
 pub mod embedding_model;
+pub mod feed_model;
 pub mod file_model;
 pub mod job_model;
+pub mod sparse_embedding_model;
 
 pub use chunk_model::*;
+pub use embedding_cache_model::*;
 pub use embedding_model::*;
+pub use feed_model::*;
 pub use file_model::*;
 pub use job_model::*;
+pub use sparse_embedding_model::*;
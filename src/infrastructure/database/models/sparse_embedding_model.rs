@@ -0,0 +1,57 @@
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use pgvector::SparseVector;
+use uuid::Uuid;
+
+use crate::domain::entities::SparseEmbedding as DomainSparseEmbedding;
+use crate::infrastructure::database::schema::sparse_embeddings;
+
+#[derive(Debug, Clone, Queryable, Selectable, Identifiable)]
+#[diesel(table_name = sparse_embeddings)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct SparseEmbeddingModel {
+    pub id: Uuid,
+    pub content_chunk_id: Uuid,
+    pub model_name: String,
+    pub model_version: Option<String>,
+    pub generated_at: DateTime<Utc>,
+    pub embedding: SparseVector,
+}
+
+#[derive(Debug, Insertable)]
+#[diesel(table_name = sparse_embeddings)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct NewSparseEmbeddingModel {
+    pub id: Uuid,
+    pub content_chunk_id: Uuid,
+    pub model_name: String,
+    pub model_version: Option<String>,
+    pub generated_at: DateTime<Utc>,
+    pub embedding: SparseVector,
+}
+
+impl From<&DomainSparseEmbedding> for NewSparseEmbeddingModel {
+    fn from(domain_embedding: &DomainSparseEmbedding) -> Self {
+        Self {
+            id: domain_embedding.id(),
+            content_chunk_id: domain_embedding.content_chunk_id(),
+            model_name: domain_embedding.model_name().to_string(),
+            model_version: domain_embedding.model_version().map(|s| s.to_string()),
+            generated_at: domain_embedding.generated_at(),
+            embedding: domain_embedding.embedding().clone(),
+        }
+    }
+}
+
+impl From<SparseEmbeddingModel> for DomainSparseEmbedding {
+    fn from(model: SparseEmbeddingModel) -> Self {
+        DomainSparseEmbedding::from_database(
+            model.id,
+            model.content_chunk_id,
+            model.model_name,
+            model.model_version,
+            model.generated_at,
+            model.embedding,
+        )
+    }
+}
@@ -0,0 +1,179 @@
+use async_trait::async_trait;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::domain::entities::ContentChunk;
+use crate::domain::repositories::{
+    ChunkRepository,
+    chunk_repository::{ChunkIndexRange, ChunkRepositoryError},
+};
+use crate::infrastructure::database::in_memory::{InMemoryDatabase, InMemoryTables};
+
+pub struct InMemoryChunkRepository {
+    database: InMemoryDatabase,
+}
+
+impl InMemoryChunkRepository {
+    pub fn new(database: InMemoryDatabase) -> Self {
+        Self { database }
+    }
+}
+
+/// Leaf chunks without a vector from `model`, in id order
+fn missing_embeddings(
+    tables: &InMemoryTables,
+    model: &str,
+    file_id: Option<Uuid>,
+) -> Vec<ContentChunk> {
+    let mut chunks: Vec<ContentChunk> = tables
+        .chunks
+        .iter()
+        .filter(|chunk| file_id.is_none_or(|file_id| chunk.file_id() == file_id))
+        .filter(|chunk| {
+            !tables
+                .chunks
+                .iter()
+                .any(|child| child.parent_chunk_id() == Some(chunk.id()))
+        })
+        .filter(|chunk| {
+            !tables.embeddings.iter().any(|embedding| {
+                embedding.content_chunk_id() == chunk.id() && embedding.model_name() == model
+            })
+        })
+        .cloned()
+        .collect();
+    chunks.sort_by_key(ContentChunk::id);
+    chunks
+}
+
+#[async_trait]
+impl ChunkRepository for InMemoryChunkRepository {
+    async fn save_batch(&self, chunks: &[ContentChunk]) -> Result<(), ChunkRepositoryError> {
+        let mut tables = self.database.tables();
+        for chunk in chunks {
+            if !tables.files.iter().any(|file| file.id() == chunk.file_id()) {
+                return Err(ChunkRepositoryError::DatabaseError(format!(
+                    "Chunk {} belongs to unknown file {}",
+                    chunk.id(),
+                    chunk.file_id()
+                )));
+            }
+        }
+        tables.chunks.extend_from_slice(chunks);
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<ContentChunk>, ChunkRepositoryError> {
+        Ok(self
+            .database
+            .tables()
+            .chunks
+            .iter()
+            .find(|chunk| chunk.id() == id)
+            .cloned())
+    }
+
+    async fn find_by_file_id_paginated(
+        &self,
+        file_id: Uuid,
+        skip: i64,
+        limit: i64,
+    ) -> Result<Vec<ContentChunk>, ChunkRepositoryError> {
+        let mut chunks: Vec<ContentChunk> = self
+            .database
+            .tables()
+            .chunks
+            .iter()
+            .filter(|chunk| chunk.file_id() == file_id)
+            .cloned()
+            .collect();
+        chunks.sort_by_key(ContentChunk::chunk_index);
+        Ok(chunks
+            .into_iter()
+            .skip(skip.max(0) as usize)
+            .take(limit.max(0) as usize)
+            .collect())
+    }
+
+    async fn find_by_index_ranges(
+        &self,
+        ranges: &[ChunkIndexRange],
+    ) -> Result<Vec<ContentChunk>, ChunkRepositoryError> {
+        let mut chunks: Vec<ContentChunk> = self
+            .database
+            .tables()
+            .chunks
+            .iter()
+            .filter(|chunk| {
+                ranges.iter().any(|range| {
+                    chunk.file_id() == range.file_id
+                        && (range.start..=range.end).contains(&chunk.chunk_index())
+                })
+            })
+            .cloned()
+            .collect();
+        chunks.sort_by_key(|chunk| (chunk.file_id(), chunk.chunk_index()));
+        Ok(chunks)
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<bool, ChunkRepositoryError> {
+        Ok(self.database.tables().delete_chunks(&[id]) > 0)
+    }
+
+    async fn delete_by_file_id(&self, file_id: Uuid) -> Result<i64, ChunkRepositoryError> {
+        let mut tables = self.database.tables();
+        let chunk_ids: Vec<Uuid> = tables
+            .chunks
+            .iter()
+            .filter(|chunk| chunk.file_id() == file_id)
+            .map(ContentChunk::id)
+            .collect();
+        Ok(tables.delete_chunks(&chunk_ids) as i64)
+    }
+
+    async fn count_by_file_id(&self, file_id: Uuid) -> Result<i64, ChunkRepositoryError> {
+        Ok(self
+            .database
+            .tables()
+            .chunks
+            .iter()
+            .filter(|chunk| chunk.file_id() == file_id)
+            .count() as i64)
+    }
+
+    async fn count_by_language(&self) -> Result<Vec<(String, i64)>, ChunkRepositoryError> {
+        let mut counts: HashMap<String, i64> = HashMap::new();
+        for chunk in &self.database.tables().chunks {
+            if let Some(language) = chunk.language() {
+                *counts.entry(language.to_string()).or_default() += 1;
+            }
+        }
+        let mut counts: Vec<(String, i64)> = counts.into_iter().collect();
+        counts.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        Ok(counts)
+    }
+
+    async fn find_missing_embeddings(
+        &self,
+        model_name: &str,
+        file_id: Option<Uuid>,
+        after: Option<Uuid>,
+        limit: i64,
+    ) -> Result<Vec<ContentChunk>, ChunkRepositoryError> {
+        Ok(
+            missing_embeddings(&self.database.tables(), model_name, file_id)
+                .into_iter()
+                .filter(|chunk| after.is_none_or(|after| chunk.id() > after))
+                .take(limit.max(0) as usize)
+                .collect(),
+        )
+    }
+
+    async fn count_missing_embeddings(
+        &self,
+        model_name: &str,
+        file_id: Option<Uuid>,
+    ) -> Result<i64, ChunkRepositoryError> {
+        Ok(missing_embeddings(&self.database.tables(), model_name, file_id).len() as i64)
+    }
+}
@@ -0,0 +1,274 @@
+use async_trait::async_trait;
+use pgvector::Vector;
+use uuid::Uuid;
+
+use crate::domain::entities::Embedding;
+use crate::domain::repositories::{
+    EmbeddingRepository,
+    embedding_repository::{
+        EmbeddingRepositoryError, SimilaritySearchFilter, SimilaritySearchResult,
+    },
+};
+use crate::infrastructure::database::in_memory::InMemoryDatabase;
+
+/// Searches by exact cosine similarity over every stored vector
+pub struct InMemoryEmbeddingRepository {
+    database: InMemoryDatabase,
+}
+
+impl InMemoryEmbeddingRepository {
+    pub fn new(database: InMemoryDatabase) -> Self {
+        Self { database }
+    }
+
+    fn search(
+        &self,
+        query_vector: &Vector,
+        limit: i32,
+        similarity_threshold: Option<f32>,
+        filter: &SimilaritySearchFilter,
+    ) -> Vec<SimilaritySearchResult> {
+        let tables = self.database.tables();
+        let mut results: Vec<SimilaritySearchResult> = tables
+            .embeddings
+            .iter()
+            .filter(|embedding| {
+                filter
+                    .model_name
+                    .as_deref()
+                    .is_none_or(|model| embedding.model_name() == model)
+            })
+            .filter(|embedding| {
+                tables
+                    .chunks
+                    .iter()
+                    .find(|chunk| chunk.id() == embedding.content_chunk_id())
+                    .is_some_and(|chunk| {
+                        filter
+                            .file_id
+                            .is_none_or(|file_id| chunk.file_id() == file_id)
+                            && (filter.languages.is_empty()
+                                || chunk.language().is_some_and(|language| {
+                                    filter.languages.iter().any(|l| l == language)
+                                }))
+                    })
+            })
+            .filter_map(|embedding| {
+                let similarity_score = cosine_similarity(query_vector, embedding.embedding())?;
+                Some(SimilaritySearchResult {
+                    embedding: embedding.clone(),
+                    similarity_score,
+                    chunk_id: embedding.content_chunk_id(),
+                })
+            })
+            .filter(|result| {
+                similarity_threshold.is_none_or(|threshold| result.similarity_score >= threshold)
+            })
+            .collect();
+
+        results.sort_by(|a, b| b.similarity_score.total_cmp(&a.similarity_score));
+        results.truncate(limit.max(0) as usize);
+        results
+    }
+}
+
+/// None for vectors of different sizes, which belong to different models
+fn cosine_similarity(a: &Vector, b: &Vector) -> Option<f32> {
+    let (a, b) = (a.as_slice(), b.as_slice());
+    if a.len() != b.len() {
+        return None;
+    }
+
+    let dot_product: f32 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+    let norm_a = a.iter().map(|x| x * x).sum::<f32>().sqrt();
+    let norm_b = b.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if norm_a == 0.0 || norm_b == 0.0 {
+        return Some(0.0);
+    }
+    Some(dot_product / (norm_a * norm_b))
+}
+
+#[async_trait]
+impl EmbeddingRepository for InMemoryEmbeddingRepository {
+    async fn save(&self, embedding: &Embedding) -> Result<(), EmbeddingRepositoryError> {
+        self.save_batch(std::slice::from_ref(embedding)).await
+    }
+
+    async fn save_batch(&self, embeddings: &[Embedding]) -> Result<(), EmbeddingRepositoryError> {
+        let mut tables = self.database.tables();
+        for embedding in embeddings {
+            if !tables
+                .chunks
+                .iter()
+                .any(|chunk| chunk.id() == embedding.content_chunk_id())
+            {
+                return Err(EmbeddingRepositoryError::DatabaseError(format!(
+                    "Embedding {} belongs to unknown chunk {}",
+                    embedding.id(),
+                    embedding.content_chunk_id()
+                )));
+            }
+        }
+        tables.embeddings.extend_from_slice(embeddings);
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Embedding>, EmbeddingRepositoryError> {
+        Ok(self
+            .database
+            .tables()
+            .embeddings
+            .iter()
+            .find(|embedding| embedding.id() == id)
+            .cloned())
+    }
+
+    async fn find_by_chunk_id(
+        &self,
+        chunk_id: Uuid,
+    ) -> Result<Option<Embedding>, EmbeddingRepositoryError> {
+        Ok(self
+            .database
+            .tables()
+            .embeddings
+            .iter()
+            .find(|embedding| embedding.content_chunk_id() == chunk_id)
+            .cloned())
+    }
+
+    async fn find_by_file_id(
+        &self,
+        file_id: Uuid,
+    ) -> Result<Vec<Embedding>, EmbeddingRepositoryError> {
+        let tables = self.database.tables();
+        Ok(tables
+            .embeddings
+            .iter()
+            .filter(|embedding| {
+                tables.chunks.iter().any(|chunk| {
+                    chunk.id() == embedding.content_chunk_id() && chunk.file_id() == file_id
+                })
+            })
+            .cloned()
+            .collect())
+    }
+
+    async fn find_by_chunk_ids(
+        &self,
+        chunk_ids: &[Uuid],
+        model_name: &str,
+    ) -> Result<Vec<Embedding>, EmbeddingRepositoryError> {
+        Ok(self
+            .database
+            .tables()
+            .embeddings
+            .iter()
+            .filter(|embedding| {
+                embedding.model_name() == model_name
+                    && chunk_ids.contains(&embedding.content_chunk_id())
+            })
+            .cloned()
+            .collect())
+    }
+
+    async fn similarity_search(
+        &self,
+        query_vector: &Vector,
+        limit: i32,
+        similarity_threshold: Option<f32>,
+    ) -> Result<Vec<SimilaritySearchResult>, EmbeddingRepositoryError> {
+        Ok(self.search(
+            query_vector,
+            limit,
+            similarity_threshold,
+            &SimilaritySearchFilter::default(),
+        ))
+    }
+
+    async fn similarity_search_by_file(
+        &self,
+        query_vector: &Vector,
+        file_id: Uuid,
+        limit: i32,
+        similarity_threshold: Option<f32>,
+    ) -> Result<Vec<SimilaritySearchResult>, EmbeddingRepositoryError> {
+        let filter = SimilaritySearchFilter {
+            file_id: Some(file_id),
+            ..Default::default()
+        };
+        Ok(self.search(query_vector, limit, similarity_threshold, &filter))
+    }
+
+    async fn similarity_search_filtered(
+        &self,
+        query_vector: &Vector,
+        limit: i32,
+        similarity_threshold: Option<f32>,
+        filter: &SimilaritySearchFilter,
+    ) -> Result<Vec<SimilaritySearchResult>, EmbeddingRepositoryError> {
+        Ok(self.search(query_vector, limit, similarity_threshold, filter))
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<bool, EmbeddingRepositoryError> {
+        let mut tables = self.database.tables();
+        let before = tables.embeddings.len();
+        tables.embeddings.retain(|embedding| embedding.id() != id);
+        Ok(tables.embeddings.len() < before)
+    }
+
+    async fn delete_by_chunk_id(&self, chunk_id: Uuid) -> Result<bool, EmbeddingRepositoryError> {
+        let mut tables = self.database.tables();
+        let before = tables.embeddings.len();
+        tables
+            .embeddings
+            .retain(|embedding| embedding.content_chunk_id() != chunk_id);
+        Ok(tables.embeddings.len() < before)
+    }
+
+    async fn delete_by_file_id(&self, file_id: Uuid) -> Result<i64, EmbeddingRepositoryError> {
+        let mut tables = self.database.tables();
+        let chunk_ids: Vec<Uuid> = tables
+            .chunks
+            .iter()
+            .filter(|chunk| chunk.file_id() == file_id)
+            .map(|chunk| chunk.id())
+            .collect();
+        let before = tables.embeddings.len();
+        tables
+            .embeddings
+            .retain(|embedding| !chunk_ids.contains(&embedding.content_chunk_id()));
+        Ok((before - tables.embeddings.len()) as i64)
+    }
+
+    async fn count(&self) -> Result<i64, EmbeddingRepositoryError> {
+        Ok(self.database.tables().embeddings.len() as i64)
+    }
+
+    async fn register_model(
+        &self,
+        model_name: &str,
+        dimension: usize,
+    ) -> Result<usize, EmbeddingRepositoryError> {
+        Ok(*self
+            .database
+            .tables()
+            .embedding_models
+            .entry(model_name.to_string())
+            .or_insert(dimension))
+    }
+
+    async fn active_model(&self) -> Result<Option<String>, EmbeddingRepositoryError> {
+        Ok(self.database.tables().active_model.clone())
+    }
+
+    async fn activate_model(&self, model_name: &str) -> Result<(), EmbeddingRepositoryError> {
+        let mut tables = self.database.tables();
+        if !tables.embedding_models.contains_key(model_name) {
+            return Err(EmbeddingRepositoryError::ModelNotFound(
+                model_name.to_string(),
+            ));
+        }
+        tables.active_model = Some(model_name.to_string());
+        Ok(())
+    }
+}
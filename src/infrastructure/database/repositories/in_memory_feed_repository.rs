@@ -0,0 +1,136 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::entities::{Feed, FeedEntry};
+use crate::domain::repositories::{FeedRepository, feed_repository::FeedRepositoryError};
+use crate::infrastructure::database::in_memory::InMemoryDatabase;
+
+pub struct InMemoryFeedRepository {
+    database: InMemoryDatabase,
+}
+
+impl InMemoryFeedRepository {
+    pub fn new(database: InMemoryDatabase) -> Self {
+        Self { database }
+    }
+}
+
+#[async_trait]
+impl FeedRepository for InMemoryFeedRepository {
+    async fn save(&self, feed: &Feed) -> Result<(), FeedRepositoryError> {
+        let mut tables = self.database.tables();
+        if tables.feeds.iter().any(|stored| stored.url() == feed.url()) {
+            return Err(FeedRepositoryError::DatabaseError(format!(
+                "Failed to save feed: {} is already subscribed",
+                feed.url()
+            )));
+        }
+        tables.feeds.push(feed.clone());
+        Ok(())
+    }
+
+    async fn find_by_id(&self, feed_id: Uuid) -> Result<Option<Feed>, FeedRepositoryError> {
+        Ok(self
+            .database
+            .tables()
+            .feeds
+            .iter()
+            .find(|feed| feed.id() == feed_id)
+            .cloned())
+    }
+
+    async fn find_by_url(&self, url: &str) -> Result<Option<Feed>, FeedRepositoryError> {
+        Ok(self
+            .database
+            .tables()
+            .feeds
+            .iter()
+            .find(|feed| feed.url() == url)
+            .cloned())
+    }
+
+    async fn find_all(&self) -> Result<Vec<Feed>, FeedRepositoryError> {
+        let mut feeds = self.database.tables().feeds.clone();
+        feeds.sort_by_key(Feed::created_at);
+        Ok(feeds)
+    }
+
+    async fn find_active(&self) -> Result<Vec<Feed>, FeedRepositoryError> {
+        let mut feeds: Vec<Feed> = self
+            .database
+            .tables()
+            .feeds
+            .iter()
+            .filter(|feed| feed.is_active())
+            .cloned()
+            .collect();
+        // Never polled first, as NULLS FIRST orders them
+        feeds.sort_by_key(Feed::last_polled_at);
+        Ok(feeds)
+    }
+
+    async fn update(&self, feed: &Feed) -> Result<(), FeedRepositoryError> {
+        if let Some(stored) = self
+            .database
+            .tables()
+            .feeds
+            .iter_mut()
+            .find(|stored| stored.id() == feed.id())
+        {
+            *stored = feed.clone();
+        }
+        Ok(())
+    }
+
+    async fn delete(&self, feed_id: Uuid) -> Result<bool, FeedRepositoryError> {
+        let mut tables = self.database.tables();
+        let before = tables.feeds.len();
+        tables.feeds.retain(|feed| feed.id() != feed_id);
+        tables
+            .feed_entries
+            .retain(|entry| entry.feed_id() != feed_id);
+        Ok(tables.feeds.len() < before)
+    }
+
+    async fn find_entry(
+        &self,
+        feed_id: Uuid,
+        guid: &str,
+    ) -> Result<Option<FeedEntry>, FeedRepositoryError> {
+        Ok(self
+            .database
+            .tables()
+            .feed_entries
+            .iter()
+            .find(|entry| entry.feed_id() == feed_id && entry.guid() == guid)
+            .cloned())
+    }
+
+    async fn find_entries(&self, feed_id: Uuid) -> Result<Vec<FeedEntry>, FeedRepositoryError> {
+        let mut entries: Vec<FeedEntry> = self
+            .database
+            .tables()
+            .feed_entries
+            .iter()
+            .filter(|entry| entry.feed_id() == feed_id)
+            .cloned()
+            .collect();
+        // Newest first, undated entries last
+        entries.sort_by_key(|entry| std::cmp::Reverse(entry.published_at()));
+        Ok(entries)
+    }
+
+    async fn save_entry(&self, entry: &FeedEntry) -> Result<(), FeedRepositoryError> {
+        let mut tables = self.database.tables();
+        // Upsert on (feed_id, guid), like the unique constraint
+        match tables
+            .feed_entries
+            .iter_mut()
+            .find(|stored| stored.feed_id() == entry.feed_id() && stored.guid() == entry.guid())
+        {
+            Some(stored) => *stored = entry.clone(),
+            None => tables.feed_entries.push(entry.clone()),
+        }
+        Ok(())
+    }
+}
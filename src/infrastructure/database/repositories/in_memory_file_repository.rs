@@ -0,0 +1,125 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::entities::File;
+use crate::domain::repositories::{FileRepository, file_repository::FileRepositoryError};
+use crate::infrastructure::database::in_memory::InMemoryDatabase;
+
+pub struct InMemoryFileRepository {
+    database: InMemoryDatabase,
+}
+
+impl InMemoryFileRepository {
+    pub fn new(database: InMemoryDatabase) -> Self {
+        Self { database }
+    }
+}
+
+#[async_trait]
+impl FileRepository for InMemoryFileRepository {
+    async fn save(&self, file: &File) -> Result<Uuid, FileRepositoryError> {
+        // New files carry a nil id for the database to assign, as Postgres does
+        let file = if file.id().is_nil() {
+            File::with_id(
+                Uuid::new_v4(),
+                file.file_path().to_string(),
+                file.file_name().to_string(),
+                file.file_size(),
+                file.file_type().map(str::to_string),
+                file.file_hash().cloned(),
+                file.created_at(),
+                file.updated_at(),
+                file.metadata().cloned(),
+                file.processing_status().clone(),
+            )
+        } else {
+            file.clone()
+        };
+
+        let mut tables = self.database.tables();
+        if tables.files.iter().any(|stored| stored.id() == file.id()) {
+            return Err(FileRepositoryError::DatabaseError(format!(
+                "File {} already exists",
+                file.id()
+            )));
+        }
+        let id = file.id();
+        tables.files.push(file);
+        Ok(id)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<File>, FileRepositoryError> {
+        Ok(self
+            .database
+            .tables()
+            .files
+            .iter()
+            .find(|file| file.id() == id)
+            .cloned())
+    }
+
+    async fn find_by_hash(&self, hash: &str) -> Result<Option<File>, FileRepositoryError> {
+        Ok(self
+            .database
+            .tables()
+            .files
+            .iter()
+            .find(|file| file.file_hash().is_some_and(|h| h.as_str() == hash))
+            .cloned())
+    }
+
+    async fn find_by_path(&self, path: &str) -> Result<Option<File>, FileRepositoryError> {
+        Ok(self
+            .database
+            .tables()
+            .files
+            .iter()
+            .filter(|file| file.file_path() == path)
+            .max_by_key(|file| file.created_at())
+            .cloned())
+    }
+
+    async fn find_by_path_prefix(&self, prefix: &str) -> Result<Vec<File>, FileRepositoryError> {
+        Ok(self
+            .database
+            .tables()
+            .files
+            .iter()
+            .filter(|file| file.file_path().starts_with(prefix))
+            .cloned()
+            .collect())
+    }
+
+    async fn find_all(&self, skip: i64, limit: i64) -> Result<Vec<File>, FileRepositoryError> {
+        let mut files = self.database.tables().files.clone();
+        // Newest first; among equal timestamps, the later insert
+        files.reverse();
+        files.sort_by_key(|file| std::cmp::Reverse(file.created_at()));
+        Ok(files
+            .into_iter()
+            .skip(skip.max(0) as usize)
+            .take(limit.max(0) as usize)
+            .collect())
+    }
+
+    async fn update(&self, file: &File) -> Result<(), FileRepositoryError> {
+        if let Some(stored) = self
+            .database
+            .tables()
+            .files
+            .iter_mut()
+            .find(|stored| stored.id() == file.id())
+        {
+            *stored = file.clone();
+        }
+        Ok(())
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<bool, FileRepositoryError> {
+        Ok(self.database.tables().delete_file(id))
+    }
+
+    async fn count(&self) -> Result<i64, FileRepositoryError> {
+        Ok(self.database.tables().files.len() as i64)
+    }
+}
@@ -0,0 +1,84 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::entities::ProcessingJob;
+use crate::domain::repositories::{JobRepository, job_repository::JobRepositoryError};
+use crate::infrastructure::database::in_memory::InMemoryDatabase;
+
+pub struct InMemoryJobRepository {
+    database: InMemoryDatabase,
+}
+
+impl InMemoryJobRepository {
+    pub fn new(database: InMemoryDatabase) -> Self {
+        Self { database }
+    }
+}
+
+#[async_trait]
+impl JobRepository for InMemoryJobRepository {
+    async fn save(&self, job: &ProcessingJob) -> Result<(), JobRepositoryError> {
+        let mut tables = self.database.tables();
+        if tables.jobs.iter().any(|stored| stored.id() == job.id()) {
+            return Err(JobRepositoryError::DatabaseError(format!(
+                "Failed to save job: {} already exists",
+                job.id()
+            )));
+        }
+        tables.jobs.push(job.clone());
+        Ok(())
+    }
+
+    async fn find_by_id(&self, job_id: Uuid) -> Result<Option<ProcessingJob>, JobRepositoryError> {
+        Ok(self
+            .database
+            .tables()
+            .jobs
+            .iter()
+            .find(|job| job.id() == job_id)
+            .cloned())
+    }
+
+    async fn find_by_file_id(
+        &self,
+        file_id: Uuid,
+    ) -> Result<Vec<ProcessingJob>, JobRepositoryError> {
+        let mut jobs: Vec<ProcessingJob> = self
+            .database
+            .tables()
+            .jobs
+            .iter()
+            .filter(|job| job.file_id() == Some(file_id))
+            .cloned()
+            .collect();
+        jobs.reverse();
+        jobs.sort_by_key(|job| std::cmp::Reverse(job.created_at()));
+        Ok(jobs)
+    }
+
+    async fn find_active_jobs(&self) -> Result<Vec<ProcessingJob>, JobRepositoryError> {
+        let mut jobs: Vec<ProcessingJob> = self
+            .database
+            .tables()
+            .jobs
+            .iter()
+            .filter(|job| job.is_active())
+            .cloned()
+            .collect();
+        jobs.sort_by_key(ProcessingJob::created_at);
+        Ok(jobs)
+    }
+
+    async fn update(&self, job: &ProcessingJob) -> Result<(), JobRepositoryError> {
+        if let Some(stored) = self
+            .database
+            .tables()
+            .jobs
+            .iter_mut()
+            .find(|stored| stored.id() == job.id())
+        {
+            *stored = job.clone();
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,69 @@
+use async_trait::async_trait;
+use lru::LruCache;
+use pgvector::Vector;
+use std::num::NonZeroUsize;
+use std::sync::{Arc, Mutex};
+
+use crate::domain::repositories::{
+    EmbeddingCacheRepository, embedding_cache_repository::EmbeddingCacheRepositoryError,
+};
+use crate::domain::value_objects::EmbeddingCacheKey;
+
+/// Keeps the most recently used entries in memory in front of another cache,
+/// which is only asked for the keys missing here
+pub struct LruEmbeddingCacheRepository {
+    inner: Arc<dyn EmbeddingCacheRepository>,
+    entries: Mutex<LruCache<EmbeddingCacheKey, Vector>>,
+}
+
+impl LruEmbeddingCacheRepository {
+    pub fn new(inner: Arc<dyn EmbeddingCacheRepository>, capacity: NonZeroUsize) -> Self {
+        Self {
+            inner,
+            entries: Mutex::new(LruCache::new(capacity)),
+        }
+    }
+
+    fn remember(&self, entries: &[(EmbeddingCacheKey, Vector)]) {
+        let mut cache = self.entries.lock().unwrap();
+        for (key, vector) in entries {
+            cache.put(key.clone(), vector.clone());
+        }
+    }
+}
+
+#[async_trait]
+impl EmbeddingCacheRepository for LruEmbeddingCacheRepository {
+    async fn find_many(
+        &self,
+        keys: &[EmbeddingCacheKey],
+    ) -> Result<Vec<(EmbeddingCacheKey, Vector)>, EmbeddingCacheRepositoryError> {
+        let mut found = Vec::new();
+        let mut missing = Vec::new();
+        {
+            let mut cache = self.entries.lock().unwrap();
+            for key in keys {
+                match cache.get(key) {
+                    Some(vector) => found.push((key.clone(), vector.clone())),
+                    None => missing.push(key.clone()),
+                }
+            }
+        }
+
+        if !missing.is_empty() {
+            let loaded = self.inner.find_many(&missing).await?;
+            self.remember(&loaded);
+            found.extend(loaded);
+        }
+
+        Ok(found)
+    }
+
+    async fn save_many(
+        &self,
+        entries: &[(EmbeddingCacheKey, Vector)],
+    ) -> Result<(), EmbeddingCacheRepositoryError> {
+        self.remember(entries);
+        self.inner.save_many(entries).await
+    }
+}
@@ -1,9 +1,37 @@
 pub mod postgres_file_repository;
 pub mod postgres_chunk_repository;
 pub mod postgres_embedding_repository;
+pub mod postgres_embedding_cache_repository;
+pub mod lru_embedding_cache_repository;
 pub mod postgres_job_repository;
+pub mod postgres_feed_repository;
+pub mod postgres_sparse_embedding_repository;
+#[cfg(test)]
+pub mod in_memory_file_repository;
+#[cfg(test)]
+pub mod in_memory_chunk_repository;
+#[cfg(test)]
+pub mod in_memory_embedding_repository;
+#[cfg(test)]
+pub mod in_memory_job_repository;
+#[cfg(test)]
+pub mod in_memory_feed_repository;
 
 pub use postgres_file_repository::PostgresFileRepository;
 pub use postgres_chunk_repository::PostgresChunkRepository;
 pub use postgres_embedding_repository::PostgresEmbeddingRepository;
+pub use postgres_embedding_cache_repository::PostgresEmbeddingCacheRepository;
+pub use lru_embedding_cache_repository::LruEmbeddingCacheRepository;
 pub use postgres_job_repository::PostgresJobRepository;
+pub use postgres_feed_repository::PostgresFeedRepository;
+pub use postgres_sparse_embedding_repository::PostgresSparseEmbeddingRepository;
+#[cfg(test)]
+pub use in_memory_file_repository::InMemoryFileRepository;
+#[cfg(test)]
+pub use in_memory_chunk_repository::InMemoryChunkRepository;
+#[cfg(test)]
+pub use in_memory_embedding_repository::InMemoryEmbeddingRepository;
+#[cfg(test)]
+pub use in_memory_job_repository::InMemoryJobRepository;
+#[cfg(test)]
+pub use in_memory_feed_repository::InMemoryFeedRepository;
@@ -1,9 +1,15 @@
 use async_trait::async_trait;
+use diesel::dsl::sql;
+use diesel::pg::Pg;
 use diesel::prelude::*;
+use diesel::sql_types::{Bool, Text};
 use uuid::Uuid;
 
 use crate::domain::entities::ContentChunk;
-use crate::domain::repositories::{ChunkRepository, chunk_repository::ChunkRepositoryError};
+use crate::domain::repositories::{
+    ChunkRepository,
+    chunk_repository::{ChunkIndexRange, ChunkRepositoryError},
+};
 use crate::infrastructure::database::models::{ContentChunkModel, NewContentChunkModel};
 use crate::infrastructure::database::schema::content_chunks::dsl::*;
 use crate::infrastructure::database::{DbPool, get_connection_from_pool};
@@ -101,6 +107,34 @@ impl ChunkRepository for PostgresChunkRepository {
         Ok(models.into_iter().map(ContentChunk::from).collect())
     }
 
+    async fn find_by_index_ranges(
+        &self,
+        ranges: &[ChunkIndexRange],
+    ) -> Result<Vec<ContentChunk>, ChunkRepositoryError> {
+        if ranges.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut conn = get_connection_from_pool(&self.pool)
+            .map_err(|e| ChunkRepositoryError::DatabaseError(e.to_string()))?;
+
+        let mut query = content_chunks.into_boxed();
+        for range in ranges {
+            query = query.or_filter(
+                file_id
+                    .eq(range.file_id)
+                    .and(chunk_index.between(range.start, range.end)),
+            );
+        }
+
+        let models = query
+            .order((file_id.asc(), chunk_index.asc()))
+            .load::<ContentChunkModel>(&mut conn)
+            .map_err(|e| ChunkRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(models.into_iter().map(ContentChunk::from).collect())
+    }
+
     async fn delete(&self, chunk_id: Uuid) -> Result<bool, ChunkRepositoryError> {
         let mut conn = get_connection_from_pool(&self.pool)
             .map_err(|e| ChunkRepositoryError::DatabaseError(e.to_string()))?;
@@ -133,4 +167,88 @@ impl ChunkRepository for PostgresChunkRepository {
             .get_result(&mut conn)
             .map_err(|e| ChunkRepositoryError::DatabaseError(e.to_string()))
     }
+
+    async fn count_by_language(&self) -> Result<Vec<(String, i64)>, ChunkRepositoryError> {
+        let mut conn = get_connection_from_pool(&self.pool)
+            .map_err(|e| ChunkRepositoryError::DatabaseError(e.to_string()))?;
+
+        let counts = content_chunks
+            .filter(language.is_not_null())
+            .group_by(language)
+            .select((language, diesel::dsl::count_star()))
+            .order(diesel::dsl::count_star().desc())
+            .load::<(Option<String>, i64)>(&mut conn)
+            .map_err(|e| ChunkRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(counts
+            .into_iter()
+            .filter_map(|(code, count)| code.map(|code| (code, count)))
+            .collect())
+    }
+
+    async fn find_missing_embeddings(
+        &self,
+        model: &str,
+        file_id_param: Option<Uuid>,
+        after: Option<Uuid>,
+        limit: i64,
+    ) -> Result<Vec<ContentChunk>, ChunkRepositoryError> {
+        let mut conn = get_connection_from_pool(&self.pool)
+            .map_err(|e| ChunkRepositoryError::DatabaseError(e.to_string()))?;
+
+        let mut query = missing_embeddings_query(model, file_id_param);
+        if let Some(after) = after {
+            query = query.filter(id.gt(after));
+        }
+
+        let models = query
+            .order(id.asc())
+            .limit(limit)
+            .load::<ContentChunkModel>(&mut conn)
+            .map_err(|e| ChunkRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(models.into_iter().map(ContentChunk::from).collect())
+    }
+
+    async fn count_missing_embeddings(
+        &self,
+        model: &str,
+        file_id_param: Option<Uuid>,
+    ) -> Result<i64, ChunkRepositoryError> {
+        let mut conn = get_connection_from_pool(&self.pool)
+            .map_err(|e| ChunkRepositoryError::DatabaseError(e.to_string()))?;
+
+        missing_embeddings_query(model, file_id_param)
+            .count()
+            .get_result(&mut conn)
+            .map_err(|e| ChunkRepositoryError::DatabaseError(e.to_string()))
+    }
+}
+
+/// Leaf chunks without a vector from `model`. The correlated subqueries are
+/// written as SQL since diesel cannot express references to the outer row.
+fn missing_embeddings_query(
+    model: &str,
+    file_id_param: Option<Uuid>,
+) -> crate::infrastructure::database::schema::content_chunks::BoxedQuery<'static, Pg> {
+    let mut query = content_chunks
+        .filter(sql::<Bool>(
+            "NOT EXISTS (SELECT 1 FROM content_chunks children \
+             WHERE children.parent_chunk_id = content_chunks.id)",
+        ))
+        .filter(
+            sql::<Bool>(
+                "NOT EXISTS (SELECT 1 FROM embeddings \
+                 WHERE embeddings.content_chunk_id = content_chunks.id \
+                 AND embeddings.model_name = ",
+            )
+            .bind::<Text, _>(model.to_string())
+            .sql(")"),
+        )
+        .into_boxed();
+
+    if let Some(file_id_param) = file_id_param {
+        query = query.filter(file_id.eq(file_id_param));
+    }
+    query
 }
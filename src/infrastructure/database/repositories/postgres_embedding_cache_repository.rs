@@ -0,0 +1,77 @@
+use async_trait::async_trait;
+use diesel::prelude::*;
+use pgvector::Vector;
+use std::collections::HashSet;
+
+use crate::domain::repositories::{
+    EmbeddingCacheRepository, embedding_cache_repository::EmbeddingCacheRepositoryError,
+};
+use crate::domain::value_objects::EmbeddingCacheKey;
+use crate::infrastructure::database::models::EmbeddingCacheModel;
+use crate::infrastructure::database::schema::embedding_cache::dsl::*;
+use crate::infrastructure::database::{DbPool, get_connection_from_pool};
+
+pub struct PostgresEmbeddingCacheRepository {
+    pool: DbPool,
+}
+
+impl PostgresEmbeddingCacheRepository {
+    pub fn new(pool: DbPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl EmbeddingCacheRepository for PostgresEmbeddingCacheRepository {
+    async fn find_many(
+        &self,
+        keys: &[EmbeddingCacheKey],
+    ) -> Result<Vec<(EmbeddingCacheKey, Vector)>, EmbeddingCacheRepositoryError> {
+        if keys.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut conn = get_connection_from_pool(&self.pool)
+            .map_err(|e| EmbeddingCacheRepositoryError::DatabaseError(e.to_string()))?;
+
+        let hashes: HashSet<&str> = keys.iter().map(|key| key.text_hash.as_str()).collect();
+        let models = embedding_cache
+            .filter(text_hash.eq_any(hashes))
+            .select(EmbeddingCacheModel::as_select())
+            .load::<EmbeddingCacheModel>(&mut conn)
+            .map_err(|e| EmbeddingCacheRepositoryError::DatabaseError(e.to_string()))?;
+
+        // The hash narrows the lookup; the rest of the key is matched here
+        let wanted: HashSet<&EmbeddingCacheKey> = keys.iter().collect();
+        Ok(models
+            .into_iter()
+            .map(EmbeddingCacheModel::into_entry)
+            .filter(|(key, _)| wanted.contains(key))
+            .collect())
+    }
+
+    async fn save_many(
+        &self,
+        entries: &[(EmbeddingCacheKey, Vector)],
+    ) -> Result<(), EmbeddingCacheRepositoryError> {
+        if entries.is_empty() {
+            return Ok(());
+        }
+
+        let mut conn = get_connection_from_pool(&self.pool)
+            .map_err(|e| EmbeddingCacheRepositoryError::DatabaseError(e.to_string()))?;
+
+        let models: Vec<EmbeddingCacheModel> = entries
+            .iter()
+            .map(|(key, vector)| EmbeddingCacheModel::new(key, vector.clone()))
+            .collect();
+
+        diesel::insert_into(embedding_cache)
+            .values(&models)
+            .on_conflict_do_nothing()
+            .execute(&mut conn)
+            .map_err(|e| EmbeddingCacheRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+}
@@ -1,24 +1,75 @@
 use async_trait::async_trait;
+use diesel::dsl::sql;
 use diesel::prelude::*;
-use pgvector::Vector;
+use diesel::sql_types::Double;
+use pgvector::sql_types::Vector as VectorType;
+use pgvector::{Vector, VectorExpressionMethods};
+use sha2::{Digest, Sha256};
 use uuid::Uuid;
 
 use crate::domain::entities::Embedding;
 use crate::domain::repositories::{
     EmbeddingRepository,
-    embedding_repository::{EmbeddingRepositoryError, SimilaritySearchResult},
+    embedding_repository::{
+        EmbeddingRepositoryError, SimilaritySearchFilter, SimilaritySearchResult,
+    },
 };
+use crate::domain::value_objects::VectorStorage;
 use crate::infrastructure::database::models::{EmbeddingModel, NewEmbeddingModel};
 use crate::infrastructure::database::schema::embeddings::dsl::*;
 use crate::infrastructure::database::{DbPool, get_connection_from_pool};
 
+/// pgvector's default candidate list size for HNSW scans, which also caps
+/// how many rows one scan returns
+const HNSW_DEFAULT_EF_SEARCH: usize = 40;
+/// The largest `hnsw.ef_search` pgvector accepts
+const HNSW_MAX_EF_SEARCH: usize = 1000;
+
 pub struct PostgresEmbeddingRepository {
     pool: DbPool,
+    storage: VectorStorage,
+    rescore_factor: usize,
 }
 
 impl PostgresEmbeddingRepository {
     pub fn new(pool: DbPool) -> Self {
-        Self { pool }
+        Self {
+            pool,
+            storage: VectorStorage::default(),
+            rescore_factor: VectorStorage::default().default_rescore_factor(),
+        }
+    }
+
+    /// Indexes vectors as `storage`; quantized searches fetch
+    /// `rescore_factor` candidates per result and rescore them exactly
+    pub fn with_storage(mut self, storage: VectorStorage, rescore_factor: usize) -> Self {
+        self.storage = storage;
+        self.rescore_factor = rescore_factor.max(1);
+        self
+    }
+}
+
+/// Each storage mode has its own index, so switching modes can build the new
+/// one before dropping the old
+fn index_name(storage: VectorStorage, model: &str) -> String {
+    let hash = format!("{:x}", Sha256::digest(model.as_bytes()));
+    match storage {
+        VectorStorage::Full => format!("embeddings_hnsw_{}", &hash[..16]),
+        VectorStorage::HalfPrecision => format!("embeddings_hnsw_half_{}", &hash[..16]),
+        VectorStorage::Binary => format!("embeddings_hnsw_bit_{}", &hash[..16]),
+    }
+}
+
+fn index_definition(storage: VectorStorage, dimension: i32) -> String {
+    match storage {
+        VectorStorage::Full => format!("(embedding::vector({})) vector_cosine_ops", dimension),
+        VectorStorage::HalfPrecision => {
+            format!("(embedding::halfvec({})) halfvec_cosine_ops", dimension)
+        }
+        VectorStorage::Binary => format!(
+            "(binary_quantize(embedding)::bit({})) bit_hamming_ops",
+            dimension
+        ),
     }
 }
 
@@ -134,6 +185,33 @@ impl EmbeddingRepository for PostgresEmbeddingRepository {
         Ok(domain_embeddings)
     }
 
+    async fn find_by_chunk_ids(
+        &self,
+        chunk_ids: &[Uuid],
+        model: &str,
+    ) -> Result<Vec<Embedding>, EmbeddingRepositoryError> {
+        if chunk_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut conn = get_connection_from_pool(&self.pool)
+            .map_err(|e| EmbeddingRepositoryError::DatabaseError(e.to_string()))?;
+
+        let models = embeddings
+            .filter(content_chunk_id.eq_any(chunk_ids.iter().map(|chunk_id| Some(*chunk_id))))
+            .filter(model_name.eq(model))
+            .select(EmbeddingModel::as_select())
+            .load::<EmbeddingModel>(&mut conn)
+            .map_err(|e| EmbeddingRepositoryError::DatabaseError(e.to_string()))?;
+
+        models
+            .into_iter()
+            .map(|model| {
+                Embedding::try_from(model).map_err(EmbeddingRepositoryError::ValidationError)
+            })
+            .collect()
+    }
+
     async fn similarity_search(
         &self,
         query_vector: &Vector,
@@ -233,6 +311,112 @@ impl EmbeddingRepository for PostgresEmbeddingRepository {
         Ok(results)
     }
 
+    async fn similarity_search_filtered(
+        &self,
+        query_vector: &Vector,
+        limit: i32,
+        similarity_threshold: Option<f32>,
+        filter: &SimilaritySearchFilter,
+    ) -> Result<Vec<SimilaritySearchResult>, EmbeddingRepositoryError> {
+        let mut conn = get_connection_from_pool(&self.pool)
+            .map_err(|e| EmbeddingRepositoryError::DatabaseError(e.to_string()))?;
+
+        use crate::infrastructure::database::schema::content_chunks::dsl as chunks_dsl;
+
+        let mut query = embeddings
+            .inner_join(
+                chunks_dsl::content_chunks.on(content_chunk_id.eq(chunks_dsl::id.nullable())),
+            )
+            .filter(embedding.is_not_null())
+            .into_boxed();
+
+        if let Some(file_id_param) = filter.file_id {
+            query = query.filter(chunks_dsl::file_id.eq(file_id_param));
+        }
+        if !filter.languages.is_empty() {
+            query = query.filter(chunks_dsl::language.eq_any(filter.languages.clone()));
+        }
+
+        // Quantized indexes only rank roughly, so they supply a wider set of
+        // candidates that is rescored below against the full vectors
+        let rescore = filter.model_name.is_some() && self.storage.is_quantized();
+        let candidates = if rescore {
+            (limit.max(0) as usize * self.rescore_factor).min(HNSW_MAX_EF_SEARCH)
+        } else {
+            limit.max(0) as usize
+        };
+
+        // Nearest neighbours first, so filters apply to the whole corpus rather than a sample
+        let dimension = query_vector.as_slice().len();
+        query =
+            match &filter.model_name {
+                // Ordering by the same expression the model's partial index is built on lets it be used
+                Some(model) => {
+                    let query = query.filter(model_name.eq(model.clone()));
+                    match self.storage {
+                    VectorStorage::Full => query.order(
+                        sql::<Double>(&format!("embeddings.embedding::vector({}) <=> ", dimension))
+                            .bind::<VectorType, _>(query_vector.clone()),
+                    ),
+                    VectorStorage::HalfPrecision => query.order(
+                        sql::<Double>(&format!("embeddings.embedding::halfvec({}) <=> ", dimension))
+                            .bind::<VectorType, _>(query_vector.clone())
+                            .sql(&format!("::halfvec({})", dimension)),
+                    ),
+                    VectorStorage::Binary => query.order(
+                        sql::<Double>(&format!(
+                            "binary_quantize(embeddings.embedding)::bit({}) <~> binary_quantize(",
+                            dimension
+                        ))
+                        .bind::<VectorType, _>(query_vector.clone())
+                        .sql(")"),
+                    ),
+                }
+                }
+                None => query.order(embedding.cosine_distance(query_vector.clone())),
+            };
+        let query = query
+            .limit(candidates as i64)
+            .select(EmbeddingModel::as_select());
+        let models = conn
+            .transaction(|conn| {
+                // An HNSW scan returns at most ef_search rows
+                if candidates > HNSW_DEFAULT_EF_SEARCH {
+                    diesel::sql_query(format!("SET LOCAL hnsw.ef_search = {}", candidates))
+                        .execute(conn)?;
+                }
+                query.load::<EmbeddingModel>(conn)
+            })
+            .map_err(|e| EmbeddingRepositoryError::DatabaseError(e.to_string()))?;
+
+        let mut results = Vec::new();
+        for model in models {
+            if let (Some(emb_vector), Some(chunk_id)) = (&model.embedding, model.content_chunk_id) {
+                let similarity_score = calculate_cosine_similarity(query_vector, emb_vector);
+
+                if similarity_threshold.is_some_and(|threshold| similarity_score < threshold) {
+                    continue;
+                }
+
+                let domain_embedding = Embedding::try_from(model)
+                    .map_err(EmbeddingRepositoryError::ValidationError)?;
+
+                results.push(SimilaritySearchResult {
+                    embedding: domain_embedding,
+                    similarity_score,
+                    chunk_id,
+                });
+            }
+        }
+
+        if rescore {
+            results.sort_by(|a, b| b.similarity_score.total_cmp(&a.similarity_score));
+            results.truncate(limit.max(0) as usize);
+        }
+
+        Ok(results)
+    }
+
     // async fn update(&self, embedding_entity: &Embedding) -> Result<(), EmbeddingRepositoryError> {
     //     let mut conn = get_connection_from_pool(&self.pool)
     //         .map_err(|e| EmbeddingRepositoryError::DatabaseError(e.to_string()))?;
@@ -307,6 +491,139 @@ impl EmbeddingRepository for PostgresEmbeddingRepository {
             .map_err(|e| EmbeddingRepositoryError::DatabaseError(e.to_string()))
     }
 
+    async fn register_model(
+        &self,
+        model: &str,
+        dimension: usize,
+    ) -> Result<usize, EmbeddingRepositoryError> {
+        use crate::infrastructure::database::schema::embedding_models::dsl as models_dsl;
+
+        let mut conn = get_connection_from_pool(&self.pool)
+            .map_err(|e| EmbeddingRepositoryError::DatabaseError(e.to_string()))?;
+
+        let requested = i32::try_from(dimension).map_err(|_| {
+            EmbeddingRepositoryError::ValidationError(format!("Invalid dimension {}", dimension))
+        })?;
+        // Checked before anything is registered, since the index DDL would fail
+        if dimension > self.storage.max_index_dimension() {
+            return Err(EmbeddingRepositoryError::ValidationError(format!(
+                "Embedding model {} produces {}-dimensional vectors, more than a {} index \
+                 holds ({}); set EMBEDDING_STORAGE=halfvec or binary",
+                model,
+                dimension,
+                self.storage.as_str(),
+                self.storage.max_index_dimension()
+            )));
+        }
+        diesel::insert_into(models_dsl::embedding_models)
+            .values((
+                models_dsl::name.eq(model),
+                models_dsl::dimension.eq(requested),
+                models_dsl::storage.eq(self.storage.as_str()),
+            ))
+            .on_conflict_do_nothing()
+            .execute(&mut conn)
+            .map_err(|e| EmbeddingRepositoryError::DatabaseError(e.to_string()))?;
+
+        let (registered, indexed_as) = models_dsl::embedding_models
+            .find(model)
+            .select((models_dsl::dimension, models_dsl::storage))
+            .first::<(i32, String)>(&mut conn)
+            .map_err(|e| EmbeddingRepositoryError::DatabaseError(e.to_string()))?;
+        if registered != requested {
+            return Ok(registered as usize);
+        }
+
+        let switching = indexed_as != self.storage.as_str();
+        if switching {
+            eprintln!(
+                "Indexing {} vectors as {} instead of {}; building the new index over the stored vectors",
+                model,
+                self.storage.as_str(),
+                indexed_as
+            );
+        }
+
+        // Index DDL cannot take bind parameters; the model name is quoted as
+        // a literal and the index named after a hash of it
+        diesel::sql_query(format!(
+            "CREATE INDEX IF NOT EXISTS {} ON embeddings USING hnsw ({}) WHERE model_name = '{}'",
+            index_name(self.storage, model),
+            index_definition(self.storage, registered),
+            model.replace('\'', "''")
+        ))
+        .execute(&mut conn)
+        .map_err(|e| EmbeddingRepositoryError::DatabaseError(e.to_string()))?;
+
+        // Only once the new index covers every vector is the old one dropped
+        if switching {
+            for storage in [
+                VectorStorage::Full,
+                VectorStorage::HalfPrecision,
+                VectorStorage::Binary,
+            ] {
+                if storage != self.storage {
+                    diesel::sql_query(format!(
+                        "DROP INDEX IF EXISTS {}",
+                        index_name(storage, model)
+                    ))
+                    .execute(&mut conn)
+                    .map_err(|e| EmbeddingRepositoryError::DatabaseError(e.to_string()))?;
+                }
+            }
+            diesel::update(models_dsl::embedding_models.find(model))
+                .set(models_dsl::storage.eq(self.storage.as_str()))
+                .execute(&mut conn)
+                .map_err(|e| EmbeddingRepositoryError::DatabaseError(e.to_string()))?;
+        }
+
+        Ok(registered as usize)
+    }
+
+    async fn active_model(&self) -> Result<Option<String>, EmbeddingRepositoryError> {
+        use crate::infrastructure::database::schema::embedding_models::dsl as models_dsl;
+
+        let mut conn = get_connection_from_pool(&self.pool)
+            .map_err(|e| EmbeddingRepositoryError::DatabaseError(e.to_string()))?;
+
+        models_dsl::embedding_models
+            .filter(models_dsl::is_active.eq(true))
+            .select(models_dsl::name)
+            .first::<String>(&mut conn)
+            .optional()
+            .map_err(|e| EmbeddingRepositoryError::DatabaseError(e.to_string()))
+    }
+
+    async fn activate_model(&self, model: &str) -> Result<(), EmbeddingRepositoryError> {
+        use crate::infrastructure::database::schema::embedding_models::dsl as models_dsl;
+
+        let mut conn = get_connection_from_pool(&self.pool)
+            .map_err(|e| EmbeddingRepositoryError::DatabaseError(e.to_string()))?;
+
+        // The partial unique index on is_active is checked row by row, so the
+        // old model is cleared before the new one is set; the transaction
+        // keeps searches from seeing neither
+        conn.transaction(|conn| {
+            diesel::update(models_dsl::embedding_models.filter(models_dsl::is_active))
+                .set(models_dsl::is_active.eq(false))
+                .execute(conn)?;
+            let updated = diesel::update(models_dsl::embedding_models.find(model))
+                .set(models_dsl::is_active.eq(true))
+                .execute(conn)?;
+            // Rolls back the deactivation, leaving the current model active
+            if updated == 0 {
+                return Err(diesel::result::Error::NotFound);
+            }
+            Ok(())
+        })
+        .map_err(|e| match e {
+            diesel::result::Error::NotFound => {
+                EmbeddingRepositoryError::ModelNotFound(model.to_string())
+            }
+            e => EmbeddingRepositoryError::DatabaseError(e.to_string()),
+        })
+    }
+
     // async fn count_by_model(
     //     &self,
     //     model_name_param: &str,
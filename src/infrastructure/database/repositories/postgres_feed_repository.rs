@@ -0,0 +1,237 @@
+use async_trait::async_trait;
+use diesel::prelude::*;
+use diesel::r2d2::{ConnectionManager, Pool};
+use uuid::Uuid;
+
+use crate::domain::entities::{Feed, FeedEntry};
+use crate::domain::repositories::{FeedRepository, feed_repository::FeedRepositoryError};
+use crate::infrastructure::database::models::{FeedEntryModel, FeedModel};
+use crate::infrastructure::database::schema::{feed_entries, feeds};
+
+pub struct PostgresFeedRepository {
+    pool: Pool<ConnectionManager<PgConnection>>,
+}
+
+impl PostgresFeedRepository {
+    pub fn new(pool: Pool<ConnectionManager<PgConnection>>) -> Self {
+        Self { pool }
+    }
+
+    fn get_connection(
+        &self,
+    ) -> Result<diesel::r2d2::PooledConnection<ConnectionManager<PgConnection>>, FeedRepositoryError>
+    {
+        self.pool.get().map_err(|e| {
+            FeedRepositoryError::DatabaseError(format!("Failed to get database connection: {}", e))
+        })
+    }
+}
+
+#[async_trait]
+impl FeedRepository for PostgresFeedRepository {
+    async fn save(&self, feed: &Feed) -> Result<(), FeedRepositoryError> {
+        let new_feed = FeedModel::from(feed);
+        let mut conn = self.get_connection()?;
+
+        tokio::task::spawn_blocking(move || {
+            diesel::insert_into(feeds::table)
+                .values(&new_feed)
+                .execute(&mut conn)
+                .map_err(|e| {
+                    FeedRepositoryError::DatabaseError(format!("Failed to save feed: {}", e))
+                })
+        })
+        .await
+        .map_err(|e| FeedRepositoryError::DatabaseError(format!("Task join error: {}", e)))??;
+
+        Ok(())
+    }
+
+    async fn find_by_id(&self, feed_id: Uuid) -> Result<Option<Feed>, FeedRepositoryError> {
+        let mut conn = self.get_connection()?;
+
+        let result = tokio::task::spawn_blocking(move || {
+            feeds::table
+                .filter(feeds::id.eq(feed_id))
+                .first::<FeedModel>(&mut conn)
+                .optional()
+                .map_err(|e| {
+                    FeedRepositoryError::DatabaseError(format!("Failed to find feed: {}", e))
+                })
+        })
+        .await
+        .map_err(|e| FeedRepositoryError::DatabaseError(format!("Task join error: {}", e)))??;
+
+        Ok(result.map(Feed::from))
+    }
+
+    async fn find_by_url(&self, url: &str) -> Result<Option<Feed>, FeedRepositoryError> {
+        let url = url.to_string();
+        let mut conn = self.get_connection()?;
+
+        let result = tokio::task::spawn_blocking(move || {
+            feeds::table
+                .filter(feeds::url.eq(url))
+                .first::<FeedModel>(&mut conn)
+                .optional()
+                .map_err(|e| {
+                    FeedRepositoryError::DatabaseError(format!("Failed to find feed by url: {}", e))
+                })
+        })
+        .await
+        .map_err(|e| FeedRepositoryError::DatabaseError(format!("Task join error: {}", e)))??;
+
+        Ok(result.map(Feed::from))
+    }
+
+    async fn find_all(&self) -> Result<Vec<Feed>, FeedRepositoryError> {
+        let mut conn = self.get_connection()?;
+
+        let models = tokio::task::spawn_blocking(move || {
+            feeds::table
+                .order(feeds::created_at.asc())
+                .load::<FeedModel>(&mut conn)
+                .map_err(|e| {
+                    FeedRepositoryError::DatabaseError(format!("Failed to list feeds: {}", e))
+                })
+        })
+        .await
+        .map_err(|e| FeedRepositoryError::DatabaseError(format!("Task join error: {}", e)))??;
+
+        Ok(models.into_iter().map(Feed::from).collect())
+    }
+
+    async fn find_active(&self) -> Result<Vec<Feed>, FeedRepositoryError> {
+        let mut conn = self.get_connection()?;
+
+        let models = tokio::task::spawn_blocking(move || {
+            feeds::table
+                .filter(feeds::is_active.eq(true))
+                .order(feeds::last_polled_at.asc().nulls_first())
+                .load::<FeedModel>(&mut conn)
+                .map_err(|e| {
+                    FeedRepositoryError::DatabaseError(format!(
+                        "Failed to find active feeds: {}",
+                        e
+                    ))
+                })
+        })
+        .await
+        .map_err(|e| FeedRepositoryError::DatabaseError(format!("Task join error: {}", e)))??;
+
+        Ok(models.into_iter().map(Feed::from).collect())
+    }
+
+    async fn update(&self, feed: &Feed) -> Result<(), FeedRepositoryError> {
+        let update_feed = FeedModel::from(feed);
+        let feed_id = feed.id();
+        let mut conn = self.get_connection()?;
+
+        let updated = tokio::task::spawn_blocking(move || {
+            diesel::update(feeds::table.filter(feeds::id.eq(feed_id)))
+                .set(&update_feed)
+                .execute(&mut conn)
+                .map_err(|e| {
+                    FeedRepositoryError::DatabaseError(format!("Failed to update feed: {}", e))
+                })
+        })
+        .await
+        .map_err(|e| FeedRepositoryError::DatabaseError(format!("Task join error: {}", e)))??;
+
+        if updated == 0 {
+            return Err(FeedRepositoryError::NotFound(feed_id));
+        }
+
+        Ok(())
+    }
+
+    async fn delete(&self, feed_id: Uuid) -> Result<bool, FeedRepositoryError> {
+        let mut conn = self.get_connection()?;
+
+        let deleted = tokio::task::spawn_blocking(move || {
+            diesel::delete(feeds::table.filter(feeds::id.eq(feed_id)))
+                .execute(&mut conn)
+                .map_err(|e| {
+                    FeedRepositoryError::DatabaseError(format!("Failed to delete feed: {}", e))
+                })
+        })
+        .await
+        .map_err(|e| FeedRepositoryError::DatabaseError(format!("Task join error: {}", e)))??;
+
+        Ok(deleted > 0)
+    }
+
+    async fn find_entry(
+        &self,
+        feed_id: Uuid,
+        guid: &str,
+    ) -> Result<Option<FeedEntry>, FeedRepositoryError> {
+        let guid = guid.to_string();
+        let mut conn = self.get_connection()?;
+
+        let result = tokio::task::spawn_blocking(move || {
+            feed_entries::table
+                .filter(feed_entries::feed_id.eq(feed_id))
+                .filter(feed_entries::guid.eq(guid))
+                .first::<FeedEntryModel>(&mut conn)
+                .optional()
+                .map_err(|e| {
+                    FeedRepositoryError::DatabaseError(format!("Failed to find feed entry: {}", e))
+                })
+        })
+        .await
+        .map_err(|e| FeedRepositoryError::DatabaseError(format!("Task join error: {}", e)))??;
+
+        Ok(result.map(FeedEntry::from))
+    }
+
+    async fn find_entries(&self, feed_id: Uuid) -> Result<Vec<FeedEntry>, FeedRepositoryError> {
+        let mut conn = self.get_connection()?;
+
+        let models = tokio::task::spawn_blocking(move || {
+            feed_entries::table
+                .filter(feed_entries::feed_id.eq(feed_id))
+                .order(feed_entries::published_at.desc().nulls_last())
+                .load::<FeedEntryModel>(&mut conn)
+                .map_err(|e| {
+                    FeedRepositoryError::DatabaseError(format!(
+                        "Failed to find feed entries: {}",
+                        e
+                    ))
+                })
+        })
+        .await
+        .map_err(|e| FeedRepositoryError::DatabaseError(format!("Task join error: {}", e)))??;
+
+        Ok(models.into_iter().map(FeedEntry::from).collect())
+    }
+
+    async fn save_entry(&self, entry: &FeedEntry) -> Result<(), FeedRepositoryError> {
+        let model = FeedEntryModel::from(entry);
+        let mut conn = self.get_connection()?;
+
+        // Upsert on (feed_id, guid) so updated items overwrite their previous row
+        tokio::task::spawn_blocking(move || {
+            diesel::insert_into(feed_entries::table)
+                .values(&model)
+                .on_conflict((feed_entries::feed_id, feed_entries::guid))
+                .do_update()
+                .set((
+                    feed_entries::link.eq(&model.link),
+                    feed_entries::title.eq(&model.title),
+                    feed_entries::published_at.eq(&model.published_at),
+                    feed_entries::content_hash.eq(&model.content_hash),
+                    feed_entries::file_id.eq(&model.file_id),
+                    feed_entries::updated_at.eq(&model.updated_at),
+                ))
+                .execute(&mut conn)
+                .map_err(|e| {
+                    FeedRepositoryError::DatabaseError(format!("Failed to save feed entry: {}", e))
+                })
+        })
+        .await
+        .map_err(|e| FeedRepositoryError::DatabaseError(format!("Task join error: {}", e)))??;
+
+        Ok(())
+    }
+}
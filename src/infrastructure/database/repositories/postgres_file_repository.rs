@@ -75,6 +75,55 @@ impl FileRepository for PostgresFileRepository {
         }
     }
 
+    async fn find_by_path(&self, path: &str) -> Result<Option<File>, FileRepositoryError> {
+        let mut conn = get_connection_from_pool(&self.pool)
+            .map_err(|e| FileRepositoryError::DatabaseError(e.to_string()))?;
+
+        let result = files
+            .filter(file_path.eq(path))
+            .order(created_at.desc())
+            .first::<FileModel>(&mut conn)
+            .optional()
+            .map_err(|e| FileRepositoryError::DatabaseError(e.to_string()))?;
+
+        match result {
+            Some(model) => {
+                let domain_file =
+                    File::try_from(model).map_err(FileRepositoryError::ValidationError)?;
+                Ok(Some(domain_file))
+            }
+            None => Ok(None),
+        }
+    }
+
+    async fn find_by_path_prefix(&self, prefix: &str) -> Result<Vec<File>, FileRepositoryError> {
+        let mut conn = get_connection_from_pool(&self.pool)
+            .map_err(|e| FileRepositoryError::DatabaseError(e.to_string()))?;
+
+        // Escape LIKE wildcards so the prefix is matched literally
+        let pattern = format!(
+            "{}%",
+            prefix
+                .replace('\\', "\\\\")
+                .replace('%', "\\%")
+                .replace('_', "\\_")
+        );
+
+        let models = files
+            .filter(file_path.like(pattern))
+            .load::<FileModel>(&mut conn)
+            .map_err(|e| FileRepositoryError::DatabaseError(e.to_string()))?;
+
+        let mut domain_files = Vec::new();
+        for model in models {
+            let domain_file =
+                File::try_from(model).map_err(FileRepositoryError::ValidationError)?;
+            domain_files.push(domain_file);
+        }
+
+        Ok(domain_files)
+    }
+
     async fn find_all(&self, skip: i64, limit: i64) -> Result<Vec<File>, FileRepositoryError> {
         let mut conn = get_connection_from_pool(&self.pool)
             .map_err(|e| FileRepositoryError::DatabaseError(e.to_string()))?;
@@ -0,0 +1,158 @@
+use async_trait::async_trait;
+use diesel::dsl::sql;
+use diesel::prelude::*;
+use diesel::sql_types::Double;
+use pgvector::SparseVector;
+use pgvector::sql_types::SparseVector as SparseVectorType;
+use sha2::{Digest, Sha256};
+
+use crate::domain::entities::SparseEmbedding;
+use crate::domain::repositories::{
+    SparseEmbeddingRepository,
+    embedding_repository::{EmbeddingRepositoryError, SimilaritySearchFilter},
+    sparse_embedding_repository::SparseSearchResult,
+};
+use crate::infrastructure::database::models::{NewSparseEmbeddingModel, SparseEmbeddingModel};
+use crate::infrastructure::database::schema::sparse_embeddings::dsl::*;
+use crate::infrastructure::database::{DbPool, get_connection_from_pool};
+
+pub struct PostgresSparseEmbeddingRepository {
+    pool: DbPool,
+}
+
+impl PostgresSparseEmbeddingRepository {
+    pub fn new(pool: DbPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl SparseEmbeddingRepository for PostgresSparseEmbeddingRepository {
+    async fn save_batch(
+        &self,
+        embedding_entities: &[SparseEmbedding],
+    ) -> Result<(), EmbeddingRepositoryError> {
+        if embedding_entities.is_empty() {
+            return Ok(());
+        }
+
+        let mut conn = get_connection_from_pool(&self.pool)
+            .map_err(|e| EmbeddingRepositoryError::DatabaseError(e.to_string()))?;
+
+        let new_embeddings: Vec<NewSparseEmbeddingModel> = embedding_entities
+            .iter()
+            .map(NewSparseEmbeddingModel::from)
+            .collect();
+
+        diesel::insert_into(sparse_embeddings)
+            .values(&new_embeddings)
+            .execute(&mut conn)
+            .map_err(|e| EmbeddingRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn search(
+        &self,
+        query_vector: &SparseVector,
+        limit: i32,
+        filter: &SimilaritySearchFilter,
+    ) -> Result<Vec<SparseSearchResult>, EmbeddingRepositoryError> {
+        let Some(model) = &filter.model_name else {
+            return Err(EmbeddingRepositoryError::ValidationError(
+                "Sparse search needs the model whose vectors to search".to_string(),
+            ));
+        };
+
+        let mut conn = get_connection_from_pool(&self.pool)
+            .map_err(|e| EmbeddingRepositoryError::DatabaseError(e.to_string()))?;
+
+        use crate::infrastructure::database::schema::content_chunks::dsl as chunks_dsl;
+
+        let mut query = sparse_embeddings
+            .inner_join(chunks_dsl::content_chunks.on(content_chunk_id.eq(chunks_dsl::id)))
+            .filter(model_name.eq(model.clone()))
+            .into_boxed();
+
+        if let Some(file_id_param) = filter.file_id {
+            query = query.filter(chunks_dsl::file_id.eq(file_id_param));
+        }
+        if !filter.languages.is_empty() {
+            query = query.filter(chunks_dsl::language.eq_any(filter.languages.clone()));
+        }
+
+        // `<#>` is the negated inner product, so ascending order puts the
+        // best match first; the cast matches the model's partial index
+        let models = query
+            .order(
+                sql::<Double>(&format!(
+                    "sparse_embeddings.embedding::sparsevec({}) <#> ",
+                    query_vector.dimensions()
+                ))
+                .bind::<SparseVectorType, _>(query_vector.clone()),
+            )
+            .limit(limit.into())
+            .select(SparseEmbeddingModel::as_select())
+            .load::<SparseEmbeddingModel>(&mut conn)
+            .map_err(|e| EmbeddingRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(models
+            .into_iter()
+            .map(SparseEmbedding::from)
+            .map(|stored| SparseSearchResult {
+                chunk_id: stored.content_chunk_id(),
+                score: stored.inner_product(query_vector),
+            })
+            .collect())
+    }
+
+    async fn register_model(
+        &self,
+        model: &str,
+        dimension: usize,
+    ) -> Result<usize, EmbeddingRepositoryError> {
+        use crate::infrastructure::database::schema::sparse_embedding_models::dsl as models_dsl;
+
+        let mut conn = get_connection_from_pool(&self.pool)
+            .map_err(|e| EmbeddingRepositoryError::DatabaseError(e.to_string()))?;
+
+        let requested = i32::try_from(dimension).map_err(|_| {
+            EmbeddingRepositoryError::ValidationError(format!("Invalid dimension {}", dimension))
+        })?;
+        diesel::insert_into(models_dsl::sparse_embedding_models)
+            .values((
+                models_dsl::name.eq(model),
+                models_dsl::dimension.eq(requested),
+            ))
+            .on_conflict_do_nothing()
+            .execute(&mut conn)
+            .map_err(|e| EmbeddingRepositoryError::DatabaseError(e.to_string()))?;
+
+        let registered = models_dsl::sparse_embedding_models
+            .find(model)
+            .select(models_dsl::dimension)
+            .first::<i32>(&mut conn)
+            .map_err(|e| EmbeddingRepositoryError::DatabaseError(e.to_string()))?;
+        if registered != requested {
+            return Ok(registered as usize);
+        }
+
+        // Same naming and quoting as the dense model indexes
+        let index_name = format!(
+            "sparse_embeddings_hnsw_{}",
+            &format!("{:x}", Sha256::digest(model.as_bytes()))[..16]
+        );
+        diesel::sql_query(format!(
+            "CREATE INDEX IF NOT EXISTS {} ON sparse_embeddings \
+             USING hnsw ((embedding::sparsevec({})) sparsevec_ip_ops) \
+             WHERE model_name = '{}'",
+            index_name,
+            registered,
+            model.replace('\'', "''")
+        ))
+        .execute(&mut conn)
+        .map_err(|e| EmbeddingRepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(registered as usize)
+    }
+}
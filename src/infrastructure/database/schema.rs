@@ -13,6 +13,35 @@ diesel::table! {
         page_number -> Nullable<Int4>,
         section_path -> Nullable<Text>,
         created_at -> Nullable<Timestamptz>,
+        language -> Nullable<Text>,
+        parent_chunk_id -> Nullable<Uuid>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use pgvector::sql_types::*;
+
+    embedding_cache (text_hash, model_name, model_version, template) {
+        text_hash -> Text,
+        model_name -> Text,
+        model_version -> Text,
+        template -> Text,
+        embedding -> Vector,
+        created_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use pgvector::sql_types::*;
+
+    embedding_models (name) {
+        name -> Text,
+        dimension -> Int4,
+        created_at -> Timestamptz,
+        is_active -> Bool,
+        storage -> Text,
     }
 }
 
@@ -31,6 +60,41 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use pgvector::sql_types::*;
+
+    feed_entries (id) {
+        id -> Uuid,
+        feed_id -> Uuid,
+        guid -> Text,
+        link -> Text,
+        title -> Nullable<Text>,
+        published_at -> Nullable<Timestamptz>,
+        content_hash -> Text,
+        file_id -> Nullable<Uuid>,
+        first_seen_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use pgvector::sql_types::*;
+
+    feeds (id) {
+        id -> Uuid,
+        url -> Text,
+        name -> Text,
+        poll_interval_seconds -> Int4,
+        is_active -> Bool,
+        last_polled_at -> Nullable<Timestamptz>,
+        last_error -> Nullable<Text>,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use pgvector::sql_types::*;
@@ -54,7 +118,7 @@ diesel::table! {
 
     processing_jobs (id) {
         id -> Uuid,
-        file_id -> Uuid,
+        file_id -> Nullable<Uuid>,
         job_type -> Varchar,
         job_data -> Nullable<Jsonb>,
         status -> Varchar,
@@ -81,13 +145,47 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use pgvector::sql_types::*;
+
+    sparse_embedding_models (name) {
+        name -> Text,
+        dimension -> Int4,
+        created_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use pgvector::sql_types::*;
+
+    sparse_embeddings (id) {
+        id -> Uuid,
+        content_chunk_id -> Uuid,
+        model_name -> Text,
+        model_version -> Nullable<Text>,
+        generated_at -> Timestamptz,
+        embedding -> SparseVector,
+    }
+}
+
 diesel::joinable!(content_chunks -> files (file_id));
 diesel::joinable!(embeddings -> content_chunks (content_chunk_id));
+diesel::joinable!(feed_entries -> feeds (feed_id));
+diesel::joinable!(feed_entries -> files (file_id));
+diesel::joinable!(sparse_embeddings -> content_chunks (content_chunk_id));
 
 diesel::allow_tables_to_appear_in_same_query!(
     content_chunks,
+    embedding_cache,
+    embedding_models,
     embeddings,
+    feed_entries,
+    feeds,
     files,
     processing_jobs,
     search_queries,
+    sparse_embedding_models,
+    sparse_embeddings,
 );
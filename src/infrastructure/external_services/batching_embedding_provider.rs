@@ -0,0 +1,514 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use futures::future::{BoxFuture, try_join_all};
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+use std::env;
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Semaphore;
+
+use crate::application::ports::Tokenizer;
+use crate::application::ports::embedding_provider::{
+    BatchEmbeddingRequest, BatchEmbeddingResponse, EmbeddingProvider, EmbeddingProviderError,
+    EmbeddingRequest, EmbeddingResponse, EndpointStatus,
+};
+use crate::domain::value_objects::EmbeddingPrompts;
+
+/// The wait a 429 response asks for, given in seconds or as an HTTP date;
+/// a date already past means retry now
+pub fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+    match value.parse::<f64>() {
+        Ok(seconds) => {
+            (seconds.is_finite() && seconds >= 0.0).then(|| Duration::from_secs_f64(seconds))
+        }
+        Err(_) => {
+            let at = DateTime::parse_from_rfc2822(value).ok()?;
+            Some(
+                (at.with_timezone(&Utc) - Utc::now())
+                    .to_std()
+                    .unwrap_or_default(),
+            )
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct EmbeddingBatchConfig {
+    /// Tokens sent in one request; TEI's default `--max-batch-tokens`
+    pub max_batch_tokens: usize,
+    /// Inputs sent in one request; TEI's default `--max-client-batch-size`
+    pub max_batch_size: usize,
+    /// Requests in flight to the model at once, across all workers
+    pub max_concurrency: usize,
+    /// Times a rate-limited request is retried before giving up
+    pub max_retries: u32,
+    /// Base of the exponential backoff when the server gives no Retry-After
+    pub backoff_factor: f64,
+}
+
+impl Default for EmbeddingBatchConfig {
+    fn default() -> Self {
+        Self {
+            max_batch_tokens: 16384,
+            max_batch_size: 32,
+            max_concurrency: 4,
+            max_retries: 5,
+            backoff_factor: 2.0,
+        }
+    }
+}
+
+impl EmbeddingBatchConfig {
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+        let var = |name: &str| env::var(name).ok().and_then(|s| s.parse::<usize>().ok());
+
+        Self {
+            max_batch_tokens: var("EMBEDDING_MAX_BATCH_TOKENS")
+                .unwrap_or(defaults.max_batch_tokens),
+            max_batch_size: var("EMBEDDING_MAX_BATCH_SIZE").unwrap_or(defaults.max_batch_size),
+            max_concurrency: var("EMBEDDING_CONCURRENCY").unwrap_or(defaults.max_concurrency),
+            max_retries: var("EMBEDDING_RATE_LIMIT_RETRIES")
+                .map(|retries| retries as u32)
+                .unwrap_or(defaults.max_retries),
+            ..defaults
+        }
+    }
+}
+
+/// Sits in front of a model's provider and owns how it is called: batch
+/// requests are packed into sub-batches by token budget and sent
+/// concurrently, up to a limit shared by everything using the model. A
+/// sub-batch the server rejects as too large is halved and retried, and
+/// rate-limited requests wait as long as the server asks.
+pub struct BatchingEmbeddingProvider {
+    inner: Arc<dyn EmbeddingProvider>,
+    tokenizer: Arc<dyn Tokenizer>,
+    permits: Semaphore,
+    config: EmbeddingBatchConfig,
+}
+
+impl BatchingEmbeddingProvider {
+    pub fn new(
+        inner: Arc<dyn EmbeddingProvider>,
+        tokenizer: Arc<dyn Tokenizer>,
+        config: EmbeddingBatchConfig,
+    ) -> Self {
+        Self {
+            inner,
+            tokenizer,
+            permits: Semaphore::new(config.max_concurrency.max(1)),
+            config,
+        }
+    }
+
+    /// Splits `texts` in order into batches within the size and token limits;
+    /// a text over the token budget on its own goes alone
+    fn pack(&self, texts: Vec<String>) -> Vec<Vec<String>> {
+        let mut batches: Vec<Vec<String>> = Vec::new();
+        let mut batch_tokens = 0;
+
+        for text in texts {
+            let tokens = self.tokenizer.count_tokens(&text);
+            match batches.last_mut() {
+                Some(batch)
+                    if batch.len() < self.config.max_batch_size
+                        && batch_tokens + tokens <= self.config.max_batch_tokens =>
+                {
+                    batch.push(text);
+                    batch_tokens += tokens;
+                }
+                _ => {
+                    batches.push(vec![text]);
+                    batch_tokens = tokens;
+                }
+            }
+        }
+
+        batches
+    }
+
+    /// Makes one call under a permit, repeating it while rate limited. The
+    /// permit is released while waiting so the wait does not count as load.
+    async fn send<T, F, Fut>(&self, call: F) -> Result<T, EmbeddingProviderError>
+    where
+        F: Fn() -> Fut,
+        Fut: Future<Output = Result<T, EmbeddingProviderError>>,
+    {
+        let mut attempts = 0;
+        loop {
+            let result = {
+                let _permit = self
+                    .permits
+                    .acquire()
+                    .await
+                    .map_err(|_| EmbeddingProviderError::ServiceUnavailable)?;
+                call().await
+            };
+
+            match result {
+                Err(EmbeddingProviderError::RateLimitExceeded(retry_after))
+                    if attempts < self.config.max_retries =>
+                {
+                    attempts += 1;
+                    let wait = retry_after.unwrap_or_else(|| {
+                        Duration::from_secs_f64(
+                            self.config.backoff_factor.powi(attempts as i32 - 1),
+                        )
+                    });
+                    tokio::time::sleep(wait).await;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    fn embed_batch(
+        &self,
+        request: BatchEmbeddingRequest,
+    ) -> BoxFuture<'_, Result<BatchEmbeddingResponse, EmbeddingProviderError>> {
+        Box::pin(async move {
+            match self
+                .send(|| self.inner.generate_embeddings(request.clone()))
+                .await
+            {
+                // Limits configured above what the server accepts still get
+                // through, one halving at a time; a single input too large
+                // to embed, and any other rejection, keeps its error
+                Err(EmbeddingProviderError::BatchTooLarge(_)) if request.texts.len() > 1 => {
+                    let mut left = request;
+                    let right = BatchEmbeddingRequest {
+                        texts: left.texts.split_off(left.texts.len() / 2),
+                        ..left.clone()
+                    };
+                    let (left, right) =
+                        futures::try_join!(self.embed_batch(left), self.embed_batch(right))?;
+                    Ok(merge(left, right))
+                }
+                result => result,
+            }
+        })
+    }
+}
+
+fn merge(
+    mut first: BatchEmbeddingResponse,
+    second: BatchEmbeddingResponse,
+) -> BatchEmbeddingResponse {
+    first.embeddings.extend(second.embeddings);
+    first.total_tokens = first
+        .total_tokens
+        .zip(second.total_tokens)
+        .map(|(first, second)| first + second);
+    first
+}
+
+#[async_trait]
+impl EmbeddingProvider for BatchingEmbeddingProvider {
+    async fn generate_embedding(
+        &self,
+        request: EmbeddingRequest,
+    ) -> Result<EmbeddingResponse, EmbeddingProviderError> {
+        self.send(|| self.inner.generate_embedding(request.clone()))
+            .await
+    }
+
+    async fn generate_embeddings(
+        &self,
+        request: BatchEmbeddingRequest,
+    ) -> Result<BatchEmbeddingResponse, EmbeddingProviderError> {
+        if request.texts.is_empty() {
+            return self.inner.generate_embeddings(request).await;
+        }
+
+        let BatchEmbeddingRequest {
+            texts,
+            purpose,
+            model_name,
+            model_version,
+        } = request;
+        let batches = self.pack(texts).into_iter().map(|texts| {
+            self.embed_batch(BatchEmbeddingRequest {
+                texts,
+                purpose,
+                model_name: model_name.clone(),
+                model_version: model_version.clone(),
+            })
+        });
+
+        let responses = try_join_all(batches).await?;
+        Ok(responses
+            .into_iter()
+            .reduce(merge)
+            .expect("at least one batch is packed from non-empty input"))
+    }
+
+    async fn health_check(&self) -> Result<bool, EmbeddingProviderError> {
+        self.inner.health_check().await
+    }
+
+    fn model_info(&self) -> (String, Option<String>) {
+        self.inner.model_info()
+    }
+
+    fn max_input_length(&self) -> usize {
+        self.inner.max_input_length()
+    }
+
+    fn embedding_dimension(&self) -> usize {
+        self.inner.embedding_dimension()
+    }
+
+    fn prompts(&self) -> EmbeddingPrompts {
+        self.inner.prompts()
+    }
+
+    fn endpoints(&self) -> Vec<EndpointStatus> {
+        self.inner.endpoints()
+    }
+
+    fn unavailable_for(&self) -> Option<Duration> {
+        self.inner.unavailable_for()
+    }
+
+    fn derived_from(&self) -> Option<String> {
+        self.inner.derived_from()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::EmbeddingPurpose;
+    use crate::infrastructure::external_services::tokenization::WhitespaceTokenizer;
+    use pgvector::Vector;
+    use std::sync::Mutex;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Embeds each text as its word count, rejecting batches over
+    /// `accepted_batch_size` and rate limiting the first request
+    struct RecordingProvider {
+        accepted_batch_size: usize,
+        rate_limit_first: bool,
+        calls: AtomicUsize,
+        in_flight: AtomicUsize,
+        max_in_flight: AtomicUsize,
+        batch_sizes: Mutex<Vec<usize>>,
+    }
+
+    impl RecordingProvider {
+        fn new(accepted_batch_size: usize) -> Self {
+            Self {
+                accepted_batch_size,
+                rate_limit_first: false,
+                calls: AtomicUsize::new(0),
+                in_flight: AtomicUsize::new(0),
+                max_in_flight: AtomicUsize::new(0),
+                batch_sizes: Mutex::new(Vec::new()),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl EmbeddingProvider for RecordingProvider {
+        async fn generate_embedding(
+            &self,
+            _request: EmbeddingRequest,
+        ) -> Result<EmbeddingResponse, EmbeddingProviderError> {
+            unimplemented!()
+        }
+
+        async fn generate_embeddings(
+            &self,
+            request: BatchEmbeddingRequest,
+        ) -> Result<BatchEmbeddingResponse, EmbeddingProviderError> {
+            if self.calls.fetch_add(1, Ordering::SeqCst) == 0 && self.rate_limit_first {
+                return Err(EmbeddingProviderError::RateLimitExceeded(Some(
+                    Duration::from_millis(10),
+                )));
+            }
+            if request.texts.len() > self.accepted_batch_size {
+                return Err(EmbeddingProviderError::BatchTooLarge(
+                    "batch too large".to_string(),
+                ));
+            }
+            if request.texts.iter().any(|text| text.is_empty()) {
+                return Err(EmbeddingProviderError::InvalidInput(
+                    "empty input".to_string(),
+                ));
+            }
+
+            let in_flight = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            self.max_in_flight.fetch_max(in_flight, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(5)).await;
+            self.in_flight.fetch_sub(1, Ordering::SeqCst);
+            self.batch_sizes.lock().unwrap().push(request.texts.len());
+
+            Ok(BatchEmbeddingResponse {
+                embeddings: request
+                    .texts
+                    .iter()
+                    .map(|text| Vector::from(vec![text.split_whitespace().count() as f32]))
+                    .collect(),
+                model_name: "recording".to_string(),
+                model_version: None,
+                total_tokens: Some(request.texts.len() as i32),
+            })
+        }
+
+        async fn health_check(&self) -> Result<bool, EmbeddingProviderError> {
+            Ok(true)
+        }
+
+        fn model_info(&self) -> (String, Option<String>) {
+            ("recording".to_string(), None)
+        }
+
+        fn max_input_length(&self) -> usize {
+            512
+        }
+
+        fn embedding_dimension(&self) -> usize {
+            1
+        }
+    }
+
+    fn batcher(
+        inner: Arc<RecordingProvider>,
+        config: EmbeddingBatchConfig,
+    ) -> BatchingEmbeddingProvider {
+        BatchingEmbeddingProvider::new(inner, Arc::new(WhitespaceTokenizer::new()), config)
+    }
+
+    fn request(texts: &[&str]) -> BatchEmbeddingRequest {
+        BatchEmbeddingRequest {
+            texts: texts.iter().map(|text| text.to_string()).collect(),
+            purpose: EmbeddingPurpose::Document,
+            model_name: None,
+            model_version: None,
+        }
+    }
+
+    #[test]
+    fn test_pack_by_tokens_and_size() {
+        let provider = batcher(
+            Arc::new(RecordingProvider::new(usize::MAX)),
+            EmbeddingBatchConfig {
+                max_batch_tokens: 4,
+                max_batch_size: 2,
+                ..Default::default()
+            },
+        );
+
+        let batches = provider.pack(
+            ["a b", "c d", "e", "f", "g", "one two three four five", "h"]
+                .iter()
+                .map(|text| text.to_string())
+                .collect(),
+        );
+        let sizes: Vec<usize> = batches.iter().map(Vec::len).collect();
+
+        assert_eq!(sizes, vec![2, 2, 1, 1, 1]);
+        assert_eq!(batches[3], vec!["one two three four five".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_batches_keep_order_and_limit() {
+        let inner = Arc::new(RecordingProvider::new(usize::MAX));
+        let provider = batcher(
+            inner.clone(),
+            EmbeddingBatchConfig {
+                max_batch_size: 1,
+                max_concurrency: 2,
+                ..Default::default()
+            },
+        );
+
+        let response = provider
+            .generate_embeddings(request(&["a", "a b", "a b c", "a b c d", "a b c d e"]))
+            .await
+            .unwrap();
+
+        let dimensions: Vec<f32> = response
+            .embeddings
+            .iter()
+            .map(|v| v.as_slice()[0])
+            .collect();
+        assert_eq!(dimensions, vec![1.0, 2.0, 3.0, 4.0, 5.0]);
+        assert_eq!(response.total_tokens, Some(5));
+        assert_eq!(inner.max_in_flight.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_rejected_batch_is_split() {
+        let inner = Arc::new(RecordingProvider::new(2));
+        let provider = batcher(inner.clone(), EmbeddingBatchConfig::default());
+
+        let response = provider
+            .generate_embeddings(request(&["a", "a b", "a b c", "a b c d", "a b c d e"]))
+            .await
+            .unwrap();
+
+        assert_eq!(response.embeddings.len(), 5);
+        assert_eq!(response.embeddings[4].as_slice(), &[5.0]);
+        let mut sizes = inner.batch_sizes.lock().unwrap().clone();
+        sizes.sort();
+        assert_eq!(sizes, vec![1, 2, 2]);
+    }
+
+    #[tokio::test]
+    async fn test_other_rejections_are_not_split() {
+        let inner = Arc::new(RecordingProvider::new(usize::MAX));
+        let provider = batcher(inner.clone(), EmbeddingBatchConfig::default());
+
+        let result = provider
+            .generate_embeddings(request(&["a", "", "a b"]))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(EmbeddingProviderError::InvalidInput(_))
+        ));
+        assert_eq!(inner.calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_is_retried() {
+        let inner = Arc::new(RecordingProvider {
+            rate_limit_first: true,
+            ..RecordingProvider::new(usize::MAX)
+        });
+        let provider = batcher(inner.clone(), EmbeddingBatchConfig::default());
+
+        let response = provider.generate_embeddings(request(&["a"])).await.unwrap();
+
+        assert_eq!(response.embeddings.len(), 1);
+        assert_eq!(inner.calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_retry_after() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(retry_after(&headers), None);
+
+        headers.insert(RETRY_AFTER, "3".parse().unwrap());
+        assert_eq!(retry_after(&headers), Some(Duration::from_secs(3)));
+
+        headers.insert(
+            RETRY_AFTER,
+            "Wed, 21 Oct 2015 07:28:00 GMT".parse().unwrap(),
+        );
+        assert_eq!(retry_after(&headers), Some(Duration::ZERO));
+
+        let later =
+            (Utc::now() + chrono::Duration::seconds(60)).format("%a, %d %b %Y %H:%M:%S GMT");
+        headers.insert(RETRY_AFTER, later.to_string().parse().unwrap());
+        let wait = retry_after(&headers).unwrap();
+        assert!(wait > Duration::from_secs(50) && wait <= Duration::from_secs(60));
+
+        headers.insert(RETRY_AFTER, "soon".parse().unwrap());
+        assert_eq!(retry_after(&headers), None);
+    }
+}
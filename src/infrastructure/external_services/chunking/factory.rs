@@ -0,0 +1,397 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+
+use crate::application::ports::chunking_strategy::{ChunkingError, TextChunk};
+use crate::application::ports::{
+    ChunkingStrategy, ChunkingStrategyFactory, EmbeddingProvider, Tokenizer,
+};
+use crate::domain::value_objects::{
+    ChunkSizeUnit, ChunkingConfig, EmbeddingPrompts, EmbeddingPurpose,
+};
+use crate::infrastructure::external_services::chunking::{
+    MarkdownChunker, RecursiveCharacterChunker, SemanticChunker, SentenceChunker,
+    TokenWindowChunker, measured_chunk,
+};
+use crate::infrastructure::external_services::tokenization::{
+    CharacterTokenizer, WhitespaceTokenizer,
+};
+
+/// Tokens set aside for the `title > section_path` header a chunk is
+/// embedded behind; headers longer than this are refused at validation
+const CONTEXTUAL_HEADER_TOKENS: usize = 64;
+
+/// Builds chunking strategies from per-request configs, sized against the
+/// embedding model's tokenizer unless the config asks for words or characters
+pub struct ConfiguredChunkingFactory {
+    default_strategy: &'static str,
+    tokenizer: Arc<dyn Tokenizer>,
+    max_tokens: usize,
+    instruction_tokens: usize,
+    embedding_provider: Arc<dyn EmbeddingProvider>,
+}
+
+impl ConfiguredChunkingFactory {
+    /// `default_strategy` is a config name such as the `CHUNKING_STRATEGY`
+    /// environment variable
+    pub fn new(
+        default_strategy: &str,
+        tokenizer: Arc<dyn Tokenizer>,
+        max_tokens: usize,
+        embedding_provider: Arc<dyn EmbeddingProvider>,
+    ) -> Result<Self, String> {
+        let default_strategy = canonical_strategy(default_strategy).ok_or_else(|| {
+            format!(
+                "Unknown chunking strategy '{}'; expected one of {}",
+                default_strategy,
+                ChunkingConfig::STRATEGIES.join(", ")
+            )
+        })?;
+
+        Ok(Self {
+            default_strategy,
+            tokenizer,
+            max_tokens: max_tokens.max(1),
+            instruction_tokens: 0,
+            embedding_provider,
+        })
+    }
+
+    /// Document instructions are rendered around every chunk, so the longest
+    /// one comes out of the chunk budget
+    pub fn with_instruction_prompts(
+        mut self,
+        prompts: impl IntoIterator<Item = EmbeddingPrompts>,
+    ) -> Self {
+        self.instruction_tokens = prompts
+            .into_iter()
+            .map(|prompts| {
+                self.tokenizer
+                    .count_tokens(&prompts.render(EmbeddingPurpose::Document, ""))
+                    .saturating_sub(self.tokenizer.special_token_overhead())
+            })
+            .max()
+            .unwrap_or_default();
+        self
+    }
+
+    /// Model tokens left for the chunk text once the instruction and, if
+    /// enabled, the contextual header are added
+    fn chunk_budget(&self, contextual_headers: bool) -> usize {
+        let header_tokens = if contextual_headers {
+            CONTEXTUAL_HEADER_TOKENS
+        } else {
+            0
+        };
+        self.max_tokens
+            .saturating_sub(self.instruction_tokens + header_tokens)
+            .max(1)
+    }
+
+    fn model_measured(
+        &self,
+        strategy: Arc<dyn ChunkingStrategy>,
+        unit: ChunkSizeUnit,
+    ) -> Arc<dyn ChunkingStrategy> {
+        match unit {
+            ChunkSizeUnit::Tokens => strategy,
+            _ => Arc::new(ModelMeasured {
+                inner: strategy,
+                tokenizer: self.tokenizer.clone(),
+            }),
+        }
+    }
+
+    fn unit_tokenizer(&self, unit: ChunkSizeUnit) -> Arc<dyn Tokenizer> {
+        match unit {
+            ChunkSizeUnit::Tokens => self.tokenizer.clone(),
+            ChunkSizeUnit::Words => Arc::new(WhitespaceTokenizer::new()),
+            ChunkSizeUnit::Characters => Arc::new(CharacterTokenizer::new()),
+        }
+    }
+}
+
+impl ChunkingStrategyFactory for ConfiguredChunkingFactory {
+    fn default_strategy(&self) -> &str {
+        self.default_strategy
+    }
+
+    fn resolve(&self, config: &ChunkingConfig) -> Result<ChunkingConfig, ChunkingError> {
+        let strategy = match config.strategy.as_deref() {
+            Some(name) => canonical_strategy(name).ok_or_else(|| {
+                ChunkingError::InvalidConfig(format!("Unknown chunking strategy '{}'", name))
+            })?,
+            None => self.default_strategy,
+        };
+
+        let budget = self.chunk_budget(config.contextual_headers);
+        let chunk_size = match (config.chunk_size, config.unit) {
+            (Some(size), _) => size,
+            (None, ChunkSizeUnit::Tokens) if strategy == "sentence" => budget.min(300),
+            (None, ChunkSizeUnit::Tokens) => budget,
+            (None, unit) => {
+                return Err(ChunkingError::InvalidConfig(format!(
+                    "chunk_size is required when the unit is {}",
+                    unit.as_str()
+                )));
+            }
+        };
+        if config.unit == ChunkSizeUnit::Tokens && chunk_size > budget {
+            return Err(ChunkingError::InvalidConfig(format!(
+                "chunk_size {} exceeds the {} tokens of the embedding model limit of {} left \
+                 after instructions and contextual headers",
+                chunk_size, budget, self.max_tokens
+            )));
+        }
+
+        // Only window-based strategies carry an overlap between chunks
+        let chunk_overlap = match strategy {
+            "token_window" | "markdown" => Some(config.chunk_overlap.unwrap_or(chunk_size / 6)),
+            _ => None,
+        };
+
+        let resolved = ChunkingConfig {
+            strategy: Some(strategy.to_string()),
+            chunk_size: Some(chunk_size),
+            chunk_overlap,
+            unit: config.unit,
+            parent_chunk_size: config.parent_chunk_size,
+            contextual_headers: config.contextual_headers,
+        };
+        resolved.validate().map_err(ChunkingError::InvalidConfig)?;
+
+        Ok(resolved)
+    }
+
+    fn create(&self, config: &ChunkingConfig) -> Result<Arc<dyn ChunkingStrategy>, ChunkingError> {
+        let config = self.resolve(config)?;
+        let tokenizer = self.unit_tokenizer(config.unit);
+        let size = config.chunk_size.unwrap_or(self.max_tokens);
+        let overlap = config.chunk_overlap.unwrap_or_default();
+
+        let strategy: Arc<dyn ChunkingStrategy> = match config.strategy.as_deref() {
+            Some("recursive") => {
+                let chunker = RecursiveCharacterChunker::new(tokenizer).with_max_tokens(size);
+                match config.unit {
+                    ChunkSizeUnit::Characters => Arc::new(chunker.with_max_chunk_chars(size)),
+                    _ => Arc::new(chunker),
+                }
+            }
+            Some("sentence") => Arc::new(SentenceChunker::new(tokenizer).with_max_tokens(size)),
+            Some("markdown") => Arc::new(
+                MarkdownChunker::new(tokenizer)
+                    .with_max_tokens(size)
+                    .with_overlap_tokens(overlap),
+            ),
+            Some("semantic") => Arc::new(
+                SemanticChunker::new(self.embedding_provider.clone(), tokenizer)
+                    .with_max_tokens(size),
+            ),
+            _ => Arc::new(
+                TokenWindowChunker::new(tokenizer)
+                    .with_max_tokens(size)
+                    .with_chunk_overlap(overlap),
+            ),
+        };
+
+        Ok(self.model_measured(strategy, config.unit))
+    }
+
+    fn create_parent(
+        &self,
+        config: &ChunkingConfig,
+    ) -> Result<Option<Arc<dyn ChunkingStrategy>>, ChunkingError> {
+        let config = self.resolve(config)?;
+        let Some(size) = config.parent_chunk_size else {
+            return Ok(None);
+        };
+        let tokenizer = self.unit_tokenizer(config.unit);
+
+        // Parents are never embedded, so they may exceed the model limit
+        let strategy: Arc<dyn ChunkingStrategy> = match config.strategy.as_deref() {
+            Some("markdown") => Arc::new(
+                MarkdownChunker::new(tokenizer)
+                    .with_max_tokens(size)
+                    .with_overlap_tokens(0),
+            ),
+            _ => Arc::new(
+                TokenWindowChunker::new(tokenizer)
+                    .with_max_tokens(size)
+                    .with_chunk_overlap(0),
+            ),
+        };
+
+        Ok(Some(self.model_measured(strategy, config.unit)))
+    }
+
+    fn count_tokens(&self, text: &str) -> usize {
+        self.tokenizer.count_tokens(text)
+    }
+}
+
+/// Re-counts chunks sized in words or characters with the model tokenizer,
+/// so oversized chunks are still caught before embedding
+struct ModelMeasured {
+    inner: Arc<dyn ChunkingStrategy>,
+    tokenizer: Arc<dyn Tokenizer>,
+}
+
+#[async_trait]
+impl ChunkingStrategy for ModelMeasured {
+    async fn chunk(&self, text: &str) -> Result<Vec<TextChunk>, ChunkingError> {
+        Ok(self
+            .inner
+            .chunk(text)
+            .await?
+            .into_iter()
+            .map(|chunk| {
+                measured_chunk(self.tokenizer.as_ref(), chunk.text)
+                    .with_section_path(chunk.section_path)
+            })
+            .collect())
+    }
+}
+
+fn canonical_strategy(name: &str) -> Option<&'static str> {
+    match name.trim().to_lowercase().as_str() {
+        "word_window" | "word" => Some("token_window"),
+        other => ChunkingConfig::STRATEGIES
+            .iter()
+            .find(|strategy| **strategy == other)
+            .copied(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::external_services::inference_client::{
+        InferenceClient, InferenceEmbeddingProvider, ModelInfo,
+    };
+
+    fn factory(max_tokens: usize) -> ConfiguredChunkingFactory {
+        ConfiguredChunkingFactory::new(
+            "word_window",
+            Arc::new(WhitespaceTokenizer::new()),
+            max_tokens,
+            Arc::new(InferenceEmbeddingProvider::new(
+                InferenceClient::from_env().unwrap(),
+                ModelInfo {
+                    model_id: "test-model".to_string(),
+                    model_sha: None,
+                    max_input_length: max_tokens,
+                    embedding_dimension: 8,
+                },
+            )),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_resolve_fills_defaults() {
+        let factory = factory(120);
+
+        let resolved = factory.resolve(&ChunkingConfig::default()).unwrap();
+
+        assert_eq!(resolved.strategy.as_deref(), Some("token_window"));
+        assert_eq!(resolved.chunk_size, Some(120));
+        assert_eq!(resolved.chunk_overlap, Some(20));
+        assert_eq!(factory.resolve(&resolved).unwrap(), resolved);
+    }
+
+    #[test]
+    fn test_resolve_rejects_sizes_over_model_limit() {
+        let factory = factory(120);
+        let config = ChunkingConfig {
+            chunk_size: Some(500),
+            ..Default::default()
+        };
+
+        assert!(factory.resolve(&config).is_err());
+        assert!(
+            factory
+                .resolve(&ChunkingConfig {
+                    unit: ChunkSizeUnit::Characters,
+                    ..config
+                })
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_headers_and_instructions_come_out_of_the_budget() {
+        let prompts: EmbeddingPrompts =
+            serde_json::from_str(r#"{"document_template": "search document: "}"#).unwrap();
+        let factory = factory(120).with_instruction_prompts([EmbeddingPrompts::default(), prompts]);
+        let config = ChunkingConfig {
+            contextual_headers: true,
+            ..Default::default()
+        };
+
+        let resolved = factory.resolve(&config).unwrap();
+
+        assert_eq!(
+            resolved.chunk_size,
+            Some(120 - 2 - CONTEXTUAL_HEADER_TOKENS)
+        );
+        assert!(
+            factory
+                .resolve(&ChunkingConfig {
+                    chunk_size: Some(120),
+                    ..config
+                })
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_character_sized_chunks_report_model_tokens() {
+        let factory = factory(120);
+        let strategy = factory
+            .create(&ChunkingConfig {
+                chunk_size: Some(20),
+                chunk_overlap: Some(0),
+                unit: ChunkSizeUnit::Characters,
+                ..Default::default()
+            })
+            .unwrap();
+
+        let chunks = strategy
+            .chunk("one two three four five six seven eight nine ten")
+            .await
+            .unwrap();
+
+        assert!(chunks.len() > 1);
+        assert!(chunks.iter().all(|c| c.text.chars().count() <= 20));
+        assert_eq!(
+            chunks[0].token_count as usize,
+            chunks[0].text.split_whitespace().count()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_parent_windows_exceed_model_limit() {
+        let factory = factory(4);
+        let config = ChunkingConfig {
+            chunk_size: Some(2),
+            parent_chunk_size: Some(6),
+            ..Default::default()
+        };
+
+        let parents = factory
+            .create_parent(&config)
+            .unwrap()
+            .unwrap()
+            .chunk("one two three four five six seven eight")
+            .await
+            .unwrap();
+
+        assert_eq!(parents.len(), 2);
+        assert_eq!(parents[0].token_count, 6);
+        assert!(
+            factory
+                .create_parent(&ChunkingConfig::default())
+                .unwrap()
+                .is_none()
+        );
+    }
+}
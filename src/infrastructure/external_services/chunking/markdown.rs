@@ -0,0 +1,186 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+
+use crate::application::ports::Tokenizer;
+use crate::application::ports::chunking_strategy::{ChunkingError, ChunkingStrategy, TextChunk};
+use crate::infrastructure::external_services::chunking::{measured_chunk, token_windows};
+
+/// One chunk per Markdown section, labelled with its heading trail. Sections
+/// longer than `max_tokens` are split into overlapping token windows.
+#[derive(Clone)]
+pub struct MarkdownChunker {
+    tokenizer: Arc<dyn Tokenizer>,
+    max_tokens: usize,
+    overlap_tokens: usize,
+}
+
+struct Section {
+    path: Option<String>,
+    lines: Vec<String>,
+}
+
+impl MarkdownChunker {
+    pub fn new(tokenizer: Arc<dyn Tokenizer>) -> Self {
+        Self {
+            tokenizer,
+            max_tokens: 512,
+            overlap_tokens: 85,
+        }
+    }
+
+    pub fn with_max_tokens(mut self, max_tokens: usize) -> Self {
+        self.max_tokens = max_tokens.max(1);
+        self
+    }
+
+    pub fn with_overlap_tokens(mut self, overlap_tokens: usize) -> Self {
+        self.overlap_tokens = overlap_tokens;
+        self
+    }
+
+    fn sections(&self, text: &str) -> Vec<Section> {
+        let mut sections = Vec::new();
+        let mut headings: Vec<(usize, String)> = Vec::new();
+        let mut current = Section {
+            path: None,
+            lines: Vec::new(),
+        };
+        let mut in_code_block = false;
+
+        for line in text.lines() {
+            let trimmed = line.trim_start();
+            if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+                in_code_block = !in_code_block;
+            }
+
+            let heading = if in_code_block {
+                None
+            } else {
+                parse_heading(trimmed)
+            };
+
+            match heading {
+                Some((level, title)) => {
+                    sections.push(std::mem::replace(
+                        &mut current,
+                        Section {
+                            path: None,
+                            lines: Vec::new(),
+                        },
+                    ));
+
+                    headings.retain(|(l, _)| *l < level);
+                    headings.push((level, title));
+                    current.path = Some(
+                        headings
+                            .iter()
+                            .map(|(_, t)| t.as_str())
+                            .collect::<Vec<_>>()
+                            .join(" > "),
+                    );
+                    current.lines.push(line.to_string());
+                }
+                None => current.lines.push(line.to_string()),
+            }
+        }
+        sections.push(current);
+
+        sections
+    }
+}
+
+#[async_trait]
+impl ChunkingStrategy for MarkdownChunker {
+    async fn chunk(&self, text: &str) -> Result<Vec<TextChunk>, ChunkingError> {
+        let mut chunks = Vec::new();
+
+        for section in self.sections(text) {
+            let body = section.lines.join("\n");
+            let body = body.trim();
+            if body.len() < 10 {
+                continue; // Skip empty sections and bare headings
+            }
+
+            let tokenizer = self.tokenizer.as_ref();
+            if tokenizer.count_tokens(body) <= self.max_tokens {
+                chunks.push(
+                    measured_chunk(tokenizer, body.to_string()).with_section_path(section.path),
+                );
+            } else {
+                chunks.extend(
+                    token_windows(body, tokenizer, self.max_tokens, self.overlap_tokens)
+                        .into_iter()
+                        .map(|window| {
+                            measured_chunk(tokenizer, window)
+                                .with_section_path(section.path.clone())
+                        }),
+                );
+            }
+        }
+
+        Ok(chunks)
+    }
+}
+
+/// ATX headings only: `#` to `######` followed by a space
+fn parse_heading(line: &str) -> Option<(usize, String)> {
+    let level = line.chars().take_while(|c| *c == '#').count();
+    if level == 0 || level > 6 {
+        return None;
+    }
+
+    let rest = &line[level..];
+    if !rest.starts_with(' ') {
+        return None;
+    }
+
+    let title = rest.trim().trim_end_matches('#').trim();
+    (!title.is_empty()).then(|| (level, title.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::external_services::tokenization::WhitespaceTokenizer;
+
+    #[tokio::test]
+    async fn test_sections_carry_heading_path() {
+        let text = "# Guide\nIntro text for the guide.\n\n## Install\nRun the installer now.\n\n```sh\n# not a heading\n```\n\n## Usage\nCall the binary here.";
+
+        let chunks = MarkdownChunker::new(Arc::new(WhitespaceTokenizer::new()))
+            .chunk(text)
+            .await
+            .unwrap();
+
+        assert_eq!(chunks.len(), 3);
+        assert_eq!(chunks[0].section_path.as_deref(), Some("Guide"));
+        assert_eq!(chunks[1].section_path.as_deref(), Some("Guide > Install"));
+        assert!(chunks[1].text.contains("# not a heading"));
+        assert_eq!(chunks[2].section_path.as_deref(), Some("Guide > Usage"));
+        assert!(chunks[2].text.starts_with("## Usage"));
+    }
+
+    #[tokio::test]
+    async fn test_long_section_is_windowed() {
+        let body = (0..30)
+            .map(|i| format!("w{}", i))
+            .collect::<Vec<_>>()
+            .join(" ");
+        let text = format!("# Long\n{}", body);
+
+        let chunks = MarkdownChunker::new(Arc::new(WhitespaceTokenizer::new()))
+            .with_max_tokens(12)
+            .with_overlap_tokens(2)
+            .chunk(&text)
+            .await
+            .unwrap();
+
+        assert!(chunks.len() > 1);
+        assert!(chunks.iter().all(|c| c.token_count <= 12));
+        assert!(
+            chunks
+                .iter()
+                .all(|c| c.section_path.as_deref() == Some("Long"))
+        );
+    }
+}
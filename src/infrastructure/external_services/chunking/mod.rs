@@ -0,0 +1,24 @@
+pub mod factory;
+pub mod markdown;
+pub mod recursive;
+pub mod semantic;
+pub mod sentence;
+pub mod token_window;
+
+pub use factory::ConfiguredChunkingFactory;
+pub use markdown::MarkdownChunker;
+pub use recursive::RecursiveCharacterChunker;
+pub use semantic::SemanticChunker;
+pub use sentence::SentenceChunker;
+pub use token_window::TokenWindowChunker;
+
+pub(crate) use token_window::token_windows;
+
+use crate::application::ports::Tokenizer;
+use crate::application::ports::chunking_strategy::TextChunk;
+
+/// Wraps a piece of text with its token count as the model will see it
+pub(crate) fn measured_chunk(tokenizer: &dyn Tokenizer, text: String) -> TextChunk {
+    let token_count = tokenizer.count_tokens(&text).min(i32::MAX as usize) as i32;
+    TextChunk::new(text, token_count)
+}
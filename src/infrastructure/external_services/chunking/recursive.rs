@@ -0,0 +1,97 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+
+use crate::application::ports::Tokenizer;
+use crate::application::ports::chunking_strategy::{ChunkingError, ChunkingStrategy, TextChunk};
+use crate::infrastructure::external_services::chunking::{measured_chunk, token_windows};
+use crate::infrastructure::external_services::semantic_chunking::{
+    RTSplitter, RecursiveTextSplitter,
+};
+
+/// Splits on paragraphs, then lines, then words, then characters until every
+/// piece fits in `max_chunk_chars`; pieces still over `max_tokens` are
+/// split into token windows
+#[derive(Clone)]
+pub struct RecursiveCharacterChunker {
+    splitter: RTSplitter,
+    tokenizer: Arc<dyn Tokenizer>,
+    max_chunk_chars: usize,
+    max_tokens: usize,
+}
+
+impl RecursiveCharacterChunker {
+    pub fn new(tokenizer: Arc<dyn Tokenizer>) -> Self {
+        Self {
+            splitter: RTSplitter::default(),
+            tokenizer,
+            max_chunk_chars: 2000,
+            max_tokens: 512,
+        }
+    }
+
+    pub fn with_max_chunk_chars(mut self, max_chunk_chars: usize) -> Self {
+        self.max_chunk_chars = max_chunk_chars.max(1);
+        self
+    }
+
+    pub fn with_max_tokens(mut self, max_tokens: usize) -> Self {
+        self.max_tokens = max_tokens.max(1);
+        self
+    }
+}
+
+#[async_trait]
+impl ChunkingStrategy for RecursiveCharacterChunker {
+    async fn chunk(&self, text: &str) -> Result<Vec<TextChunk>, ChunkingError> {
+        if text.trim().is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let tokenizer = self.tokenizer.as_ref();
+        Ok(self
+            .splitter
+            .split_text(text, self.max_chunk_chars)
+            .into_iter()
+            .flat_map(|piece| {
+                if tokenizer.count_tokens(&piece) > self.max_tokens {
+                    token_windows(&piece, tokenizer, self.max_tokens, 0)
+                } else {
+                    vec![piece]
+                }
+            })
+            .filter(|piece| piece.trim().len() >= 10) // Skip very small chunks
+            .map(|piece| measured_chunk(tokenizer, piece))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::external_services::tokenization::WhitespaceTokenizer;
+
+    #[tokio::test]
+    async fn test_splits_on_paragraphs() {
+        let chunker = RecursiveCharacterChunker::new(Arc::new(WhitespaceTokenizer::new()))
+            .with_max_chunk_chars(40);
+        let text = "First paragraph is here.\n\nSecond paragraph is here.\n\nok";
+
+        let chunks = chunker.chunk(text).await.unwrap();
+
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks[0].text, "First paragraph is here.");
+        assert!(chunks.iter().all(|c| c.text.len() <= 40));
+    }
+
+    #[tokio::test]
+    async fn test_respects_token_limit() {
+        let chunker =
+            RecursiveCharacterChunker::new(Arc::new(WhitespaceTokenizer::new())).with_max_tokens(4);
+        let text = "one two three four five six seven eight ninety twenty";
+
+        let chunks = chunker.chunk(text).await.unwrap();
+
+        assert_eq!(chunks.len(), 3);
+        assert!(chunks.iter().all(|c| c.token_count <= 4));
+    }
+}
@@ -0,0 +1,314 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+
+use crate::application::ports::chunking_strategy::{ChunkingError, ChunkingStrategy, TextChunk};
+use crate::application::ports::embedding_provider::BatchEmbeddingRequest;
+use crate::application::ports::{EmbeddingProvider, Tokenizer};
+use crate::domain::value_objects::EmbeddingPurpose;
+use crate::infrastructure::external_services::chunking::sentence::split_sentences;
+use crate::infrastructure::external_services::chunking::{measured_chunk, token_windows};
+
+/// Places chunk boundaries where the meaning shifts: each sentence is
+/// embedded together with its neighbours, and a boundary goes wherever the
+/// cosine distance between adjacent windows is above the 95th percentile of
+/// all distances in the document. `min_tokens` and `max_tokens` keep chunks
+/// from becoming fragments or outgrowing the model.
+pub struct SemanticChunker {
+    embedding_provider: Arc<dyn EmbeddingProvider>,
+    tokenizer: Arc<dyn Tokenizer>,
+    max_tokens: usize,
+    min_tokens: usize,
+    breakpoint_percentile: f32,
+    buffer_size: usize,
+    batch_size: usize,
+}
+
+impl SemanticChunker {
+    pub fn new(
+        embedding_provider: Arc<dyn EmbeddingProvider>,
+        tokenizer: Arc<dyn Tokenizer>,
+    ) -> Self {
+        Self {
+            embedding_provider,
+            tokenizer,
+            max_tokens: 512,
+            min_tokens: 64,
+            breakpoint_percentile: 95.0,
+            buffer_size: 1,
+            batch_size: 16,
+        }
+    }
+
+    pub fn with_max_tokens(mut self, max_tokens: usize) -> Self {
+        self.max_tokens = max_tokens.max(1);
+        self
+    }
+
+    #[cfg(test)]
+    pub fn with_min_tokens(mut self, min_tokens: usize) -> Self {
+        self.min_tokens = min_tokens;
+        self
+    }
+
+    /// Neighbouring sentences embedded on each side of a sentence
+    #[cfg(test)]
+    pub fn with_buffer_size(mut self, buffer_size: usize) -> Self {
+        self.buffer_size = buffer_size;
+        self
+    }
+
+    #[cfg(test)]
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+
+    async fn embed_windows(&self, windows: Vec<String>) -> Result<Vec<Vec<f32>>, ChunkingError> {
+        let (model_name, model_version) = self.embedding_provider.model_info();
+        let mut embeddings = Vec::with_capacity(windows.len());
+
+        for batch in windows.chunks(self.batch_size) {
+            let response = self
+                .embedding_provider
+                .generate_embeddings(BatchEmbeddingRequest {
+                    texts: batch.to_vec(),
+                    purpose: EmbeddingPurpose::Document,
+                    model_name: Some(model_name.clone()),
+                    model_version: model_version.clone(),
+                })
+                .await
+                .map_err(|e| ChunkingError::EmbeddingError(e.to_string()))?;
+
+            if response.embeddings.len() != batch.len() {
+                return Err(ChunkingError::EmbeddingError(format!(
+                    "expected {} embeddings, got {}",
+                    batch.len(),
+                    response.embeddings.len()
+                )));
+            }
+            embeddings.extend(response.embeddings.iter().map(|v| v.as_slice().to_vec()));
+        }
+
+        Ok(embeddings)
+    }
+}
+
+#[async_trait]
+impl ChunkingStrategy for SemanticChunker {
+    async fn chunk(&self, text: &str) -> Result<Vec<TextChunk>, ChunkingError> {
+        let tokenizer = self.tokenizer.as_ref();
+        let sentences = split_sentences(text);
+        if sentences.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let windows = sentences
+            .iter()
+            .enumerate()
+            .map(|(i, _)| {
+                let start = i.saturating_sub(self.buffer_size);
+                let end = (i + self.buffer_size + 1).min(sentences.len());
+                let window = sentences[start..end].join(" ");
+
+                // The window only steers boundaries, so trimming it to fit the model is fine
+                if tokenizer.count_tokens(&window) > self.max_tokens {
+                    token_windows(&window, tokenizer, self.max_tokens, 0)
+                        .into_iter()
+                        .next()
+                        .unwrap_or(window)
+                } else {
+                    window
+                }
+            })
+            .collect();
+
+        let embeddings = self.embed_windows(windows).await?;
+        let distances: Vec<f32> = embeddings
+            .windows(2)
+            .map(|pair| cosine_distance(&pair[0], &pair[1]))
+            .collect();
+        let threshold = percentile(&distances, self.breakpoint_percentile);
+
+        let budget = self
+            .max_tokens
+            .saturating_sub(tokenizer.special_token_overhead())
+            .max(1);
+        let mut pieces = Vec::new();
+        let mut current: Vec<&str> = Vec::new();
+        let mut current_tokens = 0;
+
+        for (i, sentence) in sentences.iter().enumerate() {
+            let tokens = tokenizer.token_spans(sentence).len();
+
+            if tokens > budget {
+                if !current.is_empty() {
+                    pieces.push(current.join(" "));
+                    current.clear();
+                    current_tokens = 0;
+                }
+                pieces.extend(token_windows(sentence, tokenizer, self.max_tokens, 0));
+                continue;
+            }
+
+            if current_tokens + tokens > budget && !current.is_empty() {
+                pieces.push(current.join(" "));
+                current.clear();
+                current_tokens = 0;
+            }
+
+            current.push(sentence);
+            current_tokens += tokens;
+
+            let breakpoint = distances.get(i).is_some_and(|d| *d > threshold);
+            if breakpoint && current_tokens >= self.min_tokens {
+                pieces.push(current.join(" "));
+                current.clear();
+                current_tokens = 0;
+            }
+        }
+
+        if !current.is_empty() {
+            pieces.push(current.join(" "));
+        }
+
+        Ok(pieces
+            .into_iter()
+            .filter(|piece| piece.trim().len() >= 10) // Skip very small chunks
+            .map(|piece| measured_chunk(tokenizer, piece))
+            .collect())
+    }
+}
+
+fn cosine_distance(a: &[f32], b: &[f32]) -> f32 {
+    let dot: f32 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+    let norm_a = a.iter().map(|x| x * x).sum::<f32>().sqrt();
+    let norm_b = b.iter().map(|x| x * x).sum::<f32>().sqrt();
+
+    if norm_a == 0.0 || norm_b == 0.0 {
+        return 0.0;
+    }
+    1.0 - dot / (norm_a * norm_b)
+}
+
+/// Linearly interpolated percentile; infinite for no values so nothing splits
+fn percentile(values: &[f32], percentile: f32) -> f32 {
+    if values.is_empty() {
+        return f32::INFINITY;
+    }
+
+    let mut sorted = values.to_vec();
+    sorted.sort_by(f32::total_cmp);
+
+    let rank = percentile / 100.0 * (sorted.len() - 1) as f32;
+    let lower = rank.floor() as usize;
+    let upper = rank.ceil() as usize;
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (rank - lower as f32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::ports::embedding_provider::{
+        BatchEmbeddingResponse, EmbeddingProviderError, EmbeddingRequest, EmbeddingResponse,
+    };
+    use crate::infrastructure::external_services::tokenization::WhitespaceTokenizer;
+    use pgvector::Vector;
+
+    /// Embeds text as (mentions of "cat", mentions of "dog")
+    struct TopicEmbeddingProvider;
+
+    fn topic_vector(text: &str) -> Vector {
+        let lower = text.to_lowercase();
+        Vector::from(vec![
+            lower.matches("cat").count() as f32,
+            lower.matches("dog").count() as f32,
+        ])
+    }
+
+    #[async_trait]
+    impl EmbeddingProvider for TopicEmbeddingProvider {
+        async fn generate_embedding(
+            &self,
+            request: EmbeddingRequest,
+        ) -> Result<EmbeddingResponse, EmbeddingProviderError> {
+            Ok(EmbeddingResponse {
+                embedding: topic_vector(&request.text),
+                model_name: "topic".to_string(),
+                model_version: None,
+                token_count: None,
+            })
+        }
+
+        async fn generate_embeddings(
+            &self,
+            request: BatchEmbeddingRequest,
+        ) -> Result<BatchEmbeddingResponse, EmbeddingProviderError> {
+            Ok(BatchEmbeddingResponse {
+                embeddings: request.texts.iter().map(|t| topic_vector(t)).collect(),
+                model_name: "topic".to_string(),
+                model_version: None,
+                total_tokens: None,
+            })
+        }
+
+        async fn health_check(&self) -> Result<bool, EmbeddingProviderError> {
+            Ok(true)
+        }
+
+        fn model_info(&self) -> (String, Option<String>) {
+            ("topic".to_string(), None)
+        }
+
+        fn max_input_length(&self) -> usize {
+            512
+        }
+
+        fn embedding_dimension(&self) -> usize {
+            2
+        }
+    }
+
+    #[tokio::test]
+    async fn test_splits_where_topic_changes() {
+        let chunker = SemanticChunker::new(
+            Arc::new(TopicEmbeddingProvider),
+            Arc::new(WhitespaceTokenizer::new()),
+        )
+        .with_buffer_size(0)
+        .with_min_tokens(1)
+        .with_batch_size(2);
+        let text = "The cat purrs loudly. A cat naps all day. My cat hunts mice. \
+                    The dog barks often. A dog fetches sticks. My dog guards the yard.";
+
+        let chunks = chunker.chunk(text).await.unwrap();
+
+        assert_eq!(chunks.len(), 2);
+        assert!(chunks[0].text.ends_with("My cat hunts mice."));
+        assert!(chunks[1].text.starts_with("The dog barks often."));
+    }
+
+    #[tokio::test]
+    async fn test_min_and_max_guards() {
+        let chunker = SemanticChunker::new(
+            Arc::new(TopicEmbeddingProvider),
+            Arc::new(WhitespaceTokenizer::new()),
+        )
+        .with_buffer_size(0)
+        .with_min_tokens(100)
+        .with_max_tokens(10);
+        let text = "The cat purrs loudly. A cat naps all day. My cat hunts mice. \
+                    The dog barks often. A dog fetches sticks. My dog guards the yard.";
+
+        let chunks = chunker.chunk(text).await.unwrap();
+
+        assert!(chunks.len() > 2);
+        assert!(chunks.iter().all(|c| c.token_count <= 10));
+    }
+
+    #[test]
+    fn test_percentile() {
+        assert!((percentile(&[0.0, 0.0, 1.0, 0.0, 0.0], 95.0) - 0.8).abs() < 1e-5);
+        assert!((percentile(&[0.2, 0.4], 50.0) - 0.3).abs() < 1e-5);
+        assert!(percentile(&[], 95.0).is_infinite());
+    }
+}
@@ -0,0 +1,163 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+
+use crate::application::ports::Tokenizer;
+use crate::application::ports::chunking_strategy::{ChunkingError, ChunkingStrategy, TextChunk};
+use crate::infrastructure::external_services::chunking::{measured_chunk, token_windows};
+
+/// Packs whole sentences into chunks of up to `max_tokens`, repeating the
+/// last `overlap_sentences` sentences at the start of the next chunk
+#[derive(Clone)]
+pub struct SentenceChunker {
+    tokenizer: Arc<dyn Tokenizer>,
+    max_tokens: usize,
+    overlap_sentences: usize,
+}
+
+impl SentenceChunker {
+    pub fn new(tokenizer: Arc<dyn Tokenizer>) -> Self {
+        Self {
+            tokenizer,
+            max_tokens: 300,
+            overlap_sentences: 1,
+        }
+    }
+
+    pub fn with_max_tokens(mut self, max_tokens: usize) -> Self {
+        self.max_tokens = max_tokens.max(1);
+        self
+    }
+
+    #[cfg(test)]
+    pub fn with_overlap_sentences(mut self, overlap_sentences: usize) -> Self {
+        self.overlap_sentences = overlap_sentences;
+        self
+    }
+}
+
+#[async_trait]
+impl ChunkingStrategy for SentenceChunker {
+    async fn chunk(&self, text: &str) -> Result<Vec<TextChunk>, ChunkingError> {
+        let tokenizer = self.tokenizer.as_ref();
+        let budget = self
+            .max_tokens
+            .saturating_sub(tokenizer.special_token_overhead())
+            .max(1);
+
+        let mut pieces = Vec::new();
+        let mut window: Vec<(&str, usize)> = Vec::new();
+        let mut window_tokens = 0;
+        // Sentences in the window that have not been emitted yet
+        let mut fresh = 0;
+
+        for sentence in split_sentences(text) {
+            let tokens = tokenizer.token_spans(sentence).len();
+
+            // A single sentence longer than a chunk falls back to token windows
+            if tokens > budget {
+                if fresh > 0 {
+                    pieces.push(join(&window));
+                }
+                pieces.extend(token_windows(sentence, tokenizer, self.max_tokens, 0));
+                window.clear();
+                window_tokens = 0;
+                fresh = 0;
+                continue;
+            }
+
+            if window_tokens + tokens > budget && fresh > 0 {
+                pieces.push(join(&window));
+                let keep = self.overlap_sentences.min(window.len());
+                window.drain(..window.len() - keep);
+                window_tokens = window.iter().map(|(_, t)| t).sum();
+                fresh = 0;
+
+                // Drop overlap that would not leave room for the new sentence
+                while window_tokens + tokens > budget && !window.is_empty() {
+                    window_tokens -= window.remove(0).1;
+                }
+            }
+
+            window.push((sentence, tokens));
+            window_tokens += tokens;
+            fresh += 1;
+        }
+
+        if fresh > 0 {
+            pieces.push(join(&window));
+        }
+
+        Ok(pieces
+            .into_iter()
+            .filter(|piece| piece.trim().len() >= 10) // Skip very small chunks
+            .map(|piece| measured_chunk(tokenizer, piece))
+            .collect())
+    }
+}
+
+fn join(window: &[(&str, usize)]) -> String {
+    window
+        .iter()
+        .map(|(sentence, _)| *sentence)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Splits after `.`, `!` or `?` followed by whitespace, and at blank lines
+pub(crate) fn split_sentences(text: &str) -> Vec<&str> {
+    let mut sentences = Vec::new();
+    let mut start = 0;
+    let mut chars = text.char_indices().peekable();
+
+    while let Some((index, c)) = chars.next() {
+        let boundary = match c {
+            '.' | '!' | '?' => chars.peek().is_some_and(|(_, next)| next.is_whitespace()),
+            '\n' => chars.peek().is_some_and(|(_, next)| *next == '\n'),
+            _ => false,
+        };
+
+        if boundary {
+            let end = index + c.len_utf8();
+            let sentence = text[start..end].trim();
+            if !sentence.is_empty() {
+                sentences.push(sentence);
+            }
+            start = end;
+        }
+    }
+
+    let rest = text[start..].trim();
+    if !rest.is_empty() {
+        sentences.push(rest);
+    }
+
+    sentences
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::external_services::tokenization::WhitespaceTokenizer;
+
+    #[tokio::test]
+    async fn test_packs_sentences_with_overlap() {
+        let chunker = SentenceChunker::new(Arc::new(WhitespaceTokenizer::new()))
+            .with_max_tokens(8)
+            .with_overlap_sentences(1);
+        let text = "The cat sat down. It was tired. Then it slept well. The end came soon.";
+
+        let chunks = chunker.chunk(text).await.unwrap();
+
+        assert_eq!(chunks[0].text, "The cat sat down. It was tired.");
+        assert_eq!(chunks[1].text, "It was tired. Then it slept well.");
+        assert_eq!(chunks[2].text, "Then it slept well. The end came soon.");
+        assert_eq!(chunks[2].token_count, 8);
+    }
+
+    #[test]
+    fn test_split_sentences() {
+        let sentences = split_sentences("Version 1.2 is out! Is it good?\n\nYes");
+
+        assert_eq!(sentences, vec!["Version 1.2 is out!", "Is it good?", "Yes"]);
+    }
+}
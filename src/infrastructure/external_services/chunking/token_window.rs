@@ -0,0 +1,115 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+
+use crate::application::ports::Tokenizer;
+use crate::application::ports::chunking_strategy::{ChunkingError, ChunkingStrategy, TextChunk};
+use crate::infrastructure::external_services::chunking::measured_chunk;
+
+/// Fixed-size windows of model tokens with a trailing overlap
+#[derive(Clone)]
+pub struct TokenWindowChunker {
+    tokenizer: Arc<dyn Tokenizer>,
+    max_tokens: usize,
+    chunk_overlap: usize,
+}
+
+impl TokenWindowChunker {
+    pub fn new(tokenizer: Arc<dyn Tokenizer>) -> Self {
+        Self {
+            tokenizer,
+            max_tokens: 512,
+            chunk_overlap: 85,
+        }
+    }
+
+    pub fn with_max_tokens(mut self, max_tokens: usize) -> Self {
+        self.max_tokens = max_tokens.max(1);
+        self
+    }
+
+    pub fn with_chunk_overlap(mut self, chunk_overlap: usize) -> Self {
+        self.chunk_overlap = chunk_overlap;
+        self
+    }
+}
+
+#[async_trait]
+impl ChunkingStrategy for TokenWindowChunker {
+    async fn chunk(&self, text: &str) -> Result<Vec<TextChunk>, ChunkingError> {
+        Ok(token_windows(
+            text,
+            self.tokenizer.as_ref(),
+            self.max_tokens,
+            self.chunk_overlap,
+        )
+        .into_iter()
+        .map(|window| measured_chunk(self.tokenizer.as_ref(), window))
+        .collect())
+    }
+}
+
+/// Splits `text` into slices of at most `max_tokens` model tokens (special
+/// tokens included). Shared by the other strategies to break up pieces that
+/// are still too long.
+pub(crate) fn token_windows(
+    text: &str,
+    tokenizer: &dyn Tokenizer,
+    max_tokens: usize,
+    overlap: usize,
+) -> Vec<String> {
+    let spans = tokenizer.token_spans(text);
+    let size = max_tokens
+        .saturating_sub(tokenizer.special_token_overhead())
+        .max(1);
+    let overlap = overlap.min(size - 1);
+
+    let mut windows = Vec::new();
+    let mut start = 0;
+
+    while start < spans.len() {
+        let mut end = (start + size).min(spans.len());
+        let mut window = &text[spans[start].0..spans[end - 1].1];
+
+        // Re-tokenizing a slice can merge differently at its edges
+        while end - start > 1 && tokenizer.count_tokens(window) > max_tokens {
+            end -= 1;
+            window = &text[spans[start].0..spans[end - 1].1];
+        }
+
+        // Skip empty or very small chunks
+        if window.trim().len() >= 10 {
+            windows.push(window.to_string());
+        }
+
+        if end >= spans.len() {
+            break;
+        }
+        start = (end - overlap.min(end - start - 1)).max(start + 1);
+    }
+
+    windows
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::external_services::tokenization::WhitespaceTokenizer;
+
+    #[tokio::test]
+    async fn test_windows_overlap() {
+        let text = (0..25)
+            .map(|i| format!("word{}", i))
+            .collect::<Vec<_>>()
+            .join(" ");
+        let chunker = TokenWindowChunker::new(Arc::new(WhitespaceTokenizer::new()))
+            .with_max_tokens(10)
+            .with_chunk_overlap(2);
+
+        let chunks = chunker.chunk(&text).await.unwrap();
+
+        assert_eq!(chunks.len(), 3);
+        assert_eq!(chunks[0].token_count, 10);
+        assert!(chunks[1].text.starts_with("word8 word9"));
+        assert!(chunks[2].text.ends_with("word24"));
+    }
+}
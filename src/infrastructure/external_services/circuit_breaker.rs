@@ -0,0 +1,201 @@
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::application::ports::embedding_provider::{CircuitState, EndpointStatus};
+
+#[derive(Debug, Clone)]
+pub struct CircuitBreakerConfig {
+    /// Consecutive retryable failures that open the circuit
+    pub failure_threshold: u32,
+    /// How long an open circuit rejects requests before a probe is allowed
+    pub open_for: Duration,
+}
+
+impl Default for CircuitBreakerConfig {
+    fn default() -> Self {
+        Self {
+            failure_threshold: 5,
+            open_for: Duration::from_secs(30),
+        }
+    }
+}
+
+/// What a caller may do with an endpoint right now
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Admission {
+    Allowed,
+    /// The circuit has been open long enough; this caller alone may check
+    /// whether the endpoint recovered
+    Probe,
+    Rejected,
+}
+
+#[derive(Debug)]
+struct BreakerState {
+    state: CircuitState,
+    consecutive_failures: u32,
+    opened_at: Option<Instant>,
+    times_opened: u64,
+    requests: u64,
+    failures: u64,
+}
+
+/// Stops traffic to an endpoint after repeated failures, then lets a single
+/// probe through once it has cooled down: success closes the circuit again,
+/// failure keeps it open for another period.
+#[derive(Debug)]
+pub struct CircuitBreaker {
+    config: CircuitBreakerConfig,
+    inner: Mutex<BreakerState>,
+}
+
+impl CircuitBreaker {
+    pub fn new(config: CircuitBreakerConfig) -> Self {
+        Self {
+            config,
+            inner: Mutex::new(BreakerState {
+                state: CircuitState::Closed,
+                consecutive_failures: 0,
+                opened_at: None,
+                times_opened: 0,
+                requests: 0,
+                failures: 0,
+            }),
+        }
+    }
+
+    pub fn admit(&self) -> Admission {
+        let mut inner = self.inner.lock().unwrap();
+        match inner.state {
+            CircuitState::Closed => {
+                inner.requests += 1;
+                Admission::Allowed
+            }
+            // A probe whose caller gave up without reporting back is
+            // replaced once another period has passed
+            CircuitState::Open | CircuitState::HalfOpen if self.remaining(&inner).is_none() => {
+                inner.state = CircuitState::HalfOpen;
+                inner.opened_at = Some(Instant::now());
+                inner.requests += 1;
+                Admission::Probe
+            }
+            // One probe at a time; everyone else waits for its verdict
+            CircuitState::Open | CircuitState::HalfOpen => Admission::Rejected,
+        }
+    }
+
+    pub fn record_success(&self) {
+        let mut inner = self.inner.lock().unwrap();
+        inner.state = CircuitState::Closed;
+        inner.consecutive_failures = 0;
+        inner.opened_at = None;
+    }
+
+    pub fn record_failure(&self) {
+        let mut inner = self.inner.lock().unwrap();
+        inner.failures += 1;
+        inner.consecutive_failures += 1;
+
+        let trips = match inner.state {
+            CircuitState::Closed => inner.consecutive_failures >= self.config.failure_threshold,
+            CircuitState::HalfOpen => true,
+            CircuitState::Open => false,
+        };
+        if trips {
+            inner.state = CircuitState::Open;
+            inner.opened_at = Some(Instant::now());
+            inner.times_opened += 1;
+        }
+    }
+
+    /// Time until an open circuit admits a probe; None when it would admit now
+    pub fn retry_in(&self) -> Option<Duration> {
+        let inner = self.inner.lock().unwrap();
+        match inner.state {
+            CircuitState::Open => self.remaining(&inner),
+            // A probe is in flight and will settle the state shortly
+            CircuitState::HalfOpen => self
+                .remaining(&inner)
+                .map(|remaining| remaining.min(Duration::from_secs(1))),
+            CircuitState::Closed => None,
+        }
+    }
+
+    pub fn status(&self, url: &str) -> EndpointStatus {
+        let inner = self.inner.lock().unwrap();
+        EndpointStatus {
+            url: url.to_string(),
+            state: inner.state,
+            consecutive_failures: inner.consecutive_failures,
+            times_opened: inner.times_opened,
+            requests: inner.requests,
+            failures: inner.failures,
+            retry_in: match inner.state {
+                CircuitState::Open => self.remaining(&inner),
+                _ => None,
+            },
+        }
+    }
+
+    fn remaining(&self, inner: &BreakerState) -> Option<Duration> {
+        let opened_at = inner.opened_at?;
+        self.config
+            .open_for
+            .checked_sub(opened_at.elapsed())
+            .filter(|remaining| !remaining.is_zero())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn breaker(open_for: Duration) -> CircuitBreaker {
+        CircuitBreaker::new(CircuitBreakerConfig {
+            failure_threshold: 2,
+            open_for,
+        })
+    }
+
+    #[test]
+    fn test_opens_after_consecutive_failures() {
+        let breaker = breaker(Duration::from_secs(60));
+
+        breaker.record_failure();
+        breaker.record_success();
+        breaker.record_failure();
+        assert_eq!(breaker.admit(), Admission::Allowed);
+
+        breaker.record_failure();
+        assert_eq!(breaker.admit(), Admission::Rejected);
+        assert!(breaker.retry_in().is_some());
+
+        let status = breaker.status("http://tei");
+        assert_eq!(status.state, CircuitState::Open);
+        assert_eq!(status.times_opened, 1);
+        assert_eq!(status.failures, 3);
+    }
+
+    #[test]
+    fn test_single_probe_after_cooldown() {
+        let open_for = Duration::from_millis(20);
+        let breaker = breaker(open_for);
+        breaker.record_failure();
+        breaker.record_failure();
+        std::thread::sleep(open_for);
+
+        assert_eq!(breaker.admit(), Admission::Probe);
+        assert_eq!(breaker.admit(), Admission::Rejected);
+
+        // A failed probe opens the circuit again for another period
+        breaker.record_failure();
+        assert_eq!(breaker.status("http://tei").times_opened, 2);
+        assert_eq!(breaker.admit(), Admission::Rejected);
+
+        std::thread::sleep(open_for);
+        assert_eq!(breaker.admit(), Admission::Probe);
+        breaker.record_success();
+        assert_eq!(breaker.admit(), Admission::Allowed);
+        assert_eq!(breaker.retry_in(), None);
+    }
+}
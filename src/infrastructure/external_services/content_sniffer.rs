@@ -0,0 +1,369 @@
+use chardetng::{EncodingDetector, Iso2022JpDetection, Utf8Detection};
+use encoding_rs::{Encoding, UTF_8};
+use std::io::{Cursor, Read, Seek};
+use std::path::Path;
+
+use crate::application::ports::content_detector::{
+    ContentDetector, DetectedContent, DetectionMethod,
+};
+
+const OCTET_STREAM: &str = "application/octet-stream";
+const DOCX: &str = "application/vnd.openxmlformats-officedocument.wordprocessingml.document";
+const XLSX: &str = "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet";
+const PPTX: &str = "application/vnd.openxmlformats-officedocument.presentationml.presentation";
+const EPUB: &str = "application/epub+zip";
+
+/// Bytes inspected when deciding whether content is text
+const SNIFF_LEN: usize = 8 * 1024;
+
+const SIGNATURES: &[(&[u8], &str)] = &[
+    (b"%PDF-", "application/pdf"),
+    (b"\x89PNG\r\n\x1a\n", "image/png"),
+    (b"\xff\xd8\xff", "image/jpeg"),
+    (b"GIF87a", "image/gif"),
+    (b"GIF89a", "image/gif"),
+    (b"\x1f\x8b", "application/gzip"),
+    (b"7z\xbc\xaf\x27\x1c", "application/x-7z-compressed"),
+    (b"Rar!\x1a\x07", "application/vnd.rar"),
+    (
+        b"\xd0\xcf\x11\xe0\xa1\xb1\x1a\xe1",
+        "application/x-ole-storage",
+    ),
+    (b"{\\rtf", "application/rtf"),
+];
+
+/// Detects content types from magic bytes, zip container entries, file
+/// extensions and, for text, the character encoding.
+pub struct ContentSniffer;
+
+impl ContentSniffer {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Only the central directory and the `mimetype` entry are read
+    fn detect_container<R: Read + Seek>(&self, reader: R) -> Option<&'static str> {
+        let mut archive = zip::ZipArchive::new(reader).ok()?;
+
+        // EPUB requires an uncompressed `mimetype` entry naming the format
+        if let Ok(mut entry) = archive.by_name("mimetype") {
+            let mut mimetype = String::new();
+            if entry.read_to_string(&mut mimetype).is_ok() && mimetype.trim() == EPUB {
+                return Some(EPUB);
+            }
+        }
+
+        let names: Vec<String> = archive
+            .file_names()
+            .filter_map(|name| name.ok().map(|n| n.into_owned()))
+            .collect();
+        let has = |name: &str| names.iter().any(|n| n == name);
+
+        if has("word/document.xml") {
+            Some(DOCX)
+        } else if has("xl/workbook.xml") {
+            Some(XLSX)
+        } else if has("ppt/presentation.xml") {
+            Some(PPTX)
+        } else if has("META-INF/container.xml") {
+            Some(EPUB)
+        } else {
+            Some("application/zip")
+        }
+    }
+}
+
+impl Default for ContentSniffer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ContentDetector for ContentSniffer {
+    fn detect(
+        &self,
+        data: &[u8],
+        file_name: Option<&str>,
+        declared_type: Option<&str>,
+    ) -> DetectedContent {
+        let declared = declared_type.map(normalize_mime);
+        let from_extension = file_name.and_then(mime_for_extension);
+
+        if let Some((_, mime_type)) = SIGNATURES.iter().find(|(magic, _)| data.starts_with(magic)) {
+            return detected(mime_type, None, DetectionMethod::Signature);
+        }
+
+        if data.starts_with(b"PK\x03\x04")
+            && let Some(mime_type) = self.detect_container(Cursor::new(data))
+        {
+            return detected(mime_type, None, DetectionMethod::Container);
+        }
+
+        let Some(encoding) = detect_encoding(data) else {
+            // Unknown binary content: fall back to what we were told
+            return match from_extension.or(declared.as_deref()) {
+                Some(mime_type) if !is_text_mime(mime_type) => {
+                    detected(mime_type, None, DetectionMethod::Extension)
+                }
+                _ => detected(OCTET_STREAM, None, DetectionMethod::Fallback),
+            };
+        };
+        let encoding = Some(encoding.name().to_string());
+
+        // Byte patterns can't tell text formats apart, so a textual hint is trusted
+        if let Some(mime_type) = declared.as_deref().filter(|m| is_text_mime(m)) {
+            return detected(mime_type, encoding, DetectionMethod::Declared);
+        }
+        if let Some(mime_type) = from_extension.filter(|m| is_text_mime(m)) {
+            return detected(mime_type, encoding, DetectionMethod::Extension);
+        }
+
+        let head = String::from_utf8_lossy(&data[..data.len().min(SNIFF_LEN)]).to_lowercase();
+        let head = head.trim_start_matches('\u{feff}').trim_start();
+        if head.starts_with("<!doctype html") || head.starts_with("<html") {
+            return detected("text/html", encoding, DetectionMethod::TextHeuristic);
+        }
+
+        detected("text/plain", encoding, DetectionMethod::TextHeuristic)
+    }
+
+    fn detect_file(
+        &self,
+        path: &Path,
+        file_name: Option<&str>,
+        declared_type: Option<&str>,
+    ) -> std::io::Result<DetectedContent> {
+        let mut file = std::fs::File::open(path)?;
+        let mut head = Vec::with_capacity(SNIFF_LEN);
+        (&mut file).take(SNIFF_LEN as u64).read_to_end(&mut head)?;
+
+        // A container is told apart by its entries, listed at the end of the file
+        if head.starts_with(b"PK\x03\x04")
+            && let Some(mime_type) = self.detect_container(&mut file)
+        {
+            return Ok(detected(mime_type, None, DetectionMethod::Container));
+        }
+
+        Ok(self.detect(&head, file_name, declared_type))
+    }
+}
+
+fn detected(mime_type: &str, encoding: Option<String>, method: DetectionMethod) -> DetectedContent {
+    DetectedContent {
+        mime_type: mime_type.to_string(),
+        encoding,
+        method,
+    }
+}
+
+/// Strips parameters such as `; charset=utf-8` and lowercases the type
+fn normalize_mime(mime_type: &str) -> String {
+    mime_type
+        .split(';')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_lowercase()
+}
+
+fn is_text_mime(mime_type: &str) -> bool {
+    mime_type.starts_with("text/")
+        || matches!(
+            mime_type,
+            "application/json" | "application/xml" | "application/xhtml+xml"
+        )
+}
+
+pub fn mime_for_extension(file_name: &str) -> Option<&'static str> {
+    let (_, extension) = file_name.rsplit_once('.')?;
+    let mime_type = match extension.to_lowercase().as_str() {
+        "pdf" => "application/pdf",
+        "html" | "htm" => "text/html",
+        "txt" | "text" | "log" => "text/plain",
+        "md" | "markdown" => "text/markdown",
+        "csv" => "text/csv",
+        "json" => "application/json",
+        "xml" => "application/xml",
+        "docx" => DOCX,
+        "xlsx" => XLSX,
+        "pptx" => PPTX,
+        "epub" => EPUB,
+        _ => return None,
+    };
+    Some(mime_type)
+}
+
+/// Returns the text encoding of `data`, or None if it looks binary
+pub fn detect_encoding(data: &[u8]) -> Option<&'static Encoding> {
+    if let Some((encoding, _)) = Encoding::for_bom(data) {
+        return Some(encoding);
+    }
+
+    let sample = &data[..data.len().min(SNIFF_LEN)];
+    if sample.contains(&0) {
+        return None;
+    }
+
+    // A multi-byte sequence may be cut at the end of the sample
+    match std::str::from_utf8(sample) {
+        Ok(_) => return Some(UTF_8),
+        Err(e) if e.error_len().is_none() => return Some(UTF_8),
+        Err(_) => {}
+    }
+
+    let control_bytes = sample
+        .iter()
+        .filter(|&&b| b < 0x20 && !matches!(b, b'\t' | b'\n' | b'\r' | 0x0c))
+        .count();
+    if control_bytes * 10 > sample.len() {
+        return None;
+    }
+
+    let mut detector = EncodingDetector::new(Iso2022JpDetection::Deny);
+    detector.feed(data, true);
+    Some(detector.guess(None, Utf8Detection::Allow))
+}
+
+/// Decodes text using the named encoding, detecting it when not given
+pub fn decode_text(data: &[u8], encoding: Option<&str>) -> String {
+    let encoding = encoding
+        .and_then(|label| Encoding::for_label(label.as_bytes()))
+        .or_else(|| detect_encoding(data))
+        .unwrap_or(UTF_8);
+
+    // decode() honours a BOM over the given encoding
+    let (text, _, _) = encoding.decode(data);
+    text.into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use encoding_rs::UTF_16LE;
+    use std::io::Write;
+
+    fn zip_with(entries: &[(&str, &[u8])]) -> Vec<u8> {
+        let mut writer = zip::ZipWriter::new(Cursor::new(Vec::new()));
+        let options = zip::write::SimpleFileOptions::default()
+            .compression_method(zip::CompressionMethod::Stored);
+        for (name, data) in entries {
+            writer.start_file(*name, options).unwrap();
+            writer.write_all(data).unwrap();
+        }
+        writer.finish().unwrap().into_inner()
+    }
+
+    #[test]
+    fn test_signature_wins_over_declared_type() {
+        let sniffer = ContentSniffer::new();
+        let detected = sniffer.detect(b"%PDF-1.7\n...", Some("notes.txt"), Some("text/plain"));
+        assert_eq!(detected.mime_type, "application/pdf");
+        assert_eq!(detected.method, DetectionMethod::Signature);
+        assert_eq!(detected.encoding, None);
+    }
+
+    #[test]
+    fn test_zip_containers() {
+        let sniffer = ContentSniffer::new();
+
+        let docx = zip_with(&[
+            ("[Content_Types].xml", b"<Types/>"),
+            ("word/document.xml", b"<w/>"),
+        ]);
+        assert_eq!(sniffer.detect(&docx, None, None).mime_type, DOCX);
+
+        let xlsx = zip_with(&[("xl/workbook.xml", b"<workbook/>")]);
+        assert_eq!(sniffer.detect(&xlsx, None, Some(DOCX)).mime_type, XLSX);
+
+        let epub = zip_with(&[
+            ("mimetype", EPUB.as_bytes()),
+            ("OEBPS/content.opf", b"<package/>"),
+        ]);
+        assert_eq!(
+            sniffer.detect(&epub, Some("book.zip"), None).mime_type,
+            EPUB
+        );
+
+        let plain = zip_with(&[("readme.txt", b"hello")]);
+        assert_eq!(
+            sniffer.detect(&plain, None, None).mime_type,
+            "application/zip"
+        );
+    }
+
+    #[test]
+    fn test_detect_file_reads_head_and_central_directory() {
+        let sniffer = ContentSniffer::new();
+        let dir = std::env::temp_dir().join(format!("polyrag-sniff-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        // The entry list sits past the sniffed head
+        let padding = vec![b'x'; 4 * SNIFF_LEN];
+        let docx = dir.join("report.bin");
+        std::fs::write(
+            &docx,
+            zip_with(&[
+                ("media/filler.txt", &padding),
+                ("word/document.xml", b"<w/>"),
+            ]),
+        )
+        .unwrap();
+        let detected = sniffer
+            .detect_file(&docx, Some("report.bin"), None)
+            .unwrap();
+        assert_eq!(detected.mime_type, DOCX);
+        assert_eq!(detected.method, DetectionMethod::Container);
+
+        let notes = dir.join("notes");
+        std::fs::write(&notes, "plain words ".repeat(SNIFF_LEN)).unwrap();
+        let detected = sniffer.detect_file(&notes, None, None).unwrap();
+        assert_eq!(detected.mime_type, "text/plain");
+        assert_eq!(detected.encoding.as_deref(), Some("UTF-8"));
+
+        assert!(
+            sniffer
+                .detect_file(&dir.join("missing"), None, None)
+                .is_err()
+        );
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn test_text_types() {
+        let sniffer = ContentSniffer::new();
+
+        let html = sniffer.detect(b"  <!DOCTYPE html><html></html>", None, None);
+        assert_eq!(html.mime_type, "text/html");
+        assert_eq!(html.encoding.as_deref(), Some("UTF-8"));
+
+        let markdown = sniffer.detect(b"# Title\n\nBody", Some("README.md"), None);
+        assert_eq!(markdown.mime_type, "text/markdown");
+
+        // A binary type declared for text content is not trusted
+        let declared = sniffer.detect(b"plain words", None, Some("application/pdf"));
+        assert_eq!(declared.mime_type, "text/plain");
+
+        let hinted = sniffer.detect(
+            b"https://example.com",
+            None,
+            Some("text/HTML; charset=utf-8"),
+        );
+        assert_eq!(hinted.mime_type, "text/html");
+        assert_eq!(hinted.method, DetectionMethod::Declared);
+    }
+
+    #[test]
+    fn test_encoding_detection() {
+        assert_eq!(detect_encoding(b"\xff\xfeh\0i\0"), Some(UTF_16LE));
+        assert_eq!(detect_encoding(b"\x00\x01\x02\x03binary"), None);
+
+        let latin1 = b"Caf\xe9 cr\xe8me br\xfbl\xe9e, d\xe9j\xe0 vu";
+        let encoding = detect_encoding(latin1).unwrap();
+        assert_eq!(encoding.name(), "windows-1252");
+        assert_eq!(
+            decode_text(latin1, Some(encoding.name())),
+            "Café crème brûlée, déjà vu"
+        );
+        assert_eq!(decode_text("déjà".as_bytes(), None), "déjà");
+    }
+}
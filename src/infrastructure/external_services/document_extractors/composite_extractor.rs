@@ -2,15 +2,19 @@ use async_trait::async_trait;
 use crate::domain::entities::File;
 use std::sync::Arc;
 
-use super::{HtmlExtractor, PdfExtractor, YoutubeExtractor};
+use super::{HtmlExtractor, PdfExtractor, TextExtractor, YoutubeExtractor};
+use crate::application::ports::ContentDetector;
 use crate::application::ports::document_extractor::{
     DocumentExtractionError, DocumentExtractor, ExtractedContent, ExtractionOptions,
 };
+use crate::infrastructure::external_services::ContentSniffer;
 
 pub struct CompositeDocumentExtractor {
     html_extractor: Arc<HtmlExtractor>,
     pdf_extractor: Arc<PdfExtractor>,
+    text_extractor: Arc<TextExtractor>,
     youtube_extractor: Arc<YoutubeExtractor>,
+    content_detector: Arc<dyn ContentDetector>,
 }
 
 impl CompositeDocumentExtractor {
@@ -18,10 +22,41 @@ impl CompositeDocumentExtractor {
         Ok(Self {
             html_extractor: Arc::new(HtmlExtractor::new()),
             pdf_extractor: Arc::new(PdfExtractor::new()),
+            text_extractor: Arc::new(TextExtractor::new()),
             youtube_extractor: Arc::new(YoutubeExtractor::new()?),
+            content_detector: Arc::new(ContentSniffer::new()),
         })
     }
 
+    pub fn with_content_detector(mut self, content_detector: Arc<dyn ContentDetector>) -> Self {
+        self.content_detector = content_detector;
+        self
+    }
+
+    /// Local files are routed by their sniffed type; URL-backed files keep the stored type
+    async fn resolve_file_type(&self, file: &File) -> Result<String, DocumentExtractionError> {
+        let content_detector = self.content_detector.clone();
+        let path = std::path::PathBuf::from(file.file_path());
+        let file_name = file.file_name().to_string();
+        let declared_type = file.file_type().map(str::to_string);
+        // Sniffing reads the file, so it runs off the async workers
+        let detected = tokio::task::spawn_blocking(move || {
+            content_detector.detect_file(&path, Some(&file_name), declared_type.as_deref())
+        })
+        .await
+        .map_err(std::io::Error::other)
+        .and_then(|detected| detected);
+        match detected {
+            Ok(detected) => Ok(detected.mime_type),
+            Err(_) => file.file_type().map(|t| t.to_string()).ok_or_else(|| {
+                DocumentExtractionError::UnsupportedFormat(format!(
+                    "unknown content type for {}",
+                    file.file_name()
+                ))
+            }),
+        }
+    }
+
     fn get_extractor_for_type(&self, file_type: &str) -> Option<Arc<dyn DocumentExtractor>> {
         let file_type_lower = file_type.to_lowercase();
 
@@ -29,6 +64,8 @@ impl CompositeDocumentExtractor {
             Some(self.html_extractor.clone())
         } else if self.pdf_extractor.can_extract(&file_type_lower) {
             Some(self.pdf_extractor.clone())
+        } else if self.text_extractor.can_extract(&file_type_lower) {
+            Some(self.text_extractor.clone())
         } else if self.youtube_extractor.can_extract(&file_type_lower) {
             Some(self.youtube_extractor.clone())
         } else {
@@ -50,11 +87,11 @@ impl DocumentExtractor for CompositeDocumentExtractor {
         file: &File,
         options: ExtractionOptions,
     ) -> Result<ExtractedContent, DocumentExtractionError> {
-        let file_type = file.file_type().unwrap();
+        let file_type = self.resolve_file_type(file).await?;
 
         let extractor = self
-            .get_extractor_for_type(file_type)
-            .ok_or_else(|| DocumentExtractionError::UnsupportedFormat(file_type.to_string()))?;
+            .get_extractor_for_type(&file_type)
+            .ok_or_else(|| DocumentExtractionError::UnsupportedFormat(file_type.clone()))?;
 
         extractor.extract_text(file, options).await
     }
@@ -65,12 +102,17 @@ impl DocumentExtractor for CompositeDocumentExtractor {
         file_type: &str,
         options: ExtractionOptions,
     ) -> Result<ExtractedContent, DocumentExtractionError> {
+        let file_type = self
+            .content_detector
+            .detect(data, None, Some(file_type))
+            .mime_type;
+
         let extractor = self
-            .get_extractor_for_type(file_type)
-            .ok_or_else(|| DocumentExtractionError::UnsupportedFormat(file_type.to_string()))?;
+            .get_extractor_for_type(&file_type)
+            .ok_or_else(|| DocumentExtractionError::UnsupportedFormat(file_type.clone()))?;
 
         extractor
-            .extract_text_from_bytes(data, file_type, options)
+            .extract_text_from_bytes(data, &file_type, options)
             .await
     }
 
@@ -78,6 +120,7 @@ impl DocumentExtractor for CompositeDocumentExtractor {
         let mut formats = Vec::new();
         formats.extend(self.html_extractor.supported_formats());
         formats.extend(self.pdf_extractor.supported_formats());
+        formats.extend(self.text_extractor.supported_formats());
         formats.extend(self.youtube_extractor.supported_formats());
         formats
     }
@@ -85,6 +128,7 @@ impl DocumentExtractor for CompositeDocumentExtractor {
     fn can_extract(&self, file_type: &str) -> bool {
         self.html_extractor.can_extract(file_type)
             || self.pdf_extractor.can_extract(file_type)
+            || self.text_extractor.can_extract(file_type)
             || self.youtube_extractor.can_extract(file_type)
     }
 
@@ -92,6 +136,7 @@ impl DocumentExtractor for CompositeDocumentExtractor {
         [
             self.html_extractor.max_file_size(),
             self.pdf_extractor.max_file_size(),
+            self.text_extractor.max_file_size(),
             self.youtube_extractor.max_file_size(),
         ]
         .iter()
@@ -87,19 +87,18 @@ impl DocumentExtractor for HtmlExtractor {
             .await?;
 
         let mut metadata = FileMetadata::new();
-        if options.extract_metadata {
-            // Extract basic metadata from HTML
-            if let Some(title) = extract_title_from_html(&content_path) {
-                metadata.set_title(title);
-            }
-            metadata.set_language("html".to_string());
+        // Extract basic metadata from HTML
+        if options.extract_metadata
+            && let Some(title) = extract_title_from_html(content_path)
+        {
+            metadata.set_title(title);
         }
 
         Ok(ExtractedContent {
             text,
             metadata,
             page_count: Some(1), // HTML is considered as 1 "page"
-            language: Some("html".to_string()),
+            language: None,
         })
     }
 
@@ -125,18 +124,17 @@ impl DocumentExtractor for HtmlExtractor {
             .await?;
 
         let mut metadata = FileMetadata::new();
-        if options.extract_metadata {
-            if let Some(title) = extract_title_from_html(&html_content) {
-                metadata.set_title(title);
-            }
-            metadata.set_language("html".to_string());
+        if options.extract_metadata
+            && let Some(title) = extract_title_from_html(&html_content)
+        {
+            metadata.set_title(title);
         }
 
         Ok(ExtractedContent {
             text,
             metadata,
             page_count: Some(1),
-            language: Some("html".to_string()),
+            language: None,
         })
     }
 
@@ -209,7 +209,6 @@ impl DocumentExtractor for PdfExtractor {
 
         let page_count = page_texts.len() as i32;
         metadata.set_page_count(page_count);
-        metadata.set_language("pdf".to_string());
 
         if !errors.is_empty() {
             metadata.set_property(
@@ -224,7 +223,7 @@ impl DocumentExtractor for PdfExtractor {
             text,
             metadata,
             page_count: Some(page_count),
-            language: Some("pdf".to_string()),
+            language: None, // Identified from the text after extraction
         })
     }
 
@@ -0,0 +1,123 @@
+use crate::domain::entities::File;
+use async_trait::async_trait;
+
+use crate::application::ports::document_extractor::{
+    DocumentExtractionError, DocumentExtractor, ExtractedContent, ExtractionOptions,
+};
+use crate::domain::value_objects::FileMetadata;
+use crate::infrastructure::external_services::content_sniffer::{decode_text, detect_encoding};
+
+/// Plain text and Markdown, decoded from whatever encoding they were saved in
+pub struct TextExtractor;
+
+impl TextExtractor {
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn extract(
+        &self,
+        data: &[u8],
+        encoding: Option<&str>,
+        options: &ExtractionOptions,
+    ) -> ExtractedContent {
+        let encoding = encoding
+            .map(|e| e.to_string())
+            .or_else(|| detect_encoding(data).map(|e| e.name().to_string()));
+        let text = decode_text(data, encoding.as_deref());
+
+        let mut metadata = FileMetadata::new();
+        if options.extract_metadata {
+            // A leading Markdown heading doubles as the title
+            if let Some(title) = text
+                .lines()
+                .find(|line| !line.trim().is_empty())
+                .and_then(|line| line.trim().strip_prefix("# "))
+            {
+                metadata.set_title(title.trim().to_string());
+            }
+            if let Some(encoding) = encoding {
+                metadata.set_encoding(encoding);
+            }
+        }
+
+        ExtractedContent {
+            text,
+            metadata,
+            page_count: Some(1),
+            language: None,
+        }
+    }
+}
+
+impl Default for TextExtractor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl DocumentExtractor for TextExtractor {
+    async fn extract_text(
+        &self,
+        file: &File,
+        options: ExtractionOptions,
+    ) -> Result<ExtractedContent, DocumentExtractionError> {
+        let data = tokio::fs::read(file.file_path())
+            .await
+            .map_err(|e| DocumentExtractionError::IoError(e.to_string()))?;
+
+        let encoding = file.metadata().and_then(|m| m.get_encoding());
+        Ok(self.extract(&data, encoding.as_deref(), &options))
+    }
+
+    async fn extract_text_from_bytes(
+        &self,
+        data: &[u8],
+        file_type: &str,
+        options: ExtractionOptions,
+    ) -> Result<ExtractedContent, DocumentExtractionError> {
+        if !self.can_extract(file_type) {
+            return Err(DocumentExtractionError::UnsupportedFormat(
+                file_type.to_string(),
+            ));
+        }
+
+        Ok(self.extract(data, None, &options))
+    }
+
+    fn supported_formats(&self) -> Vec<String> {
+        vec!["text/plain".to_string(), "text/markdown".to_string()]
+    }
+
+    fn can_extract(&self, file_type: &str) -> bool {
+        self.supported_formats().contains(&file_type.to_lowercase())
+    }
+
+    fn max_file_size(&self) -> Option<usize> {
+        Some(20 * 1024 * 1024) // 20MB max for text files
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_extracts_legacy_encoded_markdown() {
+        let extractor = TextExtractor::new();
+        let data = b"# R\xe9sum\xe9\n\nExp\xe9rience professionnelle et comp\xe9tences.";
+
+        let content = extractor
+            .extract_text_from_bytes(data, "text/markdown", ExtractionOptions::default())
+            .await
+            .unwrap();
+
+        assert!(content.text.starts_with("# Résumé"));
+        assert_eq!(content.metadata.get_title().as_deref(), Some("Résumé"));
+        assert_eq!(
+            content.metadata.get_encoding().as_deref(),
+            Some("windows-1252")
+        );
+    }
+}
@@ -0,0 +1,122 @@
+use async_trait::async_trait;
+use std::time::Duration;
+
+use crate::application::ports::feed_fetcher::{FeedFetchError, FeedFetcher, FeedItem, FetchedFeed};
+
+pub struct HttpFeedFetcher {
+    client: reqwest::Client,
+}
+
+impl HttpFeedFetcher {
+    pub fn new() -> Result<Self, FeedFetchError> {
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(30))
+            .user_agent(concat!("polyrag/", env!("CARGO_PKG_VERSION")))
+            .build()
+            .map_err(|e| FeedFetchError::NetworkError(e.to_string()))?;
+
+        Ok(Self { client })
+    }
+}
+
+/// Parse an RSS, Atom or JSON Feed document into the port's item model
+pub fn parse_feed(data: &[u8]) -> Result<FetchedFeed, FeedFetchError> {
+    let feed =
+        feed_rs::parser::parse(data).map_err(|e| FeedFetchError::ParseError(e.to_string()))?;
+
+    let items = feed
+        .entries
+        .into_iter()
+        .map(|entry| FeedItem {
+            // feed-rs synthesises a stable id when the document has no guid
+            guid: entry.id,
+            link: entry.links.into_iter().next().map(|link| link.href),
+            title: entry.title.map(|t| t.content.trim().to_string()),
+            published_at: entry.published,
+            updated_at: entry.updated,
+        })
+        .collect();
+
+    Ok(FetchedFeed {
+        title: feed.title.map(|t| t.content.trim().to_string()),
+        items,
+    })
+}
+
+#[async_trait]
+impl FeedFetcher for HttpFeedFetcher {
+    async fn fetch(&self, url: &str) -> Result<FetchedFeed, FeedFetchError> {
+        let response =
+            self.client.get(url).send().await.map_err(|e| {
+                FeedFetchError::NetworkError(format!("Failed to fetch feed: {}", e))
+            })?;
+
+        if !response.status().is_success() {
+            return Err(FeedFetchError::NetworkError(format!(
+                "Feed returned status {}",
+                response.status()
+            )));
+        }
+
+        let body = response
+            .bytes()
+            .await
+            .map_err(|e| FeedFetchError::NetworkError(format!("Failed to read feed: {}", e)))?;
+
+        parse_feed(&body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_rss() {
+        let rss = r#"<?xml version="1.0"?>
+            <rss version="2.0"><channel>
+              <title>Example Blog</title>
+              <item>
+                <title>First post</title>
+                <link>https://example.com/first</link>
+                <guid>post-1</guid>
+                <pubDate>Mon, 06 Jan 2025 10:00:00 GMT</pubDate>
+              </item>
+            </channel></rss>"#;
+
+        let feed = parse_feed(rss.as_bytes()).unwrap();
+        assert_eq!(feed.title.as_deref(), Some("Example Blog"));
+        assert_eq!(feed.items.len(), 1);
+        assert_eq!(feed.items[0].guid, "post-1");
+        assert_eq!(
+            feed.items[0].link.as_deref(),
+            Some("https://example.com/first")
+        );
+        assert!(feed.items[0].published_at.is_some());
+    }
+
+    #[test]
+    fn test_parse_atom() {
+        let atom = r#"<?xml version="1.0" encoding="utf-8"?>
+            <feed xmlns="http://www.w3.org/2005/Atom">
+              <title>Example Atom</title>
+              <id>urn:feed</id>
+              <updated>2025-01-06T10:00:00Z</updated>
+              <entry>
+                <title>Entry</title>
+                <id>urn:entry:1</id>
+                <link href="https://example.com/entry"/>
+                <updated>2025-01-07T10:00:00Z</updated>
+              </entry>
+            </feed>"#;
+
+        let feed = parse_feed(atom.as_bytes()).unwrap();
+        assert_eq!(feed.items[0].guid, "urn:entry:1");
+        assert!(feed.items[0].updated_at.is_some());
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!(parse_feed(b"not a feed").is_err());
+    }
+}
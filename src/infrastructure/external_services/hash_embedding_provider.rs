@@ -0,0 +1,158 @@
+use async_trait::async_trait;
+use pgvector::Vector;
+use sha2::{Digest, Sha256};
+
+use crate::application::ports::embedding_provider::{
+    BatchEmbeddingRequest, BatchEmbeddingResponse, EmbeddingProvider, EmbeddingProviderError,
+    EmbeddingRequest, EmbeddingResponse,
+};
+use crate::domain::value_objects::EmbeddingPrompts;
+
+/// Embeds text without a model: each lowercased word is hashed to one
+/// signed dimension and the counts are normalised. The same text always
+/// gives the same vector and texts sharing words score as similar, which is
+/// enough to run ingestion and search offline, in tests or during
+/// development. It captures no meaning beyond shared words.
+pub struct HashEmbeddingProvider {
+    model_name: String,
+    dimension: usize,
+    max_input_length: usize,
+    prompts: EmbeddingPrompts,
+}
+
+impl HashEmbeddingProvider {
+    pub fn new(model_name: impl Into<String>, dimension: usize) -> Self {
+        Self {
+            model_name: model_name.into(),
+            dimension: dimension.max(1),
+            max_input_length: 512,
+            prompts: EmbeddingPrompts::default(),
+        }
+    }
+
+    /// Templates are rendered around the text like any provider's
+    pub fn with_prompts(mut self, prompts: EmbeddingPrompts) -> Self {
+        self.prompts = prompts;
+        self
+    }
+
+    pub fn embed(&self, text: &str) -> Vector {
+        let mut values = vec![0.0f32; self.dimension];
+        for word in text
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|word| !word.is_empty())
+        {
+            let digest = Sha256::digest(word.to_lowercase().as_bytes());
+            let bucket = u64::from_le_bytes(digest[..8].try_into().unwrap());
+            let sign = if digest[8] & 1 == 0 { 1.0 } else { -1.0 };
+            values[(bucket % self.dimension as u64) as usize] += sign;
+        }
+
+        let norm = values.iter().map(|x| x * x).sum::<f32>().sqrt();
+        if norm == 0.0 {
+            // Text without words still needs a unit vector to be comparable
+            values[0] = 1.0;
+        } else {
+            values.iter_mut().for_each(|x| *x /= norm);
+        }
+        Vector::from(values)
+    }
+}
+
+#[async_trait]
+impl EmbeddingProvider for HashEmbeddingProvider {
+    async fn generate_embedding(
+        &self,
+        request: EmbeddingRequest,
+    ) -> Result<EmbeddingResponse, EmbeddingProviderError> {
+        Ok(EmbeddingResponse {
+            embedding: self.embed(&self.prompts.render(request.purpose, &request.text)),
+            model_name: request
+                .model_name
+                .unwrap_or_else(|| self.model_name.clone()),
+            model_version: request.model_version,
+            token_count: None,
+        })
+    }
+
+    async fn generate_embeddings(
+        &self,
+        request: BatchEmbeddingRequest,
+    ) -> Result<BatchEmbeddingResponse, EmbeddingProviderError> {
+        if request.texts.is_empty() {
+            return Err(EmbeddingProviderError::InvalidInput(
+                "No texts to embed".to_string(),
+            ));
+        }
+
+        Ok(BatchEmbeddingResponse {
+            embeddings: request
+                .texts
+                .iter()
+                .map(|text| self.embed(&self.prompts.render(request.purpose, text)))
+                .collect(),
+            model_name: request
+                .model_name
+                .unwrap_or_else(|| self.model_name.clone()),
+            model_version: request.model_version,
+            total_tokens: None,
+        })
+    }
+
+    async fn health_check(&self) -> Result<bool, EmbeddingProviderError> {
+        Ok(true)
+    }
+
+    fn model_info(&self) -> (String, Option<String>) {
+        (self.model_name.clone(), None)
+    }
+
+    fn max_input_length(&self) -> usize {
+        self.max_input_length
+    }
+
+    fn embedding_dimension(&self) -> usize {
+        self.dimension
+    }
+
+    fn prompts(&self) -> EmbeddingPrompts {
+        self.prompts.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn similarity(a: &Vector, b: &Vector) -> f32 {
+        a.as_slice()
+            .iter()
+            .zip(b.as_slice())
+            .map(|(x, y)| x * y)
+            .sum()
+    }
+
+    #[test]
+    fn test_deterministic_unit_vectors() {
+        let provider = HashEmbeddingProvider::new("hash", 64);
+
+        let first = provider.embed("The quick brown fox");
+        let second = HashEmbeddingProvider::new("hash", 64).embed("the QUICK brown fox!");
+
+        assert_eq!(first, second);
+        assert_eq!(first.as_slice().len(), 64);
+        assert!((similarity(&first, &first) - 1.0).abs() < 1e-5);
+        assert!((similarity(&provider.embed(""), &provider.embed("")) - 1.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_shared_words_are_similar() {
+        let provider = HashEmbeddingProvider::new("hash", 256);
+
+        let query = provider.embed("rust borrow checker");
+        let related = provider.embed("The borrow checker enforces Rust's ownership rules");
+        let unrelated = provider.embed("Sourdough needs a long cold fermentation");
+
+        assert!(similarity(&query, &related) > similarity(&query, &unrelated));
+    }
+}
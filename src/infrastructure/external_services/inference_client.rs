@@ -1,13 +1,25 @@
 use async_trait::async_trait;
-use pgvector::Vector;
-use reqwest::{Client, Error as ReqwestError};
+use pgvector::{SparseVector, Vector};
+use reqwest::{Client, Error as ReqwestError, StatusCode};
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use std::env;
+use std::future::Future;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::time::Duration;
 
 use crate::application::ports::embedding_provider::{
     BatchEmbeddingRequest, BatchEmbeddingResponse, EmbeddingProvider, EmbeddingProviderError,
-    EmbeddingRequest, EmbeddingResponse,
+    EmbeddingRequest, EmbeddingResponse, EndpointStatus,
+};
+use crate::application::ports::sparse_embedding_provider::{
+    SparseEmbeddingProvider, SparseEmbeddingResponse,
+};
+use crate::domain::value_objects::{EmbeddingPrompts, EmbeddingPurpose};
+use crate::infrastructure::external_services::batching_embedding_provider::retry_after;
+use crate::infrastructure::external_services::circuit_breaker::{
+    Admission, CircuitBreaker, CircuitBreakerConfig,
 };
 
 // TEI API request/response structures based on OpenAPI spec
@@ -36,6 +48,32 @@ pub enum TeiInput {
 // TEI response is just an array of arrays of floats
 pub type TeiEmbedResponse = Vec<Vec<f32>>;
 
+#[derive(Serialize)]
+pub struct TeiEmbedSparseRequest {
+    pub inputs: TeiInput,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub truncate: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prompt_name: Option<String>,
+}
+
+/// One non-zero term weight of a sparse embedding
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct TeiSparseValue {
+    pub index: usize,
+    pub value: f32,
+}
+
+pub type TeiEmbedSparseResponse = Vec<Vec<TeiSparseValue>>;
+
+/// Subset of TEI's `/info` response
+#[derive(Debug, Clone, Deserialize)]
+pub struct TeiInfo {
+    pub model_id: String,
+    pub model_sha: Option<String>,
+    pub max_input_length: usize,
+}
+
 #[derive(Deserialize)]
 pub struct TeiErrorResponse {
     pub error: String,
@@ -44,22 +82,49 @@ pub struct TeiErrorResponse {
 
 #[derive(Debug, Clone)]
 pub struct EmbeddingsClientConfig {
-    pub service_url: String,
+    /// Servers of the same model; requests rotate over the healthy ones
+    pub service_urls: Vec<String>,
     pub max_retries: u32,
     pub timeout_secs: u64,
     pub backoff_factor: f64,
+    pub breaker: CircuitBreakerConfig,
 }
 
 impl Default for EmbeddingsClientConfig {
     fn default() -> Self {
-        let service_url = env::var("EMBEDDINGS_SERVICE_URL")
-            .unwrap_or_else(|_| "http://localhost:8080".to_string());
+        Self::for_urls(
+            &env::var("EMBEDDINGS_SERVICE_URL")
+                .unwrap_or_else(|_| "http://localhost:8080".to_string()),
+        )
+    }
+}
+
+impl EmbeddingsClientConfig {
+    /// Defaults for the comma-separated `urls`, breaker settings from the environment
+    pub fn for_urls(urls: &str) -> Self {
+        let service_urls = urls
+            .split(',')
+            .map(|url| url.trim().trim_end_matches('/').to_string())
+            .filter(|url| !url.is_empty())
+            .collect();
+        let defaults = CircuitBreakerConfig::default();
 
         Self {
-            service_url,
+            service_urls,
             max_retries: 3,
             timeout_secs: 30,
             backoff_factor: 1.5,
+            breaker: CircuitBreakerConfig {
+                failure_threshold: env::var("EMBEDDINGS_BREAKER_THRESHOLD")
+                    .ok()
+                    .and_then(|s| s.parse().ok())
+                    .unwrap_or(defaults.failure_threshold),
+                open_for: env::var("EMBEDDINGS_BREAKER_OPEN_SECS")
+                    .ok()
+                    .and_then(|s| s.parse().ok())
+                    .map(Duration::from_secs)
+                    .unwrap_or(defaults.open_for),
+            },
         }
     }
 }
@@ -70,12 +135,60 @@ pub enum EmbeddingsError {
     ParseError(String),
     // MaxRetriesExceeded(String),
     ApiError(String),
+    /// 5xx: the server failed, not the request
+    ServerError(String),
+    /// 429, with the wait the server asked for
+    RateLimited(Option<Duration>),
+    /// 422: one of the inputs is invalid, e.g. too long
+    InputRejected(String),
+    /// 413, or more inputs than the server's `--max-client-batch-size`
+    BatchTooLarge(String),
+    /// Every endpoint's circuit is open; `unavailable_for` says for how long
+    Unavailable,
+}
+
+impl EmbeddingsError {
+    /// Whether another attempt, possibly on another endpoint, could succeed.
+    /// These are also the failures that count against an endpoint's circuit.
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            EmbeddingsError::RequestError(_)
+                | EmbeddingsError::ParseError(_)
+                | EmbeddingsError::ServerError(_)
+        )
+    }
+}
+
+impl From<EmbeddingsError> for EmbeddingProviderError {
+    fn from(e: EmbeddingsError) -> Self {
+        match e {
+            EmbeddingsError::RequestError(msg) => EmbeddingProviderError::NetworkError(msg),
+            EmbeddingsError::ParseError(msg) => EmbeddingProviderError::ApiError(msg),
+            EmbeddingsError::ApiError(msg) => EmbeddingProviderError::ApiError(msg),
+            EmbeddingsError::RateLimited(retry_after) => {
+                EmbeddingProviderError::RateLimitExceeded(retry_after)
+            }
+            EmbeddingsError::InputRejected(msg) => EmbeddingProviderError::InvalidInput(msg),
+            EmbeddingsError::BatchTooLarge(msg) => EmbeddingProviderError::BatchTooLarge(msg),
+            EmbeddingsError::ServerError(msg) => EmbeddingProviderError::ApiError(msg),
+            EmbeddingsError::Unavailable => EmbeddingProviderError::ServiceUnavailable,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Endpoint {
+    url: String,
+    breaker: CircuitBreaker,
 }
 
 #[derive(Debug, Clone)]
 pub struct InferenceClient {
     client: Client,
     config: EmbeddingsClientConfig,
+    endpoints: Arc<Vec<Endpoint>>,
+    next_endpoint: Arc<AtomicUsize>,
 }
 
 impl InferenceClient {
@@ -83,22 +196,42 @@ impl InferenceClient {
         let client = Client::builder()
             .timeout(Duration::from_secs(config.timeout_secs))
             .build()?;
+        let endpoints = config
+            .service_urls
+            .iter()
+            .map(|url| Endpoint {
+                url: url.clone(),
+                breaker: CircuitBreaker::new(config.breaker.clone()),
+            })
+            .collect();
 
-        Ok(Self { client, config })
+        Ok(Self {
+            client,
+            config,
+            endpoints: Arc::new(endpoints),
+            next_endpoint: Arc::new(AtomicUsize::new(0)),
+        })
     }
 
     pub fn from_env() -> Result<Self, ReqwestError> {
         Self::new(EmbeddingsClientConfig::default())
     }
 
-    pub async fn get_embedding(&self, text: &str) -> Result<TeiEmbedResponse, EmbeddingsError> {
+    /// `prompt_name` selects one of the prompts the model ships with;
+    /// `dimensions` asks a Matryoshka model for shortened vectors
+    pub async fn get_embedding(
+        &self,
+        text: &str,
+        prompt_name: Option<&str>,
+        dimensions: Option<u32>,
+    ) -> Result<TeiEmbedResponse, EmbeddingsError> {
         let request = TeiEmbedRequest {
             inputs: TeiInput::Single(text.to_string()),
             normalize: Some(true),
             truncate: Some(false),
             truncation_direction: None,
-            prompt_name: None,
-            dimensions: None,
+            prompt_name: prompt_name.map(str::to_string),
+            dimensions,
         };
 
         self.send_embed_request(request).await
@@ -107,24 +240,97 @@ impl InferenceClient {
     pub async fn get_embeddings(
         &self,
         texts: &Vec<String>,
+        prompt_name: Option<&str>,
+        dimensions: Option<u32>,
     ) -> Result<TeiEmbedResponse, EmbeddingsError> {
         let request = TeiEmbedRequest {
             inputs: TeiInput::Multiple(texts.to_vec()),
             normalize: Some(true),
             truncate: Some(false),
             truncation_direction: None,
-            prompt_name: None,
-            dimensions: None,
+            prompt_name: prompt_name.map(str::to_string),
+            dimensions,
         };
 
         self.send_embed_request(request).await
     }
 
+    /// Term weights from a sparse model such as SPLADE, via `/embed_sparse`
+    pub async fn get_sparse_embeddings(
+        &self,
+        texts: &[String],
+        prompt_name: Option<&str>,
+    ) -> Result<TeiEmbedSparseResponse, EmbeddingsError> {
+        let request = TeiEmbedSparseRequest {
+            inputs: TeiInput::Multiple(texts.to_vec()),
+            truncate: Some(false),
+            prompt_name: prompt_name.map(str::to_string),
+        };
+
+        self.with_retries(|url| self.execute_request(url, "embed_sparse", &request))
+            .await
+    }
+
+    pub async fn get_info(&self) -> Result<TeiInfo, EmbeddingsError> {
+        self.with_retries(|url| self.fetch_info(url)).await
+    }
+
+    /// Reads `/info` from every endpoint, reachable or not
+    pub async fn endpoint_infos(&self) -> Vec<(String, Result<TeiInfo, EmbeddingsError>)> {
+        let mut infos = Vec::with_capacity(self.endpoints.len());
+        for endpoint in self.endpoints.iter() {
+            infos.push((
+                endpoint.url.clone(),
+                self.fetch_info(endpoint.url.clone()).await,
+            ));
+        }
+        infos
+    }
+
+    pub fn service_urls(&self) -> Vec<&str> {
+        self.endpoints
+            .iter()
+            .map(|endpoint| endpoint.url.as_str())
+            .collect()
+    }
+
+    pub fn endpoint_statuses(&self) -> Vec<EndpointStatus> {
+        self.endpoints
+            .iter()
+            .map(|endpoint| endpoint.breaker.status(&endpoint.url))
+            .collect()
+    }
+
+    /// Until the first endpoint admits requests again, when all circuits are open
+    pub fn unavailable_for(&self) -> Option<Duration> {
+        self.endpoints
+            .iter()
+            .map(|endpoint| endpoint.breaker.retry_in())
+            .collect::<Option<Vec<_>>>()?
+            .into_iter()
+            .min()
+    }
+
+    /// Healthy when any endpoint is
     pub async fn health_check(&self) -> Result<bool, EmbeddingsError> {
-        let url = format!("{}/health", self.config.service_url);
+        let mut last_error = None;
+        for endpoint in self.endpoints.iter() {
+            match self.endpoint_health(&endpoint.url).await {
+                Ok(true) => return Ok(true),
+                Ok(false) => {}
+                Err(e) => last_error = Some(e),
+            }
+        }
+        match last_error {
+            Some(e) => Err(e),
+            None => Ok(false),
+        }
+    }
+
+    async fn endpoint_health(&self, url: &str) -> Result<bool, EmbeddingsError> {
         let response = self
             .client
-            .get(&url)
+            .get(format!("{}/health", url))
             .send()
             .await
             .map_err(|e| EmbeddingsError::RequestError(e.to_string()))?;
@@ -136,20 +342,30 @@ impl InferenceClient {
         &self,
         request: TeiEmbedRequest,
     ) -> Result<TeiEmbedResponse, EmbeddingsError> {
+        self.with_retries(|url| self.execute_request(url, "embed", &request))
+            .await
+    }
+
+    /// Retries retryable failures with backoff. Every attempt goes to the
+    /// healthy endpoints in turn, so a failing server is skipped at once;
+    /// once all circuits are open the caller learns so immediately instead
+    /// of sleeping through retries.
+    async fn with_retries<T, F, Fut>(&self, call: F) -> Result<T, EmbeddingsError>
+    where
+        F: Fn(String) -> Fut,
+        Fut: Future<Output = Result<T, EmbeddingsError>>,
+    {
         let mut attempts = 0;
 
         loop {
             attempts += 1;
 
-            let result = self.execute_embed_request(&request).await;
-
-            match result {
+            match self.route(&call).await {
                 Ok(response) => return Ok(response),
-                Err(e) => {
-                    if attempts > self.config.max_retries {
-                        return Err(e);
-                    }
-
+                Err(e) if !e.is_retryable() || attempts > self.config.max_retries => {
+                    return Err(e);
+                }
+                Err(_) => {
                     let backoff_time = Duration::from_millis(
                         (self.config.backoff_factor.powi(attempts as i32 - 1) * 1000.0) as u64,
                     );
@@ -160,11 +376,88 @@ impl InferenceClient {
         }
     }
 
-    async fn execute_embed_request(
+    /// One pass over the endpoints whose circuits admit the call, starting
+    /// from the next in rotation
+    async fn route<T, F, Fut>(&self, call: &F) -> Result<T, EmbeddingsError>
+    where
+        F: Fn(String) -> Fut,
+        Fut: Future<Output = Result<T, EmbeddingsError>>,
+    {
+        let start = self.next_endpoint.fetch_add(1, Ordering::Relaxed);
+        let mut last_error = None;
+
+        for offset in 0..self.endpoints.len() {
+            let endpoint = &self.endpoints[(start + offset) % self.endpoints.len()];
+            match endpoint.breaker.admit() {
+                Admission::Rejected => continue,
+                // Ask a recovering server's health endpoint before trusting it with work
+                Admission::Probe => {
+                    if !matches!(self.endpoint_health(&endpoint.url).await, Ok(true)) {
+                        endpoint.breaker.record_failure();
+                        continue;
+                    }
+                }
+                Admission::Allowed => {}
+            }
+
+            match call(endpoint.url.clone()).await {
+                Ok(response) => {
+                    endpoint.breaker.record_success();
+                    return Ok(response);
+                }
+                Err(e) if e.is_retryable() => {
+                    endpoint.breaker.record_failure();
+                    eprintln!("Embedding endpoint {} failed: {:?}", endpoint.url, e);
+                    last_error = Some(e);
+                }
+                // A busy server is alive; another one may have capacity
+                Err(e @ EmbeddingsError::RateLimited(_)) => {
+                    endpoint.breaker.record_success();
+                    last_error = Some(e);
+                }
+                // The server answered; the request itself is at fault
+                Err(e) => {
+                    endpoint.breaker.record_success();
+                    return Err(e);
+                }
+            }
+        }
+
+        Err(last_error.unwrap_or(EmbeddingsError::Unavailable))
+    }
+
+    async fn fetch_info(&self, url: String) -> Result<TeiInfo, EmbeddingsError> {
+        let response = self
+            .client
+            .get(format!("{}/info", url))
+            .send()
+            .await
+            .map_err(|e| EmbeddingsError::RequestError(e.to_string()))?;
+
+        let status = response.status();
+        if status.is_server_error() {
+            return Err(EmbeddingsError::ServerError(format!(
+                "HTTP error: {}",
+                status
+            )));
+        }
+        if !status.is_success() {
+            return Err(EmbeddingsError::ApiError(format!("HTTP error: {}", status)));
+        }
+
+        response
+            .json::<TeiInfo>()
+            .await
+            .map_err(|e| EmbeddingsError::ParseError(e.to_string()))
+    }
+
+    async fn execute_request<R: Serialize, T: DeserializeOwned>(
         &self,
-        request: &TeiEmbedRequest,
-    ) -> Result<TeiEmbedResponse, EmbeddingsError> {
-        let url = format!("{}/embed", self.config.service_url);
+        service_url: String,
+        path: &str,
+        request: &R,
+    ) -> Result<T, EmbeddingsError> {
+        let url = format!("{}/{}", service_url, path);
 
         let response = self
             .client
@@ -177,22 +470,36 @@ impl InferenceClient {
 
         if !response.status().is_success() {
             let status = response.status();
+            if status == StatusCode::TOO_MANY_REQUESTS {
+                return Err(EmbeddingsError::RateLimited(retry_after(
+                    response.headers(),
+                )));
+            }
             // Try to parse error response
-            match response.json::<TeiErrorResponse>().await {
-                Ok(error_response) => {
-                    return Err(EmbeddingsError::ApiError(format!(
+            let (message, batch_size_error) = match response.json::<TeiErrorResponse>().await {
+                Ok(error_response) => (
+                    format!(
                         "TEI API error: {} (type: {})",
                         error_response.error, error_response.error_type
-                    )));
+                    ),
+                    // TEI's validation error for `--max-client-batch-size`
+                    error_response.error.contains("maximum allowed batch size"),
+                ),
+                Err(_) => (format!("HTTP error: {}", status), false),
+            };
+            return Err(match status {
+                StatusCode::PAYLOAD_TOO_LARGE => EmbeddingsError::BatchTooLarge(message),
+                StatusCode::UNPROCESSABLE_ENTITY if batch_size_error => {
+                    EmbeddingsError::BatchTooLarge(message)
                 }
-                Err(_) => {
-                    return Err(EmbeddingsError::ApiError(format!("HTTP error: {}", status)));
-                }
-            }
+                StatusCode::UNPROCESSABLE_ENTITY => EmbeddingsError::InputRejected(message),
+                status if status.is_server_error() => EmbeddingsError::ServerError(message),
+                _ => EmbeddingsError::ApiError(message),
+            });
         }
 
         let embeddings = response
-            .json::<TeiEmbedResponse>()
+            .json::<T>()
             .await
             .map_err(|e| EmbeddingsError::ParseError(e.to_string()))?;
 
@@ -203,12 +510,102 @@ impl InferenceClient {
 // Adapter to implement the EmbeddingProvider trait
 pub struct InferenceEmbeddingProvider {
     client: InferenceClient,
+    model: ModelInfo,
+    prompts: EmbeddingPrompts,
+    dimensions: Option<u32>,
+}
+
+/// What the server reports about the model it serves, read once at startup
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModelInfo {
+    pub model_id: String,
+    pub model_sha: Option<String>,
+    pub max_input_length: usize,
+    pub embedding_dimension: usize,
 }
 
 impl InferenceEmbeddingProvider {
-    pub fn from_env() -> Result<Self, ReqwestError> {
-        let client = InferenceClient::from_env()?;
-        Ok(Self { client })
+    pub fn new(client: InferenceClient, model: ModelInfo) -> Self {
+        Self {
+            client,
+            model,
+            prompts: EmbeddingPrompts::default(),
+            dimensions: None,
+        }
+    }
+
+    pub fn with_prompts(mut self, prompts: EmbeddingPrompts) -> Self {
+        self.prompts = prompts;
+        self
+    }
+
+    /// Has the server shorten every vector to `dimension`; only models
+    /// trained for it (Matryoshka) accept this
+    pub fn with_dimensions(mut self, dimension: usize) -> Self {
+        self.dimensions = u32::try_from(dimension).ok();
+        self.model.embedding_dimension = dimension;
+        self
+    }
+
+    fn render(&self, purpose: EmbeddingPurpose, text: &str) -> String {
+        self.prompts.render(purpose, text)
+    }
+
+    /// Connects to the server configured in the environment and reads its model
+    pub async fn from_env() -> Result<Self, String> {
+        let client = InferenceClient::from_env()
+            .map_err(|e| format!("Failed to create embeddings client: {}", e))?;
+        Self::discover(client).await
+    }
+
+    /// Reads the model from TEI's `/info`; the dimension is not reported
+    /// there, so it is taken from one probe embedding
+    pub async fn discover(client: InferenceClient) -> Result<Self, String> {
+        let info = client.get_info().await.map_err(|e| {
+            format!(
+                "Could not read model info from {}: {:?}",
+                client.service_urls().join(", "),
+                e
+            )
+        })?;
+        // Failover is only safe between servers of the same model
+        for (url, endpoint_info) in client.endpoint_infos().await {
+            match endpoint_info {
+                Ok(endpoint_info) if endpoint_info.model_id != info.model_id => {
+                    return Err(format!(
+                        "{} serves {}, but other endpoints serve {}; every URL in \
+                         EMBEDDINGS_SERVICE_URL must serve the same model",
+                        url, endpoint_info.model_id, info.model_id
+                    ));
+                }
+                Ok(_) => {}
+                Err(e) => eprintln!("Embedding endpoint {} is not reachable yet: {:?}", url, e),
+            }
+        }
+        let probe = client
+            .get_embedding("dimension probe", None, None)
+            .await
+            .map_err(|e| {
+                format!(
+                    "Could not embed a probe text with {}: {:?}",
+                    info.model_id, e
+                )
+            })?;
+        let embedding_dimension = probe
+            .first()
+            .map(Vec::len)
+            .filter(|dimension| *dimension > 0)
+            .ok_or_else(|| format!("{} returned an empty probe embedding", info.model_id))?;
+
+        Ok(Self::new(
+            client,
+            ModelInfo {
+                model_id: info.model_id,
+                model_sha: info.model_sha,
+                max_input_length: info.max_input_length,
+                embedding_dimension,
+            },
+        ))
     }
 
     // Helper to convert f32 Vec to pgvector::Vector
@@ -225,16 +622,13 @@ impl EmbeddingProvider for InferenceEmbeddingProvider {
     ) -> Result<EmbeddingResponse, EmbeddingProviderError> {
         let response = self
             .client
-            .get_embedding(&request.text)
+            .get_embedding(
+                &self.render(request.purpose, &request.text),
+                self.prompts.prompt_name(request.purpose),
+                self.dimensions,
+            )
             .await
-            .map_err(|e| match e {
-                EmbeddingsError::RequestError(msg) => EmbeddingProviderError::NetworkError(msg),
-                EmbeddingsError::ParseError(msg) => EmbeddingProviderError::ApiError(msg),
-                EmbeddingsError::ApiError(msg) => EmbeddingProviderError::ApiError(msg),
-                // EmbeddingsError::MaxRetriesExceeded(_) => {
-                //     EmbeddingProviderError::ServiceUnavailable
-                // }
-            })?;
+            .map_err(EmbeddingProviderError::from)?;
 
         if response.is_empty() {
             return Err(EmbeddingProviderError::ApiError(
@@ -246,7 +640,7 @@ impl EmbeddingProvider for InferenceEmbeddingProvider {
             embedding: Self::to_pgvector(response[0].clone()),
             model_name: request
                 .model_name
-                .unwrap_or_else(|| "qwen-embedding".to_string()),
+                .unwrap_or_else(|| self.model.model_id.clone()),
             model_version: request.model_version,
             token_count: None, // Not provided by TEI API
         })
@@ -258,16 +652,17 @@ impl EmbeddingProvider for InferenceEmbeddingProvider {
     ) -> Result<BatchEmbeddingResponse, EmbeddingProviderError> {
         let response = self
             .client
-            .get_embeddings(&request.texts)
+            .get_embeddings(
+                &request
+                    .texts
+                    .iter()
+                    .map(|text| self.render(request.purpose, text))
+                    .collect(),
+                self.prompts.prompt_name(request.purpose),
+                self.dimensions,
+            )
             .await
-            .map_err(|e| match e {
-                EmbeddingsError::RequestError(msg) => EmbeddingProviderError::NetworkError(msg),
-                EmbeddingsError::ParseError(msg) => EmbeddingProviderError::ApiError(msg),
-                EmbeddingsError::ApiError(msg) => EmbeddingProviderError::ApiError(msg),
-                // EmbeddingsError::MaxRetriesExceeded(_) => {
-                //     EmbeddingProviderError::ServiceUnavailable
-                // }
-            })?;
+            .map_err(EmbeddingProviderError::from)?;
 
         let embeddings = response.into_iter().map(Self::to_pgvector).collect();
 
@@ -275,33 +670,368 @@ impl EmbeddingProvider for InferenceEmbeddingProvider {
             embeddings,
             model_name: request
                 .model_name
-                .unwrap_or_else(|| "qwen-embedding".to_string()),
+                .unwrap_or_else(|| self.model.model_id.clone()),
             model_version: request.model_version,
             total_tokens: None, // Not provided by TEI API
         })
     }
 
     async fn health_check(&self) -> Result<bool, EmbeddingProviderError> {
-        self.client.health_check().await.map_err(|e| match e {
-            EmbeddingsError::RequestError(msg) => EmbeddingProviderError::NetworkError(msg),
-            EmbeddingsError::ParseError(msg) => EmbeddingProviderError::ApiError(msg),
-            EmbeddingsError::ApiError(msg) => EmbeddingProviderError::ApiError(msg),
-            // EmbeddingsError::MaxRetriesExceeded(_) => EmbeddingProviderError::ServiceUnavailable,
-        })
+        self.client
+            .health_check()
+            .await
+            .map_err(EmbeddingProviderError::from)
     }
 
     fn model_info(&self) -> (String, Option<String>) {
-        (
-            "Qwen/Qwen3-Embedding-0.6B".to_string(),
-            Some("0.6B".to_string()),
-        )
+        (self.model.model_id.clone(), self.model.model_sha.clone())
     }
 
     fn max_input_length(&self) -> usize {
-        512 // Based on TEI info - this should be fetched from model info
+        self.model.max_input_length
     }
 
     fn embedding_dimension(&self) -> usize {
-        1024 // This should be fetched from model info, but 1024 is typical for this model
+        self.model.embedding_dimension
+    }
+
+    fn prompts(&self) -> EmbeddingPrompts {
+        self.prompts.clone()
+    }
+
+    fn endpoints(&self) -> Vec<EndpointStatus> {
+        self.client.endpoint_statuses()
+    }
+
+    fn unavailable_for(&self) -> Option<Duration> {
+        self.client.unavailable_for()
+    }
+}
+
+/// Sparse embeddings from a TEI server running a SPLADE-style model
+pub struct InferenceSparseEmbeddingProvider {
+    client: InferenceClient,
+    model_id: String,
+    model_sha: Option<String>,
+    dimension: usize,
+    batch_size: usize,
+    prompts: EmbeddingPrompts,
+}
+
+impl InferenceSparseEmbeddingProvider {
+    /// TEI does not report the vocabulary size, so `dimension` has to be
+    /// given; indices at or beyond it are rejected
+    pub async fn discover(client: InferenceClient, dimension: usize) -> Result<Self, String> {
+        let info = client.get_info().await.map_err(|e| {
+            format!(
+                "Could not read sparse model info from {}: {:?}",
+                client.service_urls().join(", "),
+                e
+            )
+        })?;
+
+        Ok(Self {
+            client,
+            model_id: info.model_id,
+            model_sha: info.model_sha,
+            dimension,
+            batch_size: 32,
+            prompts: EmbeddingPrompts::default(),
+        })
+    }
+
+    /// Texts per request; TEI rejects batches above its `max_client_batch_size`
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+
+    pub fn with_prompts(mut self, prompts: EmbeddingPrompts) -> Self {
+        self.prompts = prompts;
+        self
+    }
+
+    fn to_sparse_vector(
+        &self,
+        values: Vec<TeiSparseValue>,
+    ) -> Result<SparseVector, EmbeddingProviderError> {
+        let mut terms = Vec::with_capacity(values.len());
+        for TeiSparseValue { index, value } in values {
+            if index >= self.dimension {
+                return Err(EmbeddingProviderError::ApiError(format!(
+                    "{} returned term index {} beyond the configured vocabulary size {}; \
+                     set SPARSE_EMBEDDINGS_DIMENSION to the model's vocabulary size",
+                    self.model_id, index, self.dimension
+                )));
+            }
+            terms.push((index as i32, value));
+        }
+
+        Ok(SparseVector::from_map(
+            terms.iter().map(|(index, value)| (index, value)),
+            self.dimension as i32,
+        ))
+    }
+}
+
+#[async_trait]
+impl SparseEmbeddingProvider for InferenceSparseEmbeddingProvider {
+    async fn generate_sparse_embeddings(
+        &self,
+        request: BatchEmbeddingRequest,
+    ) -> Result<SparseEmbeddingResponse, EmbeddingProviderError> {
+        let texts: Vec<String> = request
+            .texts
+            .iter()
+            .map(|text| self.prompts.render(request.purpose, text))
+            .collect();
+
+        let mut embeddings = Vec::with_capacity(texts.len());
+        for batch in texts.chunks(self.batch_size) {
+            let response = self
+                .client
+                .get_sparse_embeddings(batch, self.prompts.prompt_name(request.purpose))
+                .await?;
+            if response.len() != batch.len() {
+                return Err(EmbeddingProviderError::ApiError(format!(
+                    "Expected {} sparse embeddings, got {}",
+                    batch.len(),
+                    response.len()
+                )));
+            }
+            for values in response {
+                embeddings.push(self.to_sparse_vector(values)?);
+            }
+        }
+
+        Ok(SparseEmbeddingResponse {
+            embeddings,
+            model_name: request.model_name.unwrap_or_else(|| self.model_id.clone()),
+            model_version: request.model_version,
+        })
+    }
+
+    fn model_info(&self) -> (String, Option<String>) {
+        (self.model_id.clone(), self.model_sha.clone())
+    }
+
+    fn dimension(&self) -> usize {
+        self.dimension
+    }
+}
+
+/// A TEI stand-in serving `/info` for `model_id` and `dimension`-sized
+/// vectors from `/embed`; returns its base URL
+#[cfg(test)]
+pub(crate) async fn spawn_mock_tei(model_id: &'static str, dimension: usize) -> String {
+    use axum::{Json, Router, routing::get, routing::post};
+    use serde_json::{Value, json};
+
+    let app =
+        Router::new()
+            .route(
+                "/info",
+                get(move || async move {
+                    Json(json!({ "model_id": model_id, "max_input_length": 512 }))
+                }),
+            )
+            .route(
+                "/embed",
+                post(move |Json(body): Json<Value>| async move {
+                    let inputs = body["inputs"].as_array().map_or(1, Vec::len);
+                    Json(json!(vec![vec![0.5; dimension]; inputs]))
+                }),
+            );
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        axum::serve(listener, app).await.unwrap();
+    });
+    format!("http://{}", addr)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::ports::embedding_provider::CircuitState;
+    use axum::{Json, Router, http::StatusCode as HttpStatus, routing::post};
+    use serde_json::{Value, json};
+
+    async fn spawn_mock_server(status: HttpStatus) -> String {
+        let app = Router::new().route(
+            "/embed",
+            post(move |Json(body): Json<Value>| async move {
+                if body["inputs"] == "too long" {
+                    return (
+                        HttpStatus::UNPROCESSABLE_ENTITY,
+                        Json(json!({ "error": "input too long", "error_type": "Validation" })),
+                    );
+                }
+                if let Some(inputs) = body["inputs"].as_array().filter(|inputs| inputs.len() > 2) {
+                    let error =
+                        format!("batch size {} > maximum allowed batch size 2", inputs.len());
+                    return (
+                        HttpStatus::UNPROCESSABLE_ENTITY,
+                        Json(json!({ "error": error, "error_type": "Validation" })),
+                    );
+                }
+                match status {
+                    HttpStatus::OK => (status, Json(json!([[0.5, 0.5]]))),
+                    _ => (
+                        status,
+                        Json(json!({ "error": "down", "error_type": "Backend" })),
+                    ),
+                }
+            }),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+        format!("http://{}", addr)
+    }
+
+    fn client(service_urls: Vec<String>) -> InferenceClient {
+        InferenceClient::new(EmbeddingsClientConfig {
+            service_urls,
+            max_retries: 0,
+            timeout_secs: 5,
+            backoff_factor: 1.0,
+            breaker: CircuitBreakerConfig {
+                failure_threshold: 1,
+                open_for: Duration::from_secs(60),
+            },
+        })
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_fails_over_and_opens_circuit() {
+        let failing = spawn_mock_server(HttpStatus::INTERNAL_SERVER_ERROR).await;
+        let healthy = spawn_mock_server(HttpStatus::OK).await;
+        let client = client(vec![failing.clone(), healthy]);
+
+        for _ in 0..3 {
+            let embeddings = client.get_embedding("text", None, None).await.unwrap();
+            assert_eq!(embeddings, vec![vec![0.5, 0.5]]);
+        }
+
+        let statuses = client.endpoint_statuses();
+        assert_eq!(statuses[0].url, failing);
+        assert_eq!(statuses[0].state, CircuitState::Open);
+        assert_eq!(statuses[0].failures, 1);
+        assert_eq!(statuses[1].state, CircuitState::Closed);
+        assert_eq!(statuses[1].requests, 3);
+        assert_eq!(client.unavailable_for(), None);
+    }
+
+    #[tokio::test]
+    async fn test_unavailable_once_all_circuits_open() {
+        let client = client(vec![
+            spawn_mock_server(HttpStatus::SERVICE_UNAVAILABLE).await,
+            spawn_mock_server(HttpStatus::BAD_GATEWAY).await,
+        ]);
+
+        let first = client.get_embedding("text", None, None).await;
+        assert!(matches!(first, Err(EmbeddingsError::ServerError(_))));
+
+        let second = client.get_embedding("text", None, None).await;
+        assert!(matches!(second, Err(EmbeddingsError::Unavailable)));
+        assert!(client.unavailable_for().is_some());
+    }
+
+    #[tokio::test]
+    async fn test_rejected_input_is_fatal() {
+        let client = client(vec![spawn_mock_server(HttpStatus::OK).await]);
+
+        let result = client.get_embedding("too long", None, None).await;
+
+        assert!(matches!(result, Err(EmbeddingsError::InputRejected(_))));
+        let texts = vec!["text".to_string(); 3];
+        let result = client.get_embeddings(&texts, None, None).await;
+        assert!(matches!(result, Err(EmbeddingsError::BatchTooLarge(_))));
+        let status = &client.endpoint_statuses()[0];
+        assert_eq!(status.state, CircuitState::Closed);
+        assert_eq!(status.failures, 0);
+    }
+
+    #[tokio::test]
+    async fn test_discover_reads_model_and_probes_dimension() {
+        let url = spawn_mock_tei("BAAI/bge-small-en-v1.5", 4).await;
+
+        let provider = InferenceEmbeddingProvider::discover(client(vec![url]))
+            .await
+            .unwrap();
+
+        assert_eq!(provider.model_info().0, "BAAI/bge-small-en-v1.5");
+        assert_eq!(provider.max_input_length(), 512);
+        assert_eq!(provider.embedding_dimension(), 4);
+    }
+
+    #[tokio::test]
+    async fn test_discover_refuses_endpoints_serving_different_models() {
+        let client = client(vec![
+            spawn_mock_tei("BAAI/bge-small-en-v1.5", 4).await,
+            spawn_mock_tei("intfloat/e5-small-v2", 4).await,
+        ]);
+
+        let result = InferenceEmbeddingProvider::discover(client).await;
+
+        assert!(result.is_err_and(|e| e.contains("must serve the same model")));
+    }
+
+    #[tokio::test]
+    async fn test_sparse_embeddings() {
+        let app = Router::new()
+            .route(
+                "/info",
+                axum::routing::get(|| async {
+                    Json(json!({ "model_id": "naver/splade-v3", "max_input_length": 512 }))
+                }),
+            )
+            .route(
+                "/embed_sparse",
+                post(|Json(body): Json<Value>| async move {
+                    let index = if body["inputs"][0] == "out of range" {
+                        12
+                    } else {
+                        7
+                    };
+                    Json(json!([[{ "index": 2, "value": 0.25 }, { "index": index, "value": 1.5 }]]))
+                }),
+            );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+
+        let provider = InferenceSparseEmbeddingProvider::discover(
+            client(vec![format!("http://{}", addr)]),
+            10,
+        )
+        .await
+        .unwrap();
+        let request = |text: &str| BatchEmbeddingRequest {
+            texts: vec![text.to_string()],
+            purpose: EmbeddingPurpose::Query,
+            model_name: None,
+            model_version: None,
+        };
+
+        let response = provider
+            .generate_sparse_embeddings(request("rust"))
+            .await
+            .unwrap();
+        assert_eq!(response.model_name, "naver/splade-v3");
+        assert_eq!(response.embeddings[0].indices(), &[2, 7]);
+        assert_eq!(response.embeddings[0].values(), &[0.25, 1.5]);
+        assert_eq!(response.embeddings[0].dimensions(), 10);
+
+        let result = provider
+            .generate_sparse_embeddings(request("out of range"))
+            .await;
+        assert!(matches!(result, Err(EmbeddingProviderError::ApiError(_))));
     }
 }
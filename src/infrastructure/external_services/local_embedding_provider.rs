@@ -0,0 +1,277 @@
+use async_trait::async_trait;
+use candle_core::{DType, Device, Tensor};
+use candle_nn::VarBuilder;
+use candle_transformers::models::bert::{BertModel, Config, DTYPE};
+use pgvector::Vector;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tokenizers::{PaddingParams, Tokenizer, TruncationParams};
+
+use crate::application::ports::embedding_provider::{
+    BatchEmbeddingRequest, BatchEmbeddingResponse, EmbeddingProvider, EmbeddingProviderError,
+    EmbeddingRequest, EmbeddingResponse,
+};
+use crate::domain::value_objects::{EmbeddingPrompts, EmbeddingPurpose};
+
+/// A sentence-transformer checkpoint loaded from a directory holding
+/// `config.json`, `tokenizer.json` and `model.safetensors`
+struct LocalModel {
+    model: BertModel,
+    tokenizer: Tokenizer,
+    device: Device,
+}
+
+impl LocalModel {
+    fn load(model_dir: &Path) -> Result<(Self, Config), String> {
+        let config_path = model_dir.join("config.json");
+        let config: Config = serde_json::from_str(
+            &std::fs::read_to_string(&config_path)
+                .map_err(|e| format!("Failed to read {}: {}", config_path.display(), e))?,
+        )
+        .map_err(|e| format!("Invalid model config {}: {}", config_path.display(), e))?;
+
+        let mut tokenizer = Tokenizer::from_file(model_dir.join("tokenizer.json"))
+            .map_err(|e| format!("Failed to load tokenizer: {}", e))?;
+        tokenizer.with_padding(Some(PaddingParams::default()));
+        tokenizer
+            .with_truncation(Some(TruncationParams {
+                max_length: config.max_position_embeddings,
+                ..Default::default()
+            }))
+            .map_err(|e| format!("Failed to configure truncation: {}", e))?;
+
+        let device = Device::Cpu;
+        let weights = model_dir.join("model.safetensors");
+        // Safety: the weights file is memory-mapped read-only and not modified while loaded
+        let vb = unsafe { VarBuilder::from_mmaped_safetensors(&[weights], DTYPE, &device) }
+            .map_err(|e| format!("Failed to load model weights: {}", e))?;
+        let model =
+            BertModel::load(vb, &config).map_err(|e| format!("Failed to build model: {}", e))?;
+
+        Ok((
+            Self {
+                model,
+                tokenizer,
+                device,
+            },
+            config,
+        ))
+    }
+
+    /// Embeds one batch; returns the vectors and the number of input tokens
+    fn embed(&self, texts: Vec<String>) -> Result<(Vec<Vec<f32>>, usize), String> {
+        let encodings = self
+            .tokenizer
+            .encode_batch(texts, true)
+            .map_err(|e| format!("Tokenization failed: {}", e))?;
+
+        let token_count = encodings
+            .iter()
+            .map(|encoding| encoding.get_attention_mask().iter().sum::<u32>() as usize)
+            .sum();
+
+        let to_tensor = |rows: Vec<&[u32]>| -> candle_core::Result<Tensor> {
+            let rows = rows
+                .into_iter()
+                .map(|row| Tensor::new(row, &self.device))
+                .collect::<candle_core::Result<Vec<_>>>()?;
+            Tensor::stack(&rows, 0)
+        };
+
+        let run = || -> candle_core::Result<Vec<Vec<f32>>> {
+            let input_ids = to_tensor(encodings.iter().map(|e| e.get_ids()).collect())?;
+            let attention_mask =
+                to_tensor(encodings.iter().map(|e| e.get_attention_mask()).collect())?;
+            let token_type_ids = input_ids.zeros_like()?;
+
+            let hidden = self
+                .model
+                .forward(&input_ids, &token_type_ids, Some(&attention_mask))?;
+            mean_pool_normalized(&hidden, &attention_mask)?.to_vec2::<f32>()
+        };
+
+        let vectors = run().map_err(|e| format!("Model inference failed: {}", e))?;
+        Ok((vectors, token_count))
+    }
+}
+
+/// Averages the token vectors of each sequence, ignoring padding, and scales
+/// the result to unit length
+fn mean_pool_normalized(hidden: &Tensor, attention_mask: &Tensor) -> candle_core::Result<Tensor> {
+    let mask = attention_mask.to_dtype(DType::F32)?.unsqueeze(2)?;
+    let summed = hidden.broadcast_mul(&mask)?.sum(1)?;
+    let counts = mask.sum(1)?.clamp(1e-9f32, f32::MAX)?;
+    let pooled = summed.broadcast_div(&counts)?;
+
+    let norms = pooled
+        .sqr()?
+        .sum_keepdim(1)?
+        .sqrt()?
+        .clamp(1e-12f32, f32::MAX)?;
+    pooled.broadcast_div(&norms)
+}
+
+/// Runs a BERT-family embedding model on the CPU inside the process; model
+/// calls go to the blocking thread pool so they do not stall the runtime
+pub struct LocalEmbeddingProvider {
+    model: Arc<LocalModel>,
+    model_name: String,
+    embedding_dimension: usize,
+    max_input_length: usize,
+    batch_size: usize,
+    prompts: EmbeddingPrompts,
+}
+
+impl LocalEmbeddingProvider {
+    pub fn load(model_dir: &Path) -> Result<Self, String> {
+        let (model, config) = LocalModel::load(model_dir)?;
+        let model_name = model_dir
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_else(|| "local".to_string());
+
+        Ok(Self {
+            model: Arc::new(model),
+            model_name,
+            embedding_dimension: config.hidden_size,
+            max_input_length: config.max_position_embeddings,
+            batch_size: 16,
+            prompts: EmbeddingPrompts::default(),
+        })
+    }
+
+    pub fn from_env() -> Result<Self, String> {
+        let model_dir = std::env::var("LOCAL_EMBEDDING_MODEL_DIR")
+            .map(PathBuf::from)
+            .map_err(|_| "LOCAL_EMBEDDING_MODEL_DIR is not set".to_string())?;
+        let mut provider = Self::load(&model_dir)?;
+        if let Some(batch_size) = std::env::var("LOCAL_EMBEDDING_BATCH_SIZE")
+            .ok()
+            .and_then(|s| s.parse().ok())
+        {
+            provider = provider.with_batch_size(batch_size);
+        }
+        Ok(provider)
+    }
+
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+
+    /// Only templates apply; the model runs without sentence-transformers prompts
+    pub fn with_prompts(mut self, prompts: EmbeddingPrompts) -> Self {
+        self.prompts = prompts;
+        self
+    }
+
+    async fn embed(
+        &self,
+        texts: Vec<String>,
+        purpose: EmbeddingPurpose,
+    ) -> Result<(Vec<Vector>, usize), EmbeddingProviderError> {
+        if texts.is_empty() {
+            return Err(EmbeddingProviderError::InvalidInput(
+                "No texts to embed".to_string(),
+            ));
+        }
+        let texts: Vec<String> = texts
+            .iter()
+            .map(|text| self.prompts.render(purpose, text))
+            .collect();
+
+        let model = self.model.clone();
+        let batch_size = self.batch_size;
+        tokio::task::spawn_blocking(move || {
+            let mut vectors = Vec::with_capacity(texts.len());
+            let mut token_count = 0;
+            for batch in texts.chunks(batch_size) {
+                let (batch_vectors, batch_tokens) = model.embed(batch.to_vec())?;
+                vectors.extend(batch_vectors.into_iter().map(Vector::from));
+                token_count += batch_tokens;
+            }
+            Ok((vectors, token_count))
+        })
+        .await
+        .map_err(|e| EmbeddingProviderError::ApiError(format!("Embedding task failed: {}", e)))?
+        .map_err(EmbeddingProviderError::ApiError)
+    }
+}
+
+#[async_trait]
+impl EmbeddingProvider for LocalEmbeddingProvider {
+    async fn generate_embedding(
+        &self,
+        request: EmbeddingRequest,
+    ) -> Result<EmbeddingResponse, EmbeddingProviderError> {
+        let (vectors, token_count) = self.embed(vec![request.text], request.purpose).await?;
+        let embedding = vectors.into_iter().next().ok_or_else(|| {
+            EmbeddingProviderError::ApiError("No embeddings returned".to_string())
+        })?;
+
+        Ok(EmbeddingResponse {
+            embedding,
+            model_name: self.model_name.clone(),
+            model_version: request.model_version,
+            token_count: Some(token_count as i32),
+        })
+    }
+
+    async fn generate_embeddings(
+        &self,
+        request: BatchEmbeddingRequest,
+    ) -> Result<BatchEmbeddingResponse, EmbeddingProviderError> {
+        let (embeddings, token_count) = self.embed(request.texts, request.purpose).await?;
+
+        Ok(BatchEmbeddingResponse {
+            embeddings,
+            model_name: self.model_name.clone(),
+            model_version: request.model_version,
+            total_tokens: Some(token_count as i32),
+        })
+    }
+
+    async fn health_check(&self) -> Result<bool, EmbeddingProviderError> {
+        Ok(true)
+    }
+
+    fn model_info(&self) -> (String, Option<String>) {
+        (self.model_name.clone(), None)
+    }
+
+    fn max_input_length(&self) -> usize {
+        self.max_input_length
+    }
+
+    fn embedding_dimension(&self) -> usize {
+        self.embedding_dimension
+    }
+
+    fn prompts(&self) -> EmbeddingPrompts {
+        self.prompts.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mean_pool_skips_padding() {
+        let device = Device::Cpu;
+        // Two sequences of two tokens; the second token of the second one is padding
+        let hidden = Tensor::new(
+            &[[[3.0f32, 0.0], [1.0, 0.0]], [[0.0, 2.0], [100.0, 100.0]]],
+            &device,
+        )
+        .unwrap();
+        let mask = Tensor::new(&[[1u32, 1], [1, 0]], &device).unwrap();
+
+        let pooled = mean_pool_normalized(&hidden, &mask)
+            .unwrap()
+            .to_vec2::<f32>()
+            .unwrap();
+
+        assert_eq!(pooled, vec![vec![1.0, 0.0], vec![0.0, 1.0]]);
+    }
+}
@@ -0,0 +1,224 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::application::ports::embedding_provider::{
+    BatchEmbeddingRequest, BatchEmbeddingResponse, EmbeddingProvider, EmbeddingProviderError,
+    EmbeddingRequest, EmbeddingResponse, EndpointStatus,
+};
+use crate::domain::entities::Embedding;
+use crate::domain::value_objects::EmbeddingPrompts;
+
+/// Serves a Matryoshka-trained model at a reduced size. Providers that can
+/// ask the server for the shorter vector should be configured to do so; this
+/// layer truncates whatever comes back and re-normalises it either way. The
+/// vectors are stored as a model of their own, `<model>@<dimension>`, so
+/// they sit beside the full-size ones and can be derived from them.
+pub struct MatryoshkaEmbeddingProvider {
+    inner: Arc<dyn EmbeddingProvider>,
+    model_name: String,
+    dimension: usize,
+}
+
+impl MatryoshkaEmbeddingProvider {
+    /// `inner` reports its full size; `dimension` has to be smaller
+    pub fn new(
+        inner: Arc<dyn EmbeddingProvider>,
+        full_dimension: usize,
+        dimension: usize,
+    ) -> Result<Self, String> {
+        let (inner_name, _) = inner.model_info();
+        if dimension == 0 || dimension >= full_dimension {
+            return Err(format!(
+                "{} produces {}-dimensional vectors; a truncated dimension must be between 1 and {}",
+                inner_name,
+                full_dimension,
+                full_dimension - 1
+            ));
+        }
+
+        Ok(Self {
+            model_name: Self::model_name(&inner_name, dimension),
+            inner,
+            dimension,
+        })
+    }
+
+    /// Name the vectors of `model` truncated to `dimension` are stored under
+    pub fn model_name(model: &str, dimension: usize) -> String {
+        format!("{}@{}", model, dimension)
+    }
+
+    fn truncate(
+        &self,
+        vector: &pgvector::Vector,
+    ) -> Result<pgvector::Vector, EmbeddingProviderError> {
+        Embedding::truncate_vector(vector, self.dimension).map_err(|e| {
+            EmbeddingProviderError::ApiError(format!("{}: {}", self.inner.model_info().0, e))
+        })
+    }
+}
+
+#[async_trait]
+impl EmbeddingProvider for MatryoshkaEmbeddingProvider {
+    async fn generate_embedding(
+        &self,
+        request: EmbeddingRequest,
+    ) -> Result<EmbeddingResponse, EmbeddingProviderError> {
+        let (inner_name, _) = self.inner.model_info();
+        let mut response = self
+            .inner
+            .generate_embedding(EmbeddingRequest {
+                model_name: Some(inner_name),
+                ..request
+            })
+            .await?;
+
+        response.embedding = self.truncate(&response.embedding)?;
+        response.model_name = self.model_name.clone();
+        Ok(response)
+    }
+
+    async fn generate_embeddings(
+        &self,
+        request: BatchEmbeddingRequest,
+    ) -> Result<BatchEmbeddingResponse, EmbeddingProviderError> {
+        let (inner_name, _) = self.inner.model_info();
+        let mut response = self
+            .inner
+            .generate_embeddings(BatchEmbeddingRequest {
+                model_name: Some(inner_name),
+                ..request
+            })
+            .await?;
+
+        response.embeddings = response
+            .embeddings
+            .iter()
+            .map(|embedding| self.truncate(embedding))
+            .collect::<Result<_, _>>()?;
+        response.model_name = self.model_name.clone();
+        Ok(response)
+    }
+
+    async fn health_check(&self) -> Result<bool, EmbeddingProviderError> {
+        self.inner.health_check().await
+    }
+
+    fn model_info(&self) -> (String, Option<String>) {
+        (self.model_name.clone(), self.inner.model_info().1)
+    }
+
+    fn max_input_length(&self) -> usize {
+        self.inner.max_input_length()
+    }
+
+    fn embedding_dimension(&self) -> usize {
+        self.dimension
+    }
+
+    fn prompts(&self) -> EmbeddingPrompts {
+        self.inner.prompts()
+    }
+
+    fn endpoints(&self) -> Vec<EndpointStatus> {
+        self.inner.endpoints()
+    }
+
+    fn unavailable_for(&self) -> Option<Duration> {
+        self.inner.unavailable_for()
+    }
+
+    fn derived_from(&self) -> Option<String> {
+        Some(self.inner.model_info().0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::EmbeddingPurpose;
+    use pgvector::Vector;
+    use std::sync::Mutex;
+
+    /// Returns the same full-size vector for every text and records the
+    /// model names it was asked for
+    struct FullSizeProvider {
+        requested_models: Mutex<Vec<Option<String>>>,
+    }
+
+    #[async_trait]
+    impl EmbeddingProvider for FullSizeProvider {
+        async fn generate_embedding(
+            &self,
+            _request: EmbeddingRequest,
+        ) -> Result<EmbeddingResponse, EmbeddingProviderError> {
+            unimplemented!()
+        }
+
+        async fn generate_embeddings(
+            &self,
+            request: BatchEmbeddingRequest,
+        ) -> Result<BatchEmbeddingResponse, EmbeddingProviderError> {
+            self.requested_models
+                .lock()
+                .unwrap()
+                .push(request.model_name.clone());
+            Ok(BatchEmbeddingResponse {
+                embeddings: request
+                    .texts
+                    .iter()
+                    .map(|_| Vector::from(vec![3.0, 4.0, 12.0, 84.0]))
+                    .collect(),
+                model_name: "nomic-embed".to_string(),
+                model_version: None,
+                total_tokens: None,
+            })
+        }
+
+        async fn health_check(&self) -> Result<bool, EmbeddingProviderError> {
+            Ok(true)
+        }
+
+        fn model_info(&self) -> (String, Option<String>) {
+            ("nomic-embed".to_string(), None)
+        }
+
+        fn max_input_length(&self) -> usize {
+            512
+        }
+
+        fn embedding_dimension(&self) -> usize {
+            4
+        }
+    }
+
+    #[tokio::test]
+    async fn test_truncates_and_renames() {
+        let inner = Arc::new(FullSizeProvider {
+            requested_models: Mutex::new(Vec::new()),
+        });
+        let provider = MatryoshkaEmbeddingProvider::new(inner.clone(), 4, 2).unwrap();
+
+        let response = provider
+            .generate_embeddings(BatchEmbeddingRequest {
+                texts: vec!["a".to_string(), "b".to_string()],
+                purpose: EmbeddingPurpose::Document,
+                model_name: Some("nomic-embed@2".to_string()),
+                model_version: None,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(response.model_name, "nomic-embed@2");
+        assert_eq!(response.embeddings[1].as_slice(), &[0.6, 0.8]);
+        assert_eq!(provider.embedding_dimension(), 2);
+        assert_eq!(provider.derived_from().as_deref(), Some("nomic-embed"));
+        // The server is asked for its own model, not the stored name
+        assert_eq!(
+            *inner.requested_models.lock().unwrap(),
+            vec![Some("nomic-embed".to_string())]
+        );
+        assert!(MatryoshkaEmbeddingProvider::new(inner, 4, 4).is_err());
+    }
+}
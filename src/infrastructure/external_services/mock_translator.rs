@@ -0,0 +1,82 @@
+use async_trait::async_trait;
+use std::collections::HashMap;
+
+use crate::application::ports::translation_provider::{TranslationError, TranslationProvider};
+use crate::domain::value_objects::LanguageCode;
+
+/// Offline translator for development and tests. Known phrases come from a
+/// glossary; anything else is reported as unsupported, so searches keep the
+/// original query, which multilingual embedding models still handle
+/// reasonably.
+pub struct MockTranslationProvider {
+    glossary: HashMap<(String, String), String>,
+}
+
+impl MockTranslationProvider {
+    pub fn new() -> Self {
+        Self {
+            glossary: HashMap::new(),
+        }
+    }
+
+    #[cfg(test)]
+    pub fn with_translation(mut self, text: &str, target: &str, translation: &str) -> Self {
+        self.glossary.insert(
+            (text.trim().to_lowercase(), target.to_string()),
+            translation.to_string(),
+        );
+        self
+    }
+}
+
+impl Default for MockTranslationProvider {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl TranslationProvider for MockTranslationProvider {
+    async fn translate(
+        &self,
+        text: &str,
+        _source: Option<&LanguageCode>,
+        target: &LanguageCode,
+    ) -> Result<String, TranslationError> {
+        let key = (text.trim().to_lowercase(), target.as_str().to_string());
+        self.glossary
+            .get(&key)
+            .cloned()
+            .ok_or_else(|| TranslationError::UnsupportedLanguage(target.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_glossary_and_unsupported() {
+        let translator = MockTranslationProvider::new().with_translation(
+            "Apprentissage automatique",
+            "en",
+            "machine learning",
+        );
+        let english = LanguageCode::parse("en").unwrap();
+        let german = LanguageCode::parse("de").unwrap();
+
+        assert_eq!(
+            translator
+                .translate("apprentissage automatique", None, &english)
+                .await
+                .unwrap(),
+            "machine learning"
+        );
+        assert!(matches!(
+            translator
+                .translate("apprentissage automatique", None, &german)
+                .await,
+            Err(TranslationError::UnsupportedLanguage(_))
+        ));
+    }
+}
@@ -1,5 +1,28 @@
+pub mod batching_embedding_provider;
+pub mod chunking;
+pub mod circuit_breaker;
+pub mod content_sniffer;
 pub mod inference_client;
+#[cfg(feature = "local-embeddings")]
+pub mod local_embedding_provider;
+pub mod matryoshka_embedding_provider;
+pub mod mock_translator;
+pub mod openai_embedding_client;
 pub mod document_extractors;
+pub mod feed_fetcher;
+pub mod hash_embedding_provider;
 pub mod semantic_chunking;
+pub mod tokenization;
+pub mod whatlang_detector;
 
+pub use batching_embedding_provider::{BatchingEmbeddingProvider, EmbeddingBatchConfig};
+pub use content_sniffer::ContentSniffer;
+pub use feed_fetcher::HttpFeedFetcher;
+pub use hash_embedding_provider::HashEmbeddingProvider;
 pub use inference_client::InferenceEmbeddingProvider;
+#[cfg(feature = "local-embeddings")]
+pub use local_embedding_provider::LocalEmbeddingProvider;
+pub use matryoshka_embedding_provider::MatryoshkaEmbeddingProvider;
+pub use mock_translator::MockTranslationProvider;
+pub use openai_embedding_client::OpenAiEmbeddingProvider;
+pub use whatlang_detector::WhatlangDetector;
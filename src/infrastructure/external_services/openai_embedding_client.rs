@@ -0,0 +1,503 @@
+use async_trait::async_trait;
+use pgvector::Vector;
+use reqwest::{Client, Error as ReqwestError, StatusCode};
+use serde::{Deserialize, Serialize};
+use std::env;
+use std::time::Duration;
+
+use crate::application::ports::embedding_provider::{
+    BatchEmbeddingRequest, BatchEmbeddingResponse, EmbeddingProvider, EmbeddingProviderError,
+    EmbeddingRequest, EmbeddingResponse,
+};
+use crate::domain::value_objects::{EmbeddingPrompts, EmbeddingPurpose};
+use crate::infrastructure::external_services::batching_embedding_provider::retry_after;
+
+// OpenAI `/v1/embeddings` schema, also served by vLLM, LM Studio, Ollama and LiteLLM
+#[derive(Debug, Serialize)]
+pub struct OpenAiEmbeddingRequest {
+    pub model: String,
+    pub input: Vec<String>,
+    pub encoding_format: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dimensions: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct OpenAiEmbeddingResponse {
+    pub data: Vec<OpenAiEmbeddingData>,
+    pub model: Option<String>,
+    pub usage: Option<OpenAiUsage>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct OpenAiEmbeddingData {
+    pub embedding: Vec<f32>,
+    pub index: usize,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct OpenAiUsage {
+    pub prompt_tokens: Option<i32>,
+    pub total_tokens: Option<i32>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OpenAiErrorResponse {
+    error: OpenAiErrorBody,
+}
+
+#[derive(Debug, Deserialize)]
+struct OpenAiErrorBody {
+    message: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct OpenAiEmbeddingConfig {
+    /// Base URL including the version prefix, e.g. `http://localhost:8000/v1`
+    pub base_url: String,
+    pub api_key: Option<String>,
+    pub model: String,
+    /// Requested output size, for models that can shorten their vectors
+    pub dimensions: Option<u32>,
+    pub embedding_dimension: usize,
+    pub max_input_length: usize,
+    pub max_retries: u32,
+    pub timeout_secs: u64,
+    pub backoff_factor: f64,
+}
+
+impl OpenAiEmbeddingConfig {
+    pub fn from_env() -> Self {
+        let dimensions = env::var("OPENAI_EMBEDDINGS_DIMENSIONS")
+            .ok()
+            .and_then(|s| s.parse().ok());
+
+        Self {
+            base_url: env::var("OPENAI_EMBEDDINGS_URL")
+                .unwrap_or_else(|_| "http://localhost:8000/v1".to_string()),
+            api_key: env::var("OPENAI_API_KEY")
+                .ok()
+                .filter(|key| !key.is_empty()),
+            model: env::var("OPENAI_EMBEDDINGS_MODEL")
+                .unwrap_or_else(|_| "text-embedding-3-small".to_string()),
+            dimensions,
+            embedding_dimension: dimensions.map(|d| d as usize).unwrap_or(1024),
+            max_input_length: env::var("OPENAI_EMBEDDINGS_MAX_INPUT_LENGTH")
+                .ok()
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(8191),
+            max_retries: 3,
+            timeout_secs: 30,
+            backoff_factor: 1.5,
+        }
+    }
+}
+
+pub struct OpenAiEmbeddingProvider {
+    client: Client,
+    config: OpenAiEmbeddingConfig,
+    prompts: EmbeddingPrompts,
+}
+
+impl OpenAiEmbeddingProvider {
+    pub fn new(config: OpenAiEmbeddingConfig) -> Result<Self, ReqwestError> {
+        let client = Client::builder()
+            .timeout(Duration::from_secs(config.timeout_secs))
+            .build()?;
+
+        Ok(Self {
+            client,
+            config,
+            prompts: EmbeddingPrompts::default(),
+        })
+    }
+
+    /// The API has no notion of named prompts, so only templates apply
+    pub fn with_prompts(mut self, prompts: EmbeddingPrompts) -> Self {
+        self.prompts = prompts;
+        self
+    }
+
+    /// Asks the server for vectors shortened to `dimension`
+    pub fn with_dimensions(mut self, dimension: usize) -> Self {
+        self.config.dimensions = u32::try_from(dimension).ok();
+        self.config.embedding_dimension = dimension;
+        self
+    }
+
+    pub fn from_env() -> Result<Self, ReqwestError> {
+        Self::new(OpenAiEmbeddingConfig::from_env())
+    }
+
+    /// Servers do not report the vector size, so unless `dimensions` pins
+    /// it, one probe embedding determines it
+    pub async fn discover(mut self) -> Result<Self, String> {
+        if self.config.dimensions.is_some() {
+            return Ok(self);
+        }
+
+        let probe = self
+            .embed(
+                vec!["dimension probe".to_string()],
+                None,
+                EmbeddingPurpose::Document,
+            )
+            .await
+            .map_err(|e| {
+                format!(
+                    "Could not embed a probe text with {} at {}: {}",
+                    self.config.model, self.config.base_url, e
+                )
+            })?;
+        self.config.embedding_dimension = probe
+            .data
+            .first()
+            .map(|data| data.embedding.len())
+            .filter(|dimension| *dimension > 0)
+            .ok_or_else(|| format!("{} returned an empty probe embedding", self.config.model))?;
+
+        Ok(self)
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}/{}", self.config.base_url.trim_end_matches('/'), path)
+    }
+
+    fn authorize(&self, builder: reqwest::RequestBuilder) -> reqwest::RequestBuilder {
+        match &self.config.api_key {
+            Some(key) => builder.bearer_auth(key),
+            None => builder,
+        }
+    }
+
+    async fn embed(
+        &self,
+        texts: Vec<String>,
+        model: Option<String>,
+        purpose: EmbeddingPurpose,
+    ) -> Result<OpenAiEmbeddingResponse, EmbeddingProviderError> {
+        if texts.is_empty() {
+            return Err(EmbeddingProviderError::InvalidInput(
+                "No texts to embed".to_string(),
+            ));
+        }
+
+        let request = OpenAiEmbeddingRequest {
+            model: model.unwrap_or_else(|| self.config.model.clone()),
+            input: texts
+                .iter()
+                .map(|text| self.prompts.render(purpose, text))
+                .collect(),
+            encoding_format: "float".to_string(),
+            dimensions: self.config.dimensions,
+        };
+
+        let mut attempts = 0;
+        loop {
+            attempts += 1;
+
+            match self.execute_embed_request(&request).await {
+                Ok(mut response) => {
+                    // Servers may answer out of order; `index` is authoritative
+                    response.data.sort_by_key(|data| data.index);
+                    if response.data.len() != request.input.len() {
+                        return Err(EmbeddingProviderError::ApiError(format!(
+                            "Expected {} embeddings, got {}",
+                            request.input.len(),
+                            response.data.len()
+                        )));
+                    }
+                    return Ok(response);
+                }
+                // Oversized batches are split and rate limits waited out by
+                // the batching layer, which sees every request to the server
+                Err(
+                    e @ (EmbeddingProviderError::InvalidInput(_)
+                    | EmbeddingProviderError::BatchTooLarge(_)
+                    | EmbeddingProviderError::RateLimitExceeded(_)),
+                ) => {
+                    return Err(e);
+                }
+                Err(e) => {
+                    if attempts > self.config.max_retries {
+                        return Err(e);
+                    }
+
+                    let backoff_time = Duration::from_millis(
+                        (self.config.backoff_factor.powi(attempts as i32 - 1) * 1000.0) as u64,
+                    );
+                    tokio::time::sleep(backoff_time).await;
+                }
+            }
+        }
+    }
+
+    async fn execute_embed_request(
+        &self,
+        request: &OpenAiEmbeddingRequest,
+    ) -> Result<OpenAiEmbeddingResponse, EmbeddingProviderError> {
+        let response = self
+            .authorize(self.client.post(self.url("embeddings")))
+            .json(request)
+            .send()
+            .await
+            .map_err(|e| EmbeddingProviderError::NetworkError(e.to_string()))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let retry_after = retry_after(response.headers());
+            let message = match response.json::<OpenAiErrorResponse>().await {
+                Ok(error_response) => error_response.error.message,
+                Err(_) => format!("HTTP error: {}", status),
+            };
+            return Err(match status {
+                StatusCode::TOO_MANY_REQUESTS => {
+                    EmbeddingProviderError::RateLimitExceeded(retry_after)
+                }
+                StatusCode::SERVICE_UNAVAILABLE => EmbeddingProviderError::ServiceUnavailable,
+                StatusCode::PAYLOAD_TOO_LARGE => EmbeddingProviderError::BatchTooLarge(message),
+                StatusCode::BAD_REQUEST => EmbeddingProviderError::InvalidInput(message),
+                _ => EmbeddingProviderError::ApiError(message),
+            });
+        }
+
+        response
+            .json::<OpenAiEmbeddingResponse>()
+            .await
+            .map_err(|e| EmbeddingProviderError::ApiError(e.to_string()))
+    }
+}
+
+#[async_trait]
+impl EmbeddingProvider for OpenAiEmbeddingProvider {
+    async fn generate_embedding(
+        &self,
+        request: EmbeddingRequest,
+    ) -> Result<EmbeddingResponse, EmbeddingProviderError> {
+        let response = self
+            .embed(vec![request.text], request.model_name, request.purpose)
+            .await?;
+        let token_count = response
+            .usage
+            .as_ref()
+            .and_then(|usage| usage.prompt_tokens.or(usage.total_tokens));
+        let data = response.data.into_iter().next().ok_or_else(|| {
+            EmbeddingProviderError::ApiError("No embeddings returned".to_string())
+        })?;
+
+        Ok(EmbeddingResponse {
+            embedding: Vector::from(data.embedding),
+            model_name: response.model.unwrap_or_else(|| self.config.model.clone()),
+            model_version: request.model_version,
+            token_count,
+        })
+    }
+
+    async fn generate_embeddings(
+        &self,
+        request: BatchEmbeddingRequest,
+    ) -> Result<BatchEmbeddingResponse, EmbeddingProviderError> {
+        let response = self
+            .embed(request.texts, request.model_name, request.purpose)
+            .await?;
+
+        Ok(BatchEmbeddingResponse {
+            embeddings: response
+                .data
+                .into_iter()
+                .map(|data| Vector::from(data.embedding))
+                .collect(),
+            model_name: response.model.unwrap_or_else(|| self.config.model.clone()),
+            model_version: request.model_version,
+            total_tokens: response
+                .usage
+                .and_then(|usage| usage.total_tokens.or(usage.prompt_tokens)),
+        })
+    }
+
+    async fn health_check(&self) -> Result<bool, EmbeddingProviderError> {
+        let response = self
+            .authorize(self.client.get(self.url("models")))
+            .send()
+            .await
+            .map_err(|e| EmbeddingProviderError::NetworkError(e.to_string()))?;
+
+        Ok(response.status().is_success())
+    }
+
+    fn model_info(&self) -> (String, Option<String>) {
+        (self.config.model.clone(), None)
+    }
+
+    fn max_input_length(&self) -> usize {
+        self.config.max_input_length
+    }
+
+    fn embedding_dimension(&self) -> usize {
+        self.config.embedding_dimension
+    }
+
+    fn prompts(&self) -> EmbeddingPrompts {
+        self.prompts.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{
+        Json, Router,
+        http::{HeaderMap, header},
+        response::{IntoResponse, Response},
+        routing::post,
+    };
+    use serde_json::{Value, json};
+
+    async fn mock_embeddings(headers: HeaderMap, Json(body): Json<Value>) -> Response {
+        if body["input"][0] == "rate limited" {
+            return (
+                StatusCode::TOO_MANY_REQUESTS,
+                [(header::RETRY_AFTER, "2")],
+                Json(json!({ "error": { "message": "slow down" } })),
+            )
+                .into_response();
+        }
+
+        assert_eq!(body["model"], "mock-embed");
+        assert_eq!(body["dimensions"], 3);
+        assert_eq!(body["encoding_format"], "float");
+        assert_eq!(headers["authorization"], "Bearer secret");
+
+        // Reply in reverse order to check that `index` is honoured; inputs
+        // carrying the query instruction are marked by a negative component
+        let data: Vec<Value> = body["input"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .enumerate()
+            .rev()
+            .map(|(index, input)| {
+                let marker = if input.as_str().unwrap().starts_with("query: ") { -1.0 } else { 0.5 };
+                json!({ "object": "embedding", "index": index, "embedding": [index as f32, marker, 1.0] })
+            })
+            .collect();
+
+        (
+            StatusCode::OK,
+            Json(json!({
+                "object": "list",
+                "data": data,
+                "model": "mock-embed",
+                "usage": { "prompt_tokens": 7, "total_tokens": 7 }
+            })),
+        )
+            .into_response()
+    }
+
+    async fn spawn_mock_server() -> String {
+        let app = Router::new().route("/v1/embeddings", post(mock_embeddings));
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+        format!("http://{}/v1", addr)
+    }
+
+    fn provider(base_url: String) -> OpenAiEmbeddingProvider {
+        OpenAiEmbeddingProvider::new(OpenAiEmbeddingConfig {
+            base_url,
+            api_key: Some("secret".to_string()),
+            model: "mock-embed".to_string(),
+            dimensions: Some(3),
+            embedding_dimension: 3,
+            max_input_length: 512,
+            max_retries: 0,
+            timeout_secs: 5,
+            backoff_factor: 1.0,
+        })
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_batch_against_mock_server() {
+        let provider = provider(spawn_mock_server().await);
+
+        let response = provider
+            .generate_embeddings(BatchEmbeddingRequest {
+                texts: vec!["first".to_string(), "second".to_string()],
+                purpose: EmbeddingPurpose::Document,
+                model_name: None,
+                model_version: None,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(response.embeddings.len(), 2);
+        assert_eq!(response.embeddings[0].as_slice(), &[0.0, 0.5, 1.0]);
+        assert_eq!(response.embeddings[1].as_slice(), &[1.0, 0.5, 1.0]);
+        assert_eq!(response.model_name, "mock-embed");
+        assert_eq!(response.total_tokens, Some(7));
+
+        let single = provider
+            .generate_embedding(EmbeddingRequest {
+                text: "only".to_string(),
+                purpose: EmbeddingPurpose::Document,
+                model_name: None,
+                model_version: None,
+            })
+            .await
+            .unwrap();
+        assert_eq!(single.token_count, Some(7));
+    }
+
+    #[tokio::test]
+    async fn test_query_template_is_applied() {
+        let provider = provider(spawn_mock_server().await).with_prompts(EmbeddingPrompts {
+            query_template: Some("query: {text}".to_string()),
+            ..Default::default()
+        });
+
+        let query = provider
+            .generate_embedding(EmbeddingRequest {
+                text: "what is rust".to_string(),
+                purpose: EmbeddingPurpose::Query,
+                model_name: None,
+                model_version: None,
+            })
+            .await
+            .unwrap();
+        assert_eq!(query.embedding.as_slice(), &[0.0, -1.0, 1.0]);
+
+        let document = provider
+            .generate_embedding(EmbeddingRequest {
+                text: "Rust is a language.".to_string(),
+                purpose: EmbeddingPurpose::Document,
+                model_name: None,
+                model_version: None,
+            })
+            .await
+            .unwrap();
+        assert_eq!(document.embedding.as_slice(), &[0.0, 0.5, 1.0]);
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_is_mapped() {
+        let provider = provider(spawn_mock_server().await);
+
+        let result = provider
+            .generate_embedding(EmbeddingRequest {
+                text: "rate limited".to_string(),
+                purpose: EmbeddingPurpose::Query,
+                model_name: None,
+                model_version: None,
+            })
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(EmbeddingProviderError::RateLimitExceeded(Some(retry_after)))
+                if retry_after == Duration::from_secs(2)
+        ));
+    }
+}
@@ -0,0 +1,42 @@
+use crate::application::ports::Tokenizer;
+
+/// Counts characters; used to size chunks when a request asks for
+/// character-based chunk sizes
+#[derive(Debug, Clone, Default)]
+pub struct CharacterTokenizer;
+
+impl CharacterTokenizer {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Tokenizer for CharacterTokenizer {
+    fn token_spans(&self, text: &str) -> Vec<(usize, usize)> {
+        text.char_indices()
+            .map(|(index, c)| (index, index + c.len_utf8()))
+            .collect()
+    }
+
+    fn count_tokens(&self, text: &str) -> usize {
+        text.chars().count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spans_follow_characters() {
+        let tokenizer = CharacterTokenizer::new();
+        let text = "hé y";
+
+        let spans = tokenizer.token_spans(text);
+
+        let chars: Vec<&str> = spans.iter().map(|(s, e)| &text[*s..*e]).collect();
+        assert_eq!(chars, vec!["h", "é", " ", "y"]);
+        assert_eq!(tokenizer.count_tokens(text), 4);
+        assert_eq!(tokenizer.special_token_overhead(), 0);
+    }
+}
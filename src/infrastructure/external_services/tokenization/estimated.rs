@@ -0,0 +1,56 @@
+use crate::application::ports::Tokenizer;
+use crate::infrastructure::external_services::tokenization::WhitespaceTokenizer;
+
+/// Special tokens assumed around every input, e.g. `[CLS]` and `[SEP]`
+const SPECIAL_TOKENS: usize = 2;
+
+/// Stands in for the embedding model's tokenizer when no `tokenizer.json`
+/// is available. Subword vocabularies split prose into more tokens than it
+/// has words, so each word counts as one and a half tokens, rounded up;
+/// chunks sized with it err on the small side rather than being rejected.
+#[derive(Debug, Clone, Default)]
+pub struct EstimatedTokenizer {
+    words: WhitespaceTokenizer,
+}
+
+impl EstimatedTokenizer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Tokenizer for EstimatedTokenizer {
+    fn token_spans(&self, text: &str) -> Vec<(usize, usize)> {
+        self.words.token_spans(text)
+    }
+
+    fn count_tokens(&self, text: &str) -> usize {
+        (self.words.count_tokens(text) * 3).div_ceil(2) + SPECIAL_TOKENS
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::external_services::chunking::token_windows;
+
+    #[test]
+    fn test_counts_exceed_words() {
+        let tokenizer = EstimatedTokenizer::new();
+
+        assert_eq!(tokenizer.count_tokens("one two three"), 7);
+        assert_eq!(tokenizer.special_token_overhead(), SPECIAL_TOKENS);
+        assert_eq!(tokenizer.token_spans("one two").len(), 2);
+    }
+
+    #[test]
+    fn test_windows_fit_the_budget() {
+        let tokenizer = EstimatedTokenizer::new();
+        let text = "lorem ipsum dolor sit amet ".repeat(40);
+
+        let windows = token_windows(&text, &tokenizer, 32, 0);
+
+        assert!(windows.len() > 1);
+        assert!(windows.iter().all(|w| tokenizer.count_tokens(w) <= 32));
+    }
+}
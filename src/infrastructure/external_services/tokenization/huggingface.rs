@@ -0,0 +1,110 @@
+use std::path::Path;
+
+use crate::application::ports::Tokenizer;
+
+/// Wraps a Hugging Face `tokenizer.json`, the same file the embedding server
+/// loads, so token counts match what the model sees
+pub struct HuggingFaceTokenizer {
+    inner: tokenizers::Tokenizer,
+}
+
+impl HuggingFaceTokenizer {
+    pub fn from_file(path: &Path) -> Result<Self, String> {
+        let inner = tokenizers::Tokenizer::from_file(path)
+            .map_err(|e| format!("Failed to load tokenizer {}: {}", path.display(), e))?;
+        Self::from_tokenizer(inner)
+    }
+
+    pub fn from_tokenizer(mut inner: tokenizers::Tokenizer) -> Result<Self, String> {
+        // Counts must reflect the full text, not what the file would truncate or pad it to
+        inner
+            .with_truncation(None)
+            .map_err(|e| format!("Failed to disable truncation: {}", e))?;
+        inner.with_padding(None);
+
+        Ok(Self { inner })
+    }
+}
+
+impl Tokenizer for HuggingFaceTokenizer {
+    fn token_spans(&self, text: &str) -> Vec<(usize, usize)> {
+        match self.inner.encode(text, false) {
+            Ok(encoding) => encoding
+                .get_offsets()
+                .iter()
+                .zip(encoding.get_special_tokens_mask())
+                .filter(|(_, special)| **special == 0)
+                .map(|(offsets, _)| *offsets)
+                .collect(),
+            Err(e) => {
+                eprintln!("Tokenization failed: {}", e);
+                Vec::new()
+            }
+        }
+    }
+
+    fn count_tokens(&self, text: &str) -> usize {
+        match self.inner.encode(text, true) {
+            Ok(encoding) => encoding.len(),
+            Err(e) => {
+                // Overestimate so the chunk is rejected rather than silently truncated
+                eprintln!("Tokenization failed: {}", e);
+                usize::MAX
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TOKENIZER_JSON: &str = r#"{
+        "version": "1.0",
+        "truncation": { "direction": "Right", "max_length": 3, "strategy": "LongestFirst", "stride": 0 },
+        "padding": null,
+        "added_tokens": [
+            { "id": 0, "content": "[UNK]", "single_word": false, "lstrip": false, "rstrip": false, "normalized": false, "special": true },
+            { "id": 1, "content": "[CLS]", "single_word": false, "lstrip": false, "rstrip": false, "normalized": false, "special": true },
+            { "id": 2, "content": "[SEP]", "single_word": false, "lstrip": false, "rstrip": false, "normalized": false, "special": true }
+        ],
+        "normalizer": null,
+        "pre_tokenizer": { "type": "Whitespace" },
+        "post_processor": {
+            "type": "TemplateProcessing",
+            "single": [
+                { "SpecialToken": { "id": "[CLS]", "type_id": 0 } },
+                { "Sequence": { "id": "A", "type_id": 0 } },
+                { "SpecialToken": { "id": "[SEP]", "type_id": 0 } }
+            ],
+            "pair": [
+                { "Sequence": { "id": "A", "type_id": 0 } },
+                { "Sequence": { "id": "B", "type_id": 1 } }
+            ],
+            "special_tokens": {
+                "[CLS]": { "id": "[CLS]", "ids": [1], "tokens": ["[CLS]"] },
+                "[SEP]": { "id": "[SEP]", "ids": [2], "tokens": ["[SEP]"] }
+            }
+        },
+        "decoder": null,
+        "model": {
+            "type": "WordLevel",
+            "vocab": { "[UNK]": 0, "[CLS]": 1, "[SEP]": 2, "hello": 3, "world": 4, ",": 5 },
+            "unk_token": "[UNK]"
+        }
+    }"#;
+
+    #[test]
+    fn test_counts_include_special_tokens_without_truncation() {
+        let inner: tokenizers::Tokenizer = TOKENIZER_JSON.parse().unwrap();
+        let tokenizer = HuggingFaceTokenizer::from_tokenizer(inner).unwrap();
+        let text = "hello, world again and again";
+
+        let spans = tokenizer.token_spans(text);
+
+        assert_eq!(spans.len(), 6);
+        assert_eq!(&text[spans[1].0..spans[1].1], ",");
+        assert_eq!(tokenizer.count_tokens(text), 8);
+        assert_eq!(tokenizer.special_token_overhead(), 2);
+    }
+}
@@ -0,0 +1,9 @@
+pub mod character;
+pub mod estimated;
+pub mod huggingface;
+pub mod whitespace;
+
+pub use character::CharacterTokenizer;
+pub use estimated::EstimatedTokenizer;
+pub use huggingface::HuggingFaceTokenizer;
+pub use whitespace::WhitespaceTokenizer;
@@ -0,0 +1,57 @@
+use crate::application::ports::Tokenizer;
+
+/// Counts whitespace-separated words; used to size chunks when a request
+/// asks for word-based chunk sizes
+#[derive(Debug, Clone, Default)]
+pub struct WhitespaceTokenizer;
+
+impl WhitespaceTokenizer {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Tokenizer for WhitespaceTokenizer {
+    fn token_spans(&self, text: &str) -> Vec<(usize, usize)> {
+        let mut spans = Vec::new();
+        let mut start = None;
+
+        for (index, c) in text.char_indices() {
+            match (c.is_whitespace(), start) {
+                (true, Some(s)) => {
+                    spans.push((s, index));
+                    start = None;
+                }
+                (false, None) => start = Some(index),
+                _ => {}
+            }
+        }
+        if let Some(s) = start {
+            spans.push((s, text.len()));
+        }
+
+        spans
+    }
+
+    fn count_tokens(&self, text: &str) -> usize {
+        text.split_whitespace().count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spans_follow_words() {
+        let tokenizer = WhitespaceTokenizer::new();
+        let text = "  héllo  wide\nworld ";
+
+        let spans = tokenizer.token_spans(text);
+
+        let words: Vec<&str> = spans.iter().map(|(s, e)| &text[*s..*e]).collect();
+        assert_eq!(words, vec!["héllo", "wide", "world"]);
+        assert_eq!(tokenizer.count_tokens(text), 3);
+        assert_eq!(tokenizer.special_token_overhead(), 0);
+    }
+}
@@ -0,0 +1,88 @@
+use crate::application::ports::language_detector::{DetectedLanguage, LanguageDetector};
+use crate::domain::value_objects::LanguageCode;
+
+/// Trigram statistics settle well before this; longer text only costs time
+const MAX_SAMPLE_BYTES: usize = 16 * 1024;
+
+/// Trigram-based language identification backed by whatlang
+pub struct WhatlangDetector {
+    min_confidence: f64,
+}
+
+impl WhatlangDetector {
+    pub fn new() -> Self {
+        Self {
+            min_confidence: 0.5, // whatlang's own reliability cut-off is stricter
+        }
+    }
+
+    #[cfg(test)]
+    pub fn with_min_confidence(mut self, min_confidence: f64) -> Self {
+        self.min_confidence = min_confidence.clamp(0.0, 1.0);
+        self
+    }
+}
+
+impl Default for WhatlangDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LanguageDetector for WhatlangDetector {
+    fn detect(&self, text: &str) -> Option<DetectedLanguage> {
+        let mut end = text.len().min(MAX_SAMPLE_BYTES);
+        while !text.is_char_boundary(end) {
+            end -= 1;
+        }
+
+        let info = whatlang::detect(&text[..end])?;
+        if !info.is_reliable() && info.confidence() < self.min_confidence {
+            return None;
+        }
+
+        let language = LanguageCode::parse(info.lang().code()).ok()?;
+
+        Some(DetectedLanguage {
+            language,
+            confidence: info.confidence(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detects_common_languages() {
+        let detector = WhatlangDetector::new();
+
+        let cases = [
+            (
+                "The quick brown fox jumps over the lazy dog while the farmer watches from the porch.",
+                "en",
+            ),
+            (
+                "Le renard brun rapide saute par-dessus le chien paresseux pendant que le fermier regarde.",
+                "fr",
+            ),
+            (
+                "Der schnelle braune Fuchs springt über den faulen Hund, während der Bauer zuschaut.",
+                "de",
+            ),
+        ];
+
+        for (text, expected) in cases {
+            let detected = detector.detect(text).unwrap();
+            assert_eq!(detected.language.as_str(), expected);
+        }
+    }
+
+    #[test]
+    fn test_short_text_is_undetermined() {
+        let detector = WhatlangDetector::new().with_min_confidence(1.0);
+        assert_eq!(detector.detect("ok"), None);
+        assert_eq!(detector.detect("12345 !!!"), None);
+    }
+}
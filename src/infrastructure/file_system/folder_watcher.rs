@@ -0,0 +1,410 @@
+use notify::{EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use crate::application::ports::{ContentDetector, DocumentExtractor};
+use crate::application::use_cases::{
+    SyncWatchedFileUseCase,
+    sync_watched_file::{SyncAction, WatchedFileChange},
+};
+
+/// Collapses bursts of filesystem events into one sync per path
+#[derive(Debug)]
+pub struct Debouncer {
+    window: Duration,
+    pending: HashMap<PathBuf, Instant>,
+}
+
+impl Debouncer {
+    pub fn new(window: Duration) -> Self {
+        Self {
+            window,
+            pending: HashMap::new(),
+        }
+    }
+
+    pub fn touch(&mut self, path: PathBuf, now: Instant) {
+        self.pending.insert(path, now);
+    }
+
+    /// Paths that have been quiet for the whole window
+    pub fn drain_ready(&mut self, now: Instant) -> Vec<PathBuf> {
+        let ready: Vec<PathBuf> = self
+            .pending
+            .iter()
+            .filter(|(_, last)| now.duration_since(**last) >= self.window)
+            .map(|(path, _)| path.clone())
+            .collect();
+
+        for path in &ready {
+            self.pending.remove(path);
+        }
+        ready
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pending.is_empty()
+    }
+}
+
+pub struct FolderWatcher {
+    roots: Vec<PathBuf>,
+    sync_use_case: Arc<SyncWatchedFileUseCase>,
+    document_extractor: Arc<dyn DocumentExtractor>,
+    content_detector: Arc<dyn ContentDetector>,
+    debounce: Duration,
+}
+
+impl FolderWatcher {
+    pub fn new(
+        roots: Vec<PathBuf>,
+        sync_use_case: Arc<SyncWatchedFileUseCase>,
+        document_extractor: Arc<dyn DocumentExtractor>,
+        content_detector: Arc<dyn ContentDetector>,
+    ) -> Self {
+        Self {
+            roots,
+            sync_use_case,
+            document_extractor,
+            content_detector,
+            debounce: Duration::from_secs(2), // Default debounce window
+        }
+    }
+
+    pub fn with_debounce(mut self, debounce: Duration) -> Self {
+        self.debounce = debounce;
+        self
+    }
+
+    pub async fn start(&self) {
+        if self.roots.is_empty() {
+            return;
+        }
+
+        let roots: Vec<PathBuf> = self
+            .roots
+            .iter()
+            .filter_map(|root| match std::fs::canonicalize(root) {
+                Ok(path) if path.is_dir() => Some(path),
+                _ => {
+                    eprintln!(
+                        "Watched folder {} is not a directory, skipping",
+                        root.display()
+                    );
+                    None
+                }
+            })
+            .collect();
+
+        let (event_tx, mut event_rx) = tokio::sync::mpsc::unbounded_channel();
+        let mut watcher: RecommendedWatcher =
+            match notify::recommended_watcher(move |res: notify::Result<notify::Event>| {
+                let _ = event_tx.send(res);
+            }) {
+                Ok(watcher) => watcher,
+                Err(e) => {
+                    eprintln!("Failed to create folder watcher: {}", e);
+                    return;
+                }
+            };
+
+        for root in &roots {
+            if let Err(e) = watcher.watch(root, RecursiveMode::Recursive) {
+                eprintln!("Failed to watch {}: {}", root.display(), e);
+                continue;
+            }
+            println!("Watching folder {}", root.display());
+        }
+
+        // Catch up on anything that changed while we were not running
+        for root in &roots {
+            self.reconcile(root, root).await;
+        }
+
+        let mut debouncer = Debouncer::new(self.debounce);
+        let mut ticker = tokio::time::interval(self.debounce.max(Duration::from_millis(100)) / 2);
+
+        loop {
+            tokio::select! {
+                event = event_rx.recv() => {
+                    match event {
+                        Some(Ok(event)) => {
+                            if matches!(event.kind, EventKind::Access(_)) {
+                                continue;
+                            }
+                            let now = Instant::now();
+                            for path in event.paths {
+                                if !is_ignored(&path) {
+                                    debouncer.touch(path, now);
+                                }
+                            }
+                        }
+                        Some(Err(e)) => eprintln!("Folder watcher error: {}", e),
+                        None => break,
+                    }
+                }
+                _ = ticker.tick() => {
+                    if debouncer.is_empty() {
+                        continue;
+                    }
+                    for path in debouncer.drain_ready(Instant::now()) {
+                        if let Some(root) = roots.iter().find(|root| path.starts_with(root)) {
+                            self.sync_event(root, &path).await;
+                        }
+                    }
+                }
+            }
+        }
+
+        println!("Folder watcher stopped");
+    }
+
+    /// A directory event says nothing about the files under it: one moved in
+    /// brings files that were never reported, and one removed or moved away
+    /// takes its tracked files along, so the subtree is reconciled by prefix
+    async fn sync_event(&self, root: &Path, path: &Path) {
+        if path.is_dir() {
+            self.reconcile(root, path).await;
+            return;
+        }
+
+        self.sync_path(root, path).await;
+        if !path.exists() {
+            self.remove_missing(root, path, &[]).await;
+        }
+    }
+
+    async fn reconcile(&self, root: &Path, dir: &Path) {
+        let on_disk = match collect_files(dir).await {
+            Ok(paths) => paths,
+            Err(e) => {
+                eprintln!("Failed to scan {}: {}", dir.display(), e);
+                return;
+            }
+        };
+
+        for path in &on_disk {
+            self.sync_path(root, path).await;
+        }
+        self.remove_missing(root, dir, &on_disk).await;
+    }
+
+    /// Removes tracked files under `dir` that are not in `on_disk`
+    async fn remove_missing(&self, root: &Path, dir: &Path, on_disk: &[PathBuf]) {
+        let prefix = format!("{}{}", dir.display(), std::path::MAIN_SEPARATOR);
+        let existing: HashSet<&PathBuf> = on_disk.iter().collect();
+        match self.sync_use_case.tracked_paths(&prefix).await {
+            Ok(tracked) => {
+                for path in tracked.into_iter().map(PathBuf::from) {
+                    if !existing.contains(&path) {
+                        self.sync_path(root, &path).await;
+                    }
+                }
+            }
+            Err(e) => eprintln!("Failed to list tracked files for {}: {}", dir.display(), e),
+        }
+    }
+
+    async fn sync_path(&self, root: &Path, path: &Path) {
+        let path_str = path.to_string_lossy().to_string();
+
+        let change = if path.is_file() {
+            let too_large = match (
+                tokio::fs::metadata(path).await,
+                self.document_extractor.max_file_size(),
+            ) {
+                (Ok(meta), Some(max)) => meta.len() > max as u64,
+                _ => false,
+            };
+            if too_large {
+                return;
+            }
+
+            let data = match tokio::fs::read(path).await {
+                Ok(data) => data,
+                Err(e) => {
+                    eprintln!("Failed to read watched file {}: {}", path.display(), e);
+                    return;
+                }
+            };
+
+            let file_name = path.file_name().map(|n| n.to_string_lossy());
+            let detected = self
+                .content_detector
+                .detect(&data, file_name.as_deref(), None);
+            if !self.document_extractor.can_extract(&detected.mime_type) {
+                return;
+            }
+
+            WatchedFileChange::Upserted {
+                path: path_str.clone(),
+                data,
+                detected,
+                watch_root: root.to_string_lossy().to_string(),
+            }
+        } else if path.exists() {
+            return; // Directories are reconciled by sync_event
+        } else {
+            WatchedFileChange::Removed {
+                path: path_str.clone(),
+            }
+        };
+
+        match self.sync_use_case.execute(change).await {
+            Ok(response) => match response.action {
+                SyncAction::Created | SyncAction::Updated | SyncAction::Removed => println!(
+                    "Watched file {}: {:?} (file {:?}, job {:?})",
+                    path_str, response.action, response.file_id, response.job_id
+                ),
+                SyncAction::Unchanged | SyncAction::NotTracked => {}
+            },
+            Err(e) => eprintln!("Failed to sync watched file {}: {}", path_str, e),
+        }
+    }
+}
+
+/// Editor swap files, partial downloads and dotfiles are never ingested
+pub fn is_ignored(path: &Path) -> bool {
+    let Some(name) = path.file_name().map(|n| n.to_string_lossy()) else {
+        return true;
+    };
+
+    name.starts_with('.')
+        || name.starts_with("~$")
+        || name.ends_with('~')
+        || [".tmp", ".part", ".crdownload", ".swp"]
+            .iter()
+            .any(|suffix| name.ends_with(suffix))
+}
+
+async fn collect_files(root: &Path) -> std::io::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    let mut dirs = vec![root.to_path_buf()];
+
+    while let Some(dir) = dirs.pop() {
+        let mut entries = tokio::fs::read_dir(&dir).await?;
+        while let Some(entry) = entries.next_entry().await? {
+            let path = entry.path();
+            if is_ignored(&path) {
+                continue;
+            }
+            let file_type = entry.file_type().await?;
+            if file_type.is_dir() {
+                dirs.push(path);
+            } else if file_type.is_file() {
+                files.push(path);
+            }
+        }
+    }
+
+    Ok(files)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::use_cases::QueueProcessingJobUseCase;
+    use crate::infrastructure::database::in_memory::InMemoryDatabase;
+    use crate::infrastructure::database::repositories::{
+        InMemoryFileRepository, InMemoryJobRepository,
+    };
+    use crate::infrastructure::external_services::{
+        ContentSniffer, document_extractors::CompositeDocumentExtractor,
+    };
+    use crate::infrastructure::messaging::MpscJobQueue;
+
+    #[test]
+    fn test_debouncer_waits_for_quiet_period() {
+        let mut debouncer = Debouncer::new(Duration::from_millis(500));
+        let start = Instant::now();
+        let path = PathBuf::from("/watch/report.pdf");
+
+        debouncer.touch(path.clone(), start);
+        debouncer.touch(path.clone(), start + Duration::from_millis(300));
+
+        assert!(
+            debouncer
+                .drain_ready(start + Duration::from_millis(600))
+                .is_empty()
+        );
+        assert_eq!(
+            debouncer.drain_ready(start + Duration::from_millis(800)),
+            vec![path]
+        );
+        assert!(debouncer.is_empty());
+    }
+
+    #[test]
+    fn test_ignored_paths() {
+        assert!(is_ignored(Path::new("/watch/.hidden.pdf")));
+        assert!(is_ignored(Path::new("/watch/report.pdf.part")));
+        assert!(is_ignored(Path::new("/watch/~$report.docx")));
+        assert!(!is_ignored(Path::new("/watch/report.pdf")));
+    }
+
+    #[tokio::test]
+    async fn test_directory_events_reconcile_by_prefix() {
+        let root = std::env::temp_dir().join(format!("polyrag-watch-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(root.join("notes")).unwrap();
+        std::fs::write(root.join("notes/a.txt"), "first note").unwrap();
+        std::fs::write(root.join("notes/b.txt"), "second note").unwrap();
+        std::fs::write(root.join("top.txt"), "stays put").unwrap();
+        let root = std::fs::canonicalize(root).unwrap();
+
+        let database = InMemoryDatabase::new();
+        let file_repository = Arc::new(InMemoryFileRepository::new(database.clone()));
+        let (job_queue, _job_receiver) = MpscJobQueue::create_pair();
+        let sync_use_case = Arc::new(SyncWatchedFileUseCase::new(
+            file_repository.clone(),
+            Arc::new(QueueProcessingJobUseCase::new(
+                Arc::new(InMemoryJobRepository::new(database.clone())),
+                Arc::new(job_queue),
+                file_repository,
+            )),
+        ));
+        let content_detector = Arc::new(ContentSniffer::new());
+        let watcher = FolderWatcher::new(
+            vec![root.clone()],
+            sync_use_case.clone(),
+            Arc::new(
+                CompositeDocumentExtractor::new()
+                    .unwrap()
+                    .with_content_detector(content_detector.clone()),
+            ),
+            content_detector,
+        );
+        let tracked = || async {
+            let mut paths = sync_use_case
+                .tracked_paths(&root.to_string_lossy())
+                .await
+                .unwrap();
+            paths.sort();
+            paths
+        };
+
+        watcher.reconcile(&root, &root).await;
+        assert_eq!(tracked().await.len(), 3);
+
+        // A rename reports only the two directory paths
+        std::fs::rename(root.join("notes"), root.join("archive")).unwrap();
+        watcher.sync_event(&root, &root.join("notes")).await;
+        watcher.sync_event(&root, &root.join("archive")).await;
+        let path = |name: &str| root.join(name).to_string_lossy().to_string();
+        assert_eq!(
+            tracked().await,
+            vec![
+                path("archive/a.txt"),
+                path("archive/b.txt"),
+                path("top.txt")
+            ]
+        );
+
+        std::fs::remove_dir_all(root.join("archive")).unwrap();
+        watcher.sync_event(&root, &root.join("archive")).await;
+        assert_eq!(tracked().await, vec![path("top.txt")]);
+
+        let _ = std::fs::remove_dir_all(&root);
+    }
+}
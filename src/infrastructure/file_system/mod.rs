@@ -1,3 +1,5 @@
+pub mod folder_watcher;
 pub mod local_file_storage;
 
+pub use folder_watcher::FolderWatcher;
 pub use local_file_storage::LocalFileStorage;
@@ -1,18 +1,16 @@
 use std::sync::Arc;
+use std::time::Duration;
+use uuid::Uuid;
 
 use crate::application::ports::document_extractor::DocumentExtractor;
-use crate::application::ports::document_extractor::ExtractionOptions;
-use crate::application::ports::embedding_provider::BatchEmbeddingRequest;
-use crate::application::ports::embedding_provider::EmbeddingProvider;
+use crate::application::ports::document_extractor::{ExtractedContent, ExtractionOptions};
 use crate::application::ports::file_storage::FileStorage;
-use crate::application::services::DocumentProcessorService;
+use crate::application::services::{DocumentProcessorService, EmbeddingModelRegistry};
+use crate::domain::entities::ContentChunk;
 use crate::domain::entities::processing_job::{JobResult, JobType, ProcessingJob};
 use crate::domain::repositories::{
     ChunkRepository, EmbeddingRepository, FileRepository, JobRepository,
 };
-use crate::infrastructure::external_services::semantic_chunking::{
-    RTSplitter, RecursiveTextSplitter,
-};
 use crate::infrastructure::messaging::MpscJobQueueReceiver;
 
 pub struct BackgroundProcessor {
@@ -21,11 +19,10 @@ pub struct BackgroundProcessor {
     file_repository: Arc<dyn FileRepository>,
     document_processor: Arc<DocumentProcessorService>,
     document_extractor: Arc<dyn DocumentExtractor>,
-    embedding_provider: Arc<dyn EmbeddingProvider>,
+    embedding_models: Arc<EmbeddingModelRegistry>,
     file_storage: Arc<dyn FileStorage>,
     chunk_repository: Arc<dyn ChunkRepository>,
     embedding_repository: Arc<dyn EmbeddingRepository>,
-    text_splitter: RTSplitter,
     worker_count: usize,
 }
 
@@ -36,7 +33,7 @@ impl BackgroundProcessor {
         file_repository: Arc<dyn FileRepository>,
         document_processor: Arc<DocumentProcessorService>,
         document_extractor: Arc<dyn DocumentExtractor>,
-        embedding_provider: Arc<dyn EmbeddingProvider>,
+        embedding_models: Arc<EmbeddingModelRegistry>,
         file_storage: Arc<dyn FileStorage>,
         chunk_repository: Arc<dyn ChunkRepository>,
         embedding_repository: Arc<dyn EmbeddingRepository>,
@@ -47,11 +44,10 @@ impl BackgroundProcessor {
             file_repository,
             document_processor,
             document_extractor,
-            embedding_provider,
+            embedding_models,
             file_storage,
             chunk_repository,
             embedding_repository,
-            text_splitter: RTSplitter::default(),
             worker_count: 3, // Default worker count
         }
     }
@@ -63,8 +59,9 @@ impl BackgroundProcessor {
 
     pub async fn start(&self) {
         println!(
-            "Starting background processor with {} workers",
-            self.worker_count
+            "Starting background processor with {} workers ({} chunking)",
+            self.worker_count,
+            self.document_processor.default_chunking_strategy()
         );
 
         // Spawn multiple worker tasks
@@ -122,12 +119,37 @@ impl BackgroundProcessor {
             return;
         }
 
-        // Process based on job type
-        let result = match job.job_type().clone() {
-            JobType::FileProcessing => self.process_file_job(&mut job).await,
-            JobType::UrlExtraction { url } => self.process_url_extraction_job(&mut job, &url).await,
-            JobType::YoutubeExtraction { url } => {
-                self.process_youtube_extraction_job(&mut job, &url).await
+        // Process based on job type. A job that fails while every server of
+        // an embedding model is down is parked until one recovers and then
+        // run again, rather than failed; every job type can be rerun.
+        let result = loop {
+            if !self.park_while_embeddings_unavailable(&mut job).await {
+                break Err("Cancelled while waiting for the embedding service".to_string());
+            }
+
+            let result = match (job.job_type().clone(), job.file_id()) {
+                (JobType::Reembed { model_name }, _) => {
+                    self.process_reembed_job(&mut job, &model_name).await
+                }
+                (_, None) => Err("Job has no file to process".to_string()),
+                (JobType::FileProcessing, Some(file_id)) => {
+                    self.process_file_job(&mut job, file_id).await
+                }
+                (JobType::UrlExtraction { url }, Some(file_id)) => {
+                    self.process_url_extraction_job(&mut job, file_id, &url)
+                        .await
+                }
+                (JobType::YoutubeExtraction { url }, Some(file_id)) => {
+                    self.process_youtube_extraction_job(&mut job, file_id, &url)
+                        .await
+                }
+            };
+
+            match result {
+                Err(error) if self.embeddings_unavailable_for().is_some() => {
+                    println!("Job {} parked: {}", job_id, error);
+                }
+                result => break result,
             }
         };
 
@@ -156,7 +178,42 @@ impl BackgroundProcessor {
         }
     }
 
-    async fn process_file_job(&self, job: &mut ProcessingJob) -> Result<JobResult, String> {
+    /// Waits while every server of some embedding model is down, keeping
+    /// the job's message current; false if the job is cancelled meanwhile
+    async fn park_while_embeddings_unavailable(&self, job: &mut ProcessingJob) -> bool {
+        while let Some(wait) = self.embeddings_unavailable_for() {
+            if let Ok(Some(current)) = self.job_repository.find_by_id(job.id()).await
+                && current.status().is_terminal()
+            {
+                return false;
+            }
+
+            let _ = job.update_progress(
+                job.progress(),
+                Some(format!(
+                    "Embedding service unavailable; retrying in {}s",
+                    wait.as_secs().max(1)
+                )),
+            );
+            let _ = self.job_repository.update(job).await;
+            tokio::time::sleep(wait).await;
+        }
+        true
+    }
+
+    fn embeddings_unavailable_for(&self) -> Option<Duration> {
+        self.embedding_models
+            .providers()
+            .iter()
+            .filter_map(|provider| provider.unavailable_for())
+            .max()
+    }
+
+    async fn process_file_job(
+        &self,
+        job: &mut ProcessingJob,
+        file_id: Uuid,
+    ) -> Result<JobResult, String> {
         // Update progress
         let _ = job.update_progress(0.1, Some("Loading file...".to_string()));
         let _ = self.job_repository.update(job).await;
@@ -167,33 +224,35 @@ impl BackgroundProcessor {
         // Get the file - this should exist if upload was successful
         let file = self
             .file_repository
-            .find_by_id(job.file_id())
+            .find_by_id(file_id)
             .await
             .map_err(|e| format!("Failed to find file: {}", e))?
-            .ok_or_else(|| format!("File not found in database: {}", job.file_id()))?;
+            .ok_or_else(|| format!("File not found in database: {}", file_id))?;
 
         // Update progress
         let _ = job.update_progress(0.2, Some("Processing document...".to_string()));
         let _ = self.job_repository.update(job).await;
 
         // Process the document
-        let (chunks_created, embeddings_created) = self
+        let processed = self
             .document_processor
-            .process_file(&file, ExtractionOptions::default())
+            .process_file(&file, ExtractionOptions::default(), job.chunking_config())
             .await
             .map_err(|e| format!("Document processing failed: {}", e))?;
 
         Ok(JobResult {
-            chunks_created,
-            embeddings_created,
+            chunks_created: processed.chunks_created,
+            embeddings_created: processed.embeddings_created,
             processing_time_ms: 0,    // Will be calculated by the job
             extracted_text_length: 0, // Could be calculated if needed
+            embedding_cache_hits: processed.embedding_cache_hits,
         })
     }
 
     async fn process_url_extraction_job(
         &self,
         job: &mut ProcessingJob,
+        file_id: Uuid,
         url: &str,
     ) -> Result<JobResult, String> {
         // Update progress
@@ -214,43 +273,14 @@ impl BackgroundProcessor {
             .await
             .map_err(|e| format!("URL extraction failed: {}", e))?;
 
-        // Update progress
-        let _ = job.update_progress(0.3, Some("Creating chunks...".to_string()));
-        let _ = self.job_repository.update(job).await;
-
-        // Create chunks from extracted text
-        let chunks = self.create_chunks_from_text(job.file_id(), &extracted_content.text)?;
-
-        // Save chunks
-        self.chunk_repository
-            .save_batch(&chunks)
-            .await
-            .map_err(|e| format!("Failed to save chunks: {}", e))?;
-
-        // Update progress
-        let _ = job.update_progress(0.6, Some("Generating embeddings...".to_string()));
-        let _ = self.job_repository.update(job).await;
-
-        // Generate embeddings
-        let embeddings = self.generate_embeddings_for_chunks(&chunks).await?;
-
-        // Save embeddings
-        self.embedding_repository
-            .save_batch(&embeddings)
+        self.ingest_extracted(job, file_id, &extracted_content)
             .await
-            .map_err(|e| format!("Failed to save embeddings: {}", e))?;
-
-        Ok(JobResult {
-            chunks_created: chunks.len() as i32,
-            embeddings_created: embeddings.len() as i32,
-            processing_time_ms: 0,
-            extracted_text_length: extracted_content.text.len(),
-        })
     }
 
     async fn process_youtube_extraction_job(
         &self,
         job: &mut ProcessingJob,
+        file_id: Uuid,
         url: &str,
     ) -> Result<JobResult, String> {
         // Update progress
@@ -271,12 +301,59 @@ impl BackgroundProcessor {
             .await
             .map_err(|e| format!("YouTube extraction failed: {}", e))?;
 
+        self.ingest_extracted(job, file_id, &extracted_content)
+            .await
+    }
+
+    /// Chunks, tags and embeds content extracted for `file_id`, replacing
+    /// whatever an earlier extraction of it stored
+    async fn ingest_extracted(
+        &self,
+        job: &mut ProcessingJob,
+        file_id: Uuid,
+        extracted_content: &ExtractedContent,
+    ) -> Result<JobResult, String> {
         // Update progress
         let _ = job.update_progress(0.3, Some("Creating chunks...".to_string()));
         let _ = self.job_repository.update(job).await;
 
-        // Create chunks from transcript
-        let chunks = self.create_chunks_from_text(job.file_id(), &extracted_content.text)?;
+        let chunking_config = self
+            .document_processor
+            .resolve_chunking_config(file_id, job.chunking_config())
+            .await
+            .map_err(|e| e.to_string())?;
+        let chunks = self
+            .document_processor
+            .create_chunks(file_id, &extracted_content.text, &chunking_config)
+            .await
+            .map_err(|e| e.to_string())?;
+        let file = self
+            .file_repository
+            .find_by_id(file_id)
+            .await
+            .map_err(|e| format!("Failed to load file: {}", e))?;
+        let title = DocumentProcessorService::document_title(file.as_ref(), extracted_content);
+        self.document_processor
+            .validate_chunk_sizes(
+                &chunks,
+                chunking_config.embedding_template(),
+                title.as_deref(),
+            )
+            .map_err(|e| e.to_string())?;
+        let chunks = self
+            .tag_languages(file_id, extracted_content, chunks)
+            .await?;
+        self.document_processor
+            .record_chunking_config(file_id, &chunking_config)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        // Re-extraction (an updated feed item, or a rerun after parking)
+        // replaces the previous chunks
+        self.chunk_repository
+            .delete_by_file_id(file_id)
+            .await
+            .map_err(|e| format!("Failed to clear previous chunks: {}", e))?;
 
         // Save chunks
         self.chunk_repository
@@ -289,101 +366,147 @@ impl BackgroundProcessor {
         let _ = self.job_repository.update(job).await;
 
         // Generate embeddings
-        let embeddings = self.generate_embeddings_for_chunks(&chunks).await?;
+        let generated = self
+            .document_processor
+            .generate_embeddings_for_chunks(
+                &ContentChunk::leaves(&chunks),
+                chunking_config.embedding_template(),
+                title.as_deref(),
+            )
+            .await
+            .map_err(|e| format!("Embedding generation failed: {}", e))?;
 
         // Save embeddings
-        self.embedding_repository
-            .save_batch(&embeddings)
+        self.document_processor
+            .save_embeddings(&generated)
             .await
             .map_err(|e| format!("Failed to save embeddings: {}", e))?;
 
         Ok(JobResult {
             chunks_created: chunks.len() as i32,
-            embeddings_created: embeddings.len() as i32,
+            embeddings_created: generated.embeddings.len() as i32,
             processing_time_ms: 0,
             extracted_text_length: extracted_content.text.len(),
+            embedding_cache_hits: generated.cache_hits as i32,
         })
     }
 
-    fn create_chunks_from_text(
+    /// Embeds every chunk that lacks a vector from `model_name`, batch by
+    /// batch. Each batch only selects chunks still missing one, so queueing
+    /// the job again after an interruption picks up where it stopped. A
+    /// corpus-wide run that completes makes `model_name` the active model.
+    async fn process_reembed_job(
         &self,
-        file_id: uuid::Uuid,
-        text: &str,
-    ) -> Result<Vec<crate::domain::entities::ContentChunk>, String> {
-        if text.trim().is_empty() {
-            return Ok(Vec::new());
+        job: &mut ProcessingJob,
+        model_name: &str,
+    ) -> Result<JobResult, String> {
+        const REEMBED_BATCH_SIZE: i64 = 256;
+
+        if self.embedding_models.get(model_name).is_none() {
+            return Err(format!(
+                "Embedding model {} is not configured in EMBEDDING_PROVIDERS",
+                model_name
+            ));
         }
 
-        // Use RTSplitter with a reasonable chunk size (characters, not words)
-        let max_chunk_size = 2000; // characters - good balance for embeddings
-        let chunk_texts = self.text_splitter.split_text(text, max_chunk_size);
+        let total = self
+            .chunk_repository
+            .count_missing_embeddings(model_name, job.file_id())
+            .await
+            .map_err(|e| format!("Failed to count chunks to re-embed: {}", e))?;
+        let _ = job.update_progress(
+            0.1,
+            Some(format!("Re-embedding {} chunks with {}", total, model_name)),
+        );
+        let _ = self.job_repository.update(job).await;
 
-        let mut chunks = Vec::new();
-        for (index, chunk_text) in chunk_texts.into_iter().enumerate() {
-            if chunk_text.trim().len() < 10 {
-                continue; // Skip very small chunks
+        let mut after = None;
+        let mut embedded = 0;
+        let mut cache_hits = 0;
+        let mut derived = 0;
+        loop {
+            // Stop between batches if the job was cancelled
+            if let Ok(Some(current)) = self.job_repository.find_by_id(job.id()).await
+                && current.status().is_terminal()
+            {
+                return Err(format!(
+                    "Re-embedding cancelled after {} of {} chunks",
+                    embedded, total
+                ));
             }
 
-            let word_count = chunk_text.split_whitespace().count() as i32;
+            let chunks = self
+                .chunk_repository
+                .find_missing_embeddings(model_name, job.file_id(), after, REEMBED_BATCH_SIZE)
+                .await
+                .map_err(|e| format!("Failed to load chunks to re-embed: {}", e))?;
+            let Some(last) = chunks.last() else {
+                break;
+            };
+            after = Some(last.id());
 
-            let chunk = crate::domain::entities::ContentChunk::new(
-                file_id,
-                chunk_text,
-                index as i32,
-                Some(word_count),
-                None, // page_number - not applicable for text extraction
-                None, // section_path - could be enhanced later
+            let generated = self
+                .document_processor
+                .embed_stored_chunks(model_name, &chunks)
+                .await
+                .map_err(|e| format!("Embedding generation failed: {}", e))?;
+            self.embedding_repository
+                .save_batch(&generated.embeddings)
+                .await
+                .map_err(|e| format!("Failed to save embeddings: {}", e))?;
+
+            embedded += generated.embeddings.len() as i64;
+            cache_hits += generated.cache_hits;
+            derived += generated.derived;
+            let progress = 0.1 + 0.85 * (embedded as f32 / total.max(1) as f32).min(1.0);
+            let _ = job.update_progress(
+                progress,
+                Some(format!("Re-embedded {} of {} chunks", embedded, total)),
             );
+            let _ = self.job_repository.update(job).await;
+        }
 
-            chunks.push(chunk);
+        if job.file_id().is_none() {
+            self.embedding_repository
+                .activate_model(model_name)
+                .await
+                .map_err(|e| format!("Failed to activate {}: {}", model_name, e))?;
+            self.embedding_models.set_active_model(model_name);
+            println!("Embedding model {} is now active", model_name);
+        }
+        if derived > 0 {
+            println!(
+                "Derived {} of {} {} vectors from stored full-size embeddings",
+                derived, embedded, model_name
+            );
         }
 
-        Ok(chunks)
+        Ok(JobResult {
+            chunks_created: 0,
+            embeddings_created: embedded as i32,
+            processing_time_ms: 0,
+            extracted_text_length: 0,
+            embedding_cache_hits: cache_hits as i32,
+        })
     }
 
-    async fn generate_embeddings_for_chunks(
+    async fn tag_languages(
         &self,
-        chunks: &[crate::domain::entities::ContentChunk],
-    ) -> Result<Vec<crate::domain::entities::Embedding>, String> {
-        let mut embeddings = Vec::new();
-        let (model_name, model_version) = self.embedding_provider.model_info();
-
-        const BATCH_SIZE: usize = 10;
-
-        for chunk_batch in chunks.chunks(BATCH_SIZE) {
-            let texts: Vec<String> = chunk_batch
-                .iter()
-                .map(|chunk| chunk.chunk_text().to_string())
-                .collect();
-
-            let batch_request = BatchEmbeddingRequest {
-                texts,
-                model_name: Some(model_name.clone()),
-                model_version: model_version.clone(),
-            };
-
-            let batch_response = self
-                .embedding_provider
-                .generate_embeddings(batch_request)
+        file_id: Uuid,
+        content: &ExtractedContent,
+        chunks: Vec<ContentChunk>,
+    ) -> Result<Vec<ContentChunk>, String> {
+        let document_language = self.document_processor.detect_document_language(content);
+        if let Some(language) = &document_language {
+            self.document_processor
+                .record_document_language(file_id, language)
                 .await
-                .map_err(|e| format!("Embedding generation failed: {}", e))?;
-
-            for (chunk, embedding_vector) in
-                chunk_batch.iter().zip(batch_response.embeddings.iter())
-            {
-                let embedding = crate::domain::entities::Embedding::new(
-                    chunk.id(),
-                    batch_response.model_name.clone(),
-                    batch_response.model_version.clone(),
-                    None,
-                    embedding_vector.clone(),
-                );
-
-                embeddings.push(embedding);
-            }
+                .map_err(|e| format!("Failed to record document language: {}", e))?;
         }
 
-        Ok(embeddings)
+        Ok(self
+            .document_processor
+            .assign_chunk_languages(chunks, document_language.as_ref()))
     }
 
     fn clone_for_worker(&self) -> Self {
@@ -393,11 +516,10 @@ impl BackgroundProcessor {
             file_repository: self.file_repository.clone(),
             document_processor: self.document_processor.clone(),
             document_extractor: self.document_extractor.clone(),
-            embedding_provider: self.embedding_provider.clone(),
+            embedding_models: self.embedding_models.clone(),
             file_storage: self.file_storage.clone(),
             chunk_repository: self.chunk_repository.clone(),
             embedding_repository: self.embedding_repository.clone(),
-            text_splitter: self.text_splitter.clone(),
             worker_count: self.worker_count,
         }
     }
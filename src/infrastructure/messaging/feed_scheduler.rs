@@ -0,0 +1,55 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::application::use_cases::PollFeedUseCase;
+
+pub struct FeedScheduler {
+    poll_feed_use_case: Arc<PollFeedUseCase>,
+    tick_interval: Duration,
+}
+
+impl FeedScheduler {
+    pub fn new(poll_feed_use_case: Arc<PollFeedUseCase>) -> Self {
+        Self {
+            poll_feed_use_case,
+            tick_interval: Duration::from_secs(30), // Default tick
+        }
+    }
+
+    pub fn with_tick_interval(mut self, interval: Duration) -> Self {
+        self.tick_interval = interval.max(Duration::from_secs(1));
+        self
+    }
+
+    pub async fn start(&self) {
+        println!(
+            "Starting feed scheduler (checking every {}s)",
+            self.tick_interval.as_secs()
+        );
+
+        let mut ticker = tokio::time::interval(self.tick_interval);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+
+            // Each feed carries its own interval; the tick only decides how often we check
+            match self.poll_feed_use_case.poll_due_feeds().await {
+                Ok(responses) => {
+                    for response in responses {
+                        if response.new_items > 0 || response.updated_items > 0 {
+                            println!(
+                                "Feed {} polled: {} new, {} updated, {} jobs queued",
+                                response.feed_id,
+                                response.new_items,
+                                response.updated_items,
+                                response.job_ids.len()
+                            );
+                        }
+                    }
+                }
+                Err(e) => eprintln!("Feed scheduler failed to load feeds: {}", e),
+            }
+        }
+    }
+}
@@ -1,5 +1,7 @@
 pub mod background_processor;
+pub mod feed_scheduler;
 pub mod mpsc_job_queue;
 
 pub use background_processor::*;
+pub use feed_scheduler::*;
 pub use mpsc_job_queue::*;
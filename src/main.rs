@@ -5,7 +5,7 @@ mod infrastructure;
 mod presentation;
 
 use infrastructure::container::AppContainer;
-use presentation::http::server::HttpServer;
+use presentation::http::server::{BackgroundServices, HttpHandlers, HttpServer};
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -20,14 +20,21 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .unwrap_or(3000);
 
     let server = HttpServer::new(
-        container.file_handler,
-        container.content_handler,
-        container.search_handler,
-        container.job_handler,
-        container.sse_handler,
-        container.chunk_handler,
-        container.embedding_handler,
-        container.background_processor,
+        HttpHandlers {
+            file: container.file_handler,
+            content: container.content_handler,
+            search: container.search_handler,
+            job: container.job_handler,
+            sse: container.sse_handler,
+            chunk: container.chunk_handler,
+            embedding: container.embedding_handler,
+            feed: container.feed_handler,
+        },
+        BackgroundServices {
+            background_processor: container.background_processor,
+            feed_scheduler: container.feed_scheduler,
+            folder_watcher: container.folder_watcher,
+        },
         Some(port),
     );
 
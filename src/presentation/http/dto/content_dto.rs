@@ -1,12 +1,16 @@
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::presentation::http::dto::ChunkingConfigDto;
+
 // Request DTOs for direct content processing
 #[derive(Debug, Deserialize)]
 pub struct ProcessUrlRequest {
     pub url: String,
     pub filename: Option<String>,
     pub auto_process: Option<bool>, // Default: true
+    #[serde(flatten)]
+    pub chunking: ChunkingConfigDto,
 }
 
 #[derive(Debug, Deserialize)]
@@ -16,6 +20,8 @@ pub struct ProcessYoutubeRequest {
     pub extract_timestamps: Option<bool>, // Default: true
     pub language_preference: Option<Vec<String>>, // Default: ["en"]
     pub auto_process: Option<bool>,       // Default: true
+    #[serde(flatten)]
+    pub chunking: ChunkingConfigDto,
 }
 
 // Response DTOs
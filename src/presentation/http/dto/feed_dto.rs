@@ -0,0 +1,100 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::application::use_cases::poll_feed::PollFeedResponse;
+use crate::domain::entities::{Feed, FeedEntry};
+
+#[derive(Debug, Deserialize)]
+pub struct SubscribeFeedRequestDto {
+    pub url: String,
+    pub name: Option<String>,
+    pub poll_interval_seconds: Option<i32>, // Default: 900
+}
+
+#[derive(Debug, Serialize)]
+pub struct FeedDto {
+    pub id: Uuid,
+    pub url: String,
+    pub name: String,
+    pub poll_interval_seconds: i32,
+    pub is_active: bool,
+    pub last_polled_at: Option<String>,
+    pub next_poll_at: Option<String>,
+    pub last_error: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl From<&Feed> for FeedDto {
+    fn from(feed: &Feed) -> Self {
+        Self {
+            id: feed.id(),
+            url: feed.url().to_string(),
+            name: feed.name().to_string(),
+            poll_interval_seconds: feed.poll_interval_seconds(),
+            is_active: feed.is_active(),
+            last_polled_at: feed.last_polled_at().map(|t| t.to_rfc3339()),
+            next_poll_at: feed.next_poll_at().map(|t| t.to_rfc3339()),
+            last_error: feed.last_error().map(|s| s.to_string()),
+            created_at: feed.created_at().to_rfc3339(),
+            updated_at: feed.updated_at().to_rfc3339(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct SubscribeFeedResponseDto {
+    pub feed: FeedDto,
+    pub item_count: usize,
+    pub message: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct FeedEntryDto {
+    pub id: Uuid,
+    pub guid: String,
+    pub link: String,
+    pub title: Option<String>,
+    pub published_at: Option<String>,
+    pub file_id: Option<Uuid>,
+    pub first_seen_at: String,
+    pub updated_at: String,
+}
+
+impl From<&FeedEntry> for FeedEntryDto {
+    fn from(entry: &FeedEntry) -> Self {
+        Self {
+            id: entry.id(),
+            guid: entry.guid().to_string(),
+            link: entry.link().to_string(),
+            title: entry.title().map(|s| s.to_string()),
+            published_at: entry.published_at().map(|t| t.to_rfc3339()),
+            file_id: entry.file_id(),
+            first_seen_at: entry.first_seen_at().to_rfc3339(),
+            updated_at: entry.updated_at().to_rfc3339(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct PollFeedResponseDto {
+    pub feed_id: Uuid,
+    pub items_seen: usize,
+    pub new_items: usize,
+    pub updated_items: usize,
+    pub skipped_items: usize,
+    pub job_ids: Vec<Uuid>,
+}
+
+impl From<PollFeedResponse> for PollFeedResponseDto {
+    fn from(response: PollFeedResponse) -> Self {
+        Self {
+            feed_id: response.feed_id,
+            items_seen: response.items_seen,
+            new_items: response.new_items,
+            updated_items: response.updated_items,
+            skipped_items: response.skipped_items,
+            job_ids: response.job_ids,
+        }
+    }
+}
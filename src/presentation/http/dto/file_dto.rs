@@ -49,6 +49,7 @@ pub struct UploadResponseDto {
     pub file_size: i64,
     pub file_hash: String,
     pub content_type: Option<String>,
+    pub declared_content_type: Option<String>,
     pub message: String,
 }
 
@@ -84,6 +85,7 @@ impl From<crate::application::use_cases::upload_file::UploadFileResponse> for Up
             file_size: response.file_size,
             file_hash: response.file_hash,
             content_type: response.content_type,
+            declared_content_type: response.declared_content_type,
             message: "File uploaded successfully".to_string(),
         }
     }
@@ -128,6 +130,8 @@ pub struct ContentChunkDto {
     pub word_count: Option<i32>,
     pub page_number: Option<i32>,
     pub section_path: Option<String>,
+    pub language: Option<String>,
+    pub parent_chunk_id: Option<Uuid>,
     pub created_at: String,
 }
 
@@ -171,6 +175,8 @@ impl From<&crate::domain::entities::ContentChunk> for ContentChunkDto {
             word_count: Some(chunk.word_count() as i32),
             page_number: chunk.page_number(),
             section_path: chunk.section_path().map(|s| s.to_string()),
+            language: chunk.language().map(|s| s.to_string()),
+            parent_chunk_id: chunk.parent_chunk_id(),
             created_at: chunk.created_at().to_rfc3339(),
         }
     }
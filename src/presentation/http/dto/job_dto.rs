@@ -5,11 +5,12 @@ use crate::application::use_cases::{
     get_job_status::GetJobStatusResponse, queue_processing_job::QueueJobResponse,
 };
 use crate::domain::entities::processing_job::{JobResult, JobType, ProcessingJob};
+use crate::domain::value_objects::{ChunkSizeUnit, ChunkingConfig};
 
 #[derive(Debug, Serialize)]
 pub struct JobStatusDto {
     pub job_id: Uuid,
-    pub file_id: Uuid,
+    pub file_id: Option<Uuid>,
     pub job_type: JobTypeDto,
     pub status: String,
     pub progress: f32,
@@ -18,6 +19,7 @@ pub struct JobStatusDto {
     pub completed_at: Option<String>,
     pub error_message: Option<String>,
     pub result_summary: Option<JobResultDto>,
+    pub chunking: Option<ChunkingConfigDto>,
     pub estimated_completion: Option<String>,
     pub duration_ms: Option<i64>,
     pub is_terminal: bool,
@@ -27,6 +29,8 @@ pub struct JobStatusDto {
 pub struct JobTypeDto {
     pub type_name: String,
     pub url: Option<String>,
+    /// Target model of a re-embedding job
+    pub model: Option<String>,
 }
 
 #[derive(Debug, Serialize)]
@@ -35,12 +39,14 @@ pub struct JobResultDto {
     pub embeddings_created: i32,
     pub processing_time_ms: u64,
     pub extracted_text_length: usize,
+    pub embedding_cache_hits: i32,
+    pub embedding_cache_hit_ratio: f32,
 }
 
 #[derive(Debug, Serialize)]
 pub struct QueueJobResponseDto {
     pub job_id: Uuid,
-    pub file_id: Uuid,
+    pub file_id: Option<Uuid>,
     pub job_type: JobTypeDto,
     pub status: String,
     pub message: String,
@@ -56,11 +62,80 @@ pub struct CancelJobResponseDto {
 #[derive(Debug, Deserialize)]
 pub struct ProcessUrlRequestDto {
     pub url: String,
+    #[serde(flatten)]
+    pub chunking: ChunkingConfigDto,
 }
 
 #[derive(Debug, Deserialize)]
 pub struct ProcessYoutubeRequestDto {
     pub url: String,
+    #[serde(flatten)]
+    pub chunking: ChunkingConfigDto,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ReembedRequestDto {
+    pub model: String,
+    /// Limit re-embedding to one file; the whole corpus when omitted
+    pub file_id: Option<Uuid>,
+}
+
+/// Chunking parameters, given as flat fields in query strings, JSON bodies
+/// and multipart forms
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct ChunkingConfigDto {
+    pub chunking_strategy: Option<String>,
+    pub chunk_size: Option<usize>,
+    pub chunk_overlap: Option<usize>,
+    pub chunk_unit: Option<String>,
+    pub parent_chunk_size: Option<usize>,
+    /// Embed each chunk behind its document title and section path
+    pub contextual_headers: Option<bool>,
+}
+
+impl ChunkingConfigDto {
+    /// None when the request leaves chunking to the defaults
+    pub fn into_config(self) -> Result<Option<ChunkingConfig>, String> {
+        if self.chunking_strategy.is_none()
+            && self.chunk_size.is_none()
+            && self.chunk_overlap.is_none()
+            && self.chunk_unit.is_none()
+            && self.parent_chunk_size.is_none()
+            && self.contextual_headers.is_none()
+        {
+            return Ok(None);
+        }
+
+        let config = ChunkingConfig {
+            strategy: self.chunking_strategy,
+            chunk_size: self.chunk_size,
+            chunk_overlap: self.chunk_overlap,
+            unit: self
+                .chunk_unit
+                .as_deref()
+                .map(ChunkSizeUnit::parse)
+                .transpose()?
+                .unwrap_or_default(),
+            parent_chunk_size: self.parent_chunk_size,
+            contextual_headers: self.contextual_headers.unwrap_or(false),
+        };
+        config.validate()?;
+
+        Ok(Some(config))
+    }
+}
+
+impl From<&ChunkingConfig> for ChunkingConfigDto {
+    fn from(config: &ChunkingConfig) -> Self {
+        Self {
+            chunking_strategy: config.strategy.clone(),
+            chunk_size: config.chunk_size,
+            chunk_overlap: config.chunk_overlap,
+            chunk_unit: Some(config.unit.as_str().to_string()),
+            parent_chunk_size: config.parent_chunk_size,
+            contextual_headers: Some(config.contextual_headers),
+        }
+    }
 }
 
 impl From<GetJobStatusResponse> for JobStatusDto {
@@ -83,25 +158,10 @@ impl JobStatusDto {
         estimated_completion: Option<chrono::DateTime<chrono::Utc>>,
         duration: Option<chrono::Duration>,
     ) -> Self {
-        let job_type = match job.job_type() {
-            JobType::FileProcessing => JobTypeDto {
-                type_name: "file_processing".to_string(),
-                url: None,
-            },
-            JobType::UrlExtraction { url } => JobTypeDto {
-                type_name: "url_extraction".to_string(),
-                url: Some(url.clone()),
-            },
-            JobType::YoutubeExtraction { url } => JobTypeDto {
-                type_name: "youtube_extraction".to_string(),
-                url: Some(url.clone()),
-            },
-        };
-
         Self {
             job_id: job.id(),
             file_id: job.file_id(),
-            job_type,
+            job_type: JobTypeDto::from(job.job_type()),
             status: job.status().to_string(),
             progress: job.progress(),
             created_at: job.created_at().to_rfc3339(),
@@ -109,6 +169,7 @@ impl JobStatusDto {
             completed_at: job.completed_at().map(|dt| dt.to_rfc3339()),
             error_message: job.error_message().map(|s| s.to_string()),
             result_summary: job.result_summary().map(JobResultDto::from),
+            chunking: job.chunking_config().map(ChunkingConfigDto::from),
             estimated_completion: estimated_completion.map(|dt| dt.to_rfc3339()),
             duration_ms: duration.map(|d| d.num_milliseconds()),
             is_terminal: job.status().is_terminal(),
@@ -116,6 +177,23 @@ impl JobStatusDto {
     }
 }
 
+impl From<&JobType> for JobTypeDto {
+    fn from(job_type: &JobType) -> Self {
+        let (type_name, url, model) = match job_type {
+            JobType::FileProcessing => ("file_processing", None, None),
+            JobType::UrlExtraction { url } => ("url_extraction", Some(url.clone()), None),
+            JobType::YoutubeExtraction { url } => ("youtube_extraction", Some(url.clone()), None),
+            JobType::Reembed { model_name } => ("reembed", None, Some(model_name.clone())),
+        };
+
+        Self {
+            type_name: type_name.to_string(),
+            url,
+            model,
+        }
+    }
+}
+
 impl From<&JobResult> for JobResultDto {
     fn from(result: &JobResult) -> Self {
         Self {
@@ -123,31 +201,18 @@ impl From<&JobResult> for JobResultDto {
             embeddings_created: result.embeddings_created,
             processing_time_ms: result.processing_time_ms,
             extracted_text_length: result.extracted_text_length,
+            embedding_cache_hits: result.embedding_cache_hits,
+            embedding_cache_hit_ratio: result.embedding_cache_hit_ratio(),
         }
     }
 }
 
 impl From<QueueJobResponse> for QueueJobResponseDto {
     fn from(response: QueueJobResponse) -> Self {
-        let job_type = match response.job_type {
-            JobType::FileProcessing => JobTypeDto {
-                type_name: "file_processing".to_string(),
-                url: None,
-            },
-            JobType::UrlExtraction { url } => JobTypeDto {
-                type_name: "url_extraction".to_string(),
-                url: Some(url),
-            },
-            JobType::YoutubeExtraction { url } => JobTypeDto {
-                type_name: "youtube_extraction".to_string(),
-                url: Some(url),
-            },
-        };
-
         Self {
             job_id: response.job_id,
             file_id: response.file_id,
-            job_type,
+            job_type: JobTypeDto::from(&response.job_type),
             status: response.status,
             message: response.message,
         }
@@ -1,10 +1,12 @@
 pub mod content_dto;
+pub mod feed_dto;
 pub mod file_dto;
 pub mod job_dto;
 pub mod response_dto;
 pub mod search_dto;
 
 pub use content_dto::*;
+pub use feed_dto::*;
 pub use file_dto::*;
 pub use job_dto::*;
 pub use response_dto::*;
@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::domain::value_objects::ChunkContext;
+
 #[derive(Debug, Deserialize)]
 pub struct SearchRequestDto {
     pub query: String,
@@ -8,6 +10,19 @@ pub struct SearchRequestDto {
     pub limit: Option<i32>,
     pub similarity_threshold: Option<f32>,
     pub file_id: Option<Uuid>,
+    /// Comma-separated language codes, e.g. `en,fr`
+    pub language: Option<String>,
+    /// Search the top corpus languages separately and fuse the rankings
+    pub cross_lingual: Option<bool>,
+    pub max_languages: Option<usize>,
+    /// `parent` returns the parent chunk of each matched child chunk
+    pub expand: Option<String>,
+    /// Neighbouring chunks to include on each side of every match
+    pub context_window: Option<usize>,
+    /// Embedding model to search with; defaults to the primary model
+    pub model: Option<String>,
+    /// `dense` (default), `sparse` or `hybrid`
+    pub mode: Option<String>,
 }
 
 fn default_limit() -> Option<i32> {
@@ -20,6 +35,11 @@ pub struct SearchResponseDto {
     pub results: Vec<SearchResultDto>,
     pub total_results: i32,
     pub search_time_ms: u64,
+    pub query_language: Option<String>,
+    pub searched_languages: Vec<String>,
+    pub model: String,
+    pub sparse_model: Option<String>,
+    pub mode: String,
 }
 
 #[derive(Debug, Serialize)]
@@ -31,6 +51,47 @@ pub struct SearchResultDto {
     pub chunk_index: i32,
     pub page_number: Option<i32>,
     pub section_path: Option<String>,
+    pub language: Option<String>,
+    pub parent_chunk_id: Option<Uuid>,
+    /// Text of the parent chunk, when `expand=parent` was requested
+    pub parent_text: Option<String>,
+    /// Stitched neighbouring chunks, when `context_window` was requested
+    pub context: Option<ChunkContextDto>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ChunkContextDto {
+    pub text: String,
+    pub first_chunk_index: i32,
+    pub last_chunk_index: i32,
+    /// Character offsets of the matched chunks within `text`, best match first
+    pub hits: Vec<HitSpanDto>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct HitSpanDto {
+    pub chunk_id: Uuid,
+    pub start: usize,
+    pub end: usize,
+}
+
+impl From<ChunkContext> for ChunkContextDto {
+    fn from(context: ChunkContext) -> Self {
+        Self {
+            text: context.text,
+            first_chunk_index: context.first_chunk_index,
+            last_chunk_index: context.last_chunk_index,
+            hits: context
+                .hits
+                .into_iter()
+                .map(|hit| HitSpanDto {
+                    chunk_id: hit.chunk_id,
+                    start: hit.start,
+                    end: hit.end,
+                })
+                .collect(),
+        }
+    }
 }
 
 impl From<crate::application::use_cases::search_content::SearchContentResponse> for SearchResponseDto {
@@ -40,6 +101,11 @@ impl From<crate::application::use_cases::search_content::SearchContentResponse>
             results: response.results.into_iter().map(SearchResultDto::from).collect(),
             total_results: response.total_results,
             search_time_ms: response.search_time_ms,
+            query_language: response.query_language,
+            searched_languages: response.searched_languages,
+            model: response.model,
+            sparse_model: response.sparse_model,
+            mode: response.mode.as_str().to_string(),
         }
     }
 }
@@ -54,6 +120,12 @@ impl From<crate::application::use_cases::search_content::SearchResult> for Searc
             chunk_index: result.chunk.chunk_index(),
             page_number: result.chunk.page_number(),
             section_path: result.chunk.section_path().map(|s| s.to_string()),
+            language: result.source_language,
+            parent_chunk_id: result.chunk.parent_chunk_id(),
+            parent_text: result
+                .parent_chunk
+                .map(|parent| parent.chunk_text().to_string()),
+            context: result.context.map(ChunkContextDto::from),
         }
     }
 }
@@ -42,11 +42,26 @@ impl ContentHandler {
             ));
         }
 
+        let chunking_config = match request_dto.chunking.into_config() {
+            Ok(config) => config,
+            Err(e) => {
+                return Ok((
+                    StatusCode::BAD_REQUEST,
+                    Json(ApiResponse::error(
+                        "INVALID_CHUNKING_CONFIG".to_string(),
+                        e,
+                        None,
+                    )),
+                ));
+            }
+        };
+
         // Convert DTO to use case request
         let use_case_request = ProcessUrlDirectRequest {
             url: request_dto.url,
             filename: request_dto.filename,
             auto_process: request_dto.auto_process.unwrap_or(true),
+            chunking_config,
         };
 
         // Execute use case
@@ -111,6 +126,20 @@ impl ContentHandler {
             ));
         }
 
+        let chunking_config = match request_dto.chunking.into_config() {
+            Ok(config) => config,
+            Err(e) => {
+                return Ok((
+                    StatusCode::BAD_REQUEST,
+                    Json(ApiResponse::error(
+                        "INVALID_CHUNKING_CONFIG".to_string(),
+                        e,
+                        None,
+                    )),
+                ));
+            }
+        };
+
         // Convert DTO to use case request
         let use_case_request = ProcessYoutubeDirectRequest {
             url: request_dto.url,
@@ -120,6 +149,7 @@ impl ContentHandler {
                 .language_preference
                 .unwrap_or_else(|| vec!["en".to_string()]),
             auto_process: request_dto.auto_process.unwrap_or(true),
+            chunking_config,
         };
 
         // Execute use case
@@ -8,6 +8,7 @@ use pgvector::Vector;
 use std::sync::Arc;
 use uuid::Uuid;
 
+use crate::application::services::EmbeddingModelRegistry;
 use crate::domain::repositories::EmbeddingRepository;
 use crate::presentation::http::dto::ApiResponse;
 
@@ -34,15 +35,66 @@ pub struct SimilaritySearchResultDto {
 
 pub struct EmbeddingHandler {
     embedding_repository: Arc<dyn EmbeddingRepository>,
+    embedding_models: Arc<EmbeddingModelRegistry>,
 }
 
 impl EmbeddingHandler {
-    pub fn new(embedding_repository: Arc<dyn EmbeddingRepository>) -> Self {
+    pub fn new(
+        embedding_repository: Arc<dyn EmbeddingRepository>,
+        embedding_models: Arc<EmbeddingModelRegistry>,
+    ) -> Self {
         Self {
             embedding_repository,
+            embedding_models,
         }
     }
 
+    /// The configured models and the state of each server behind them
+    pub async fn get_embedding_models(
+        State(handler): State<Arc<EmbeddingHandler>>,
+    ) -> Result<impl IntoResponse, StatusCode> {
+        let active_model = handler.embedding_models.active_model();
+        let models: Vec<serde_json::Value> = handler
+            .embedding_models
+            .providers()
+            .iter()
+            .map(|provider| {
+                let (model_name, model_version) = provider.model_info();
+                let endpoints: Vec<serde_json::Value> = provider
+                    .endpoints()
+                    .iter()
+                    .map(|endpoint| {
+                        serde_json::json!({
+                            "url": endpoint.url,
+                            "state": endpoint.state.as_str(),
+                            "consecutive_failures": endpoint.consecutive_failures,
+                            "times_opened": endpoint.times_opened,
+                            "requests": endpoint.requests,
+                            "failures": endpoint.failures,
+                            "retry_in_secs": endpoint.retry_in.map(|wait| wait.as_secs_f64()),
+                        })
+                    })
+                    .collect();
+
+                serde_json::json!({
+                    "active": model_name == active_model,
+                    "model_name": model_name,
+                    "model_version": model_version,
+                    "dimension": provider.embedding_dimension(),
+                    "available": provider.unavailable_for().is_none(),
+                    "endpoints": endpoints,
+                })
+            })
+            .collect();
+
+        Ok((
+            StatusCode::OK,
+            Json(ApiResponse::success(
+                serde_json::json!({ "models": models }),
+            )),
+        ))
+    }
+
     pub async fn get_embedding(
         State(handler): State<Arc<EmbeddingHandler>>,
         Path(embedding_id): Path<Uuid>,
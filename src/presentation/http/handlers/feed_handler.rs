@@ -0,0 +1,243 @@
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::application::use_cases::{
+    PollFeedUseCase, SubscribeFeedUseCase,
+    poll_feed::PollFeedError,
+    subscribe_feed::{SubscribeFeedError, SubscribeFeedRequest},
+};
+use crate::domain::repositories::FeedRepository;
+use crate::presentation::http::dto::{
+    ApiResponse, FeedDto, FeedEntryDto, PollFeedResponseDto, SubscribeFeedRequestDto,
+    SubscribeFeedResponseDto,
+};
+
+pub struct FeedHandler {
+    subscribe_feed_use_case: Arc<SubscribeFeedUseCase>,
+    poll_feed_use_case: Arc<PollFeedUseCase>,
+    feed_repository: Arc<dyn FeedRepository>,
+}
+
+impl FeedHandler {
+    pub fn new(
+        subscribe_feed_use_case: Arc<SubscribeFeedUseCase>,
+        poll_feed_use_case: Arc<PollFeedUseCase>,
+        feed_repository: Arc<dyn FeedRepository>,
+    ) -> Self {
+        Self {
+            subscribe_feed_use_case,
+            poll_feed_use_case,
+            feed_repository,
+        }
+    }
+
+    pub async fn subscribe(
+        State(handler): State<Arc<FeedHandler>>,
+        Json(request_dto): Json<SubscribeFeedRequestDto>,
+    ) -> Result<impl IntoResponse, StatusCode> {
+        if request_dto.url.trim().is_empty() {
+            return Ok((
+                StatusCode::BAD_REQUEST,
+                Json(ApiResponse::error(
+                    "EMPTY_URL".to_string(),
+                    "Feed URL cannot be empty".to_string(),
+                    None,
+                )),
+            ));
+        }
+
+        let use_case_request = SubscribeFeedRequest {
+            url: request_dto.url.trim().to_string(),
+            name: request_dto.name,
+            poll_interval_seconds: request_dto.poll_interval_seconds,
+        };
+
+        match handler
+            .subscribe_feed_use_case
+            .execute(use_case_request)
+            .await
+        {
+            Ok(response) => {
+                let dto = SubscribeFeedResponseDto {
+                    feed: FeedDto::from(&response.feed),
+                    item_count: response.item_count,
+                    message: "Feed subscribed successfully".to_string(),
+                };
+                Ok((StatusCode::CREATED, Json(ApiResponse::success(dto))))
+            }
+            Err(e) => {
+                let (status, error_code) = match e {
+                    SubscribeFeedError::InvalidUrl(_) => (StatusCode::BAD_REQUEST, "INVALID_URL"),
+                    SubscribeFeedError::ValidationError(_) => {
+                        (StatusCode::BAD_REQUEST, "VALIDATION_ERROR")
+                    }
+                    SubscribeFeedError::AlreadySubscribed(_) => {
+                        (StatusCode::CONFLICT, "FEED_ALREADY_SUBSCRIBED")
+                    }
+                    SubscribeFeedError::FetchError(_) => {
+                        (StatusCode::UNPROCESSABLE_ENTITY, "FEED_FETCH_FAILED")
+                    }
+                    SubscribeFeedError::RepositoryError(_) => {
+                        (StatusCode::INTERNAL_SERVER_ERROR, "REPOSITORY_ERROR")
+                    }
+                };
+
+                Ok((
+                    status,
+                    Json(ApiResponse::error(
+                        error_code.to_string(),
+                        e.to_string(),
+                        None,
+                    )),
+                ))
+            }
+        }
+    }
+
+    pub async fn list_feeds(
+        State(handler): State<Arc<FeedHandler>>,
+    ) -> Result<impl IntoResponse, StatusCode> {
+        match handler.feed_repository.find_all().await {
+            Ok(feeds) => {
+                let feeds_dto: Vec<FeedDto> = feeds.iter().map(FeedDto::from).collect();
+                Ok((
+                    StatusCode::OK,
+                    Json(ApiResponse::success(serde_json::json!({
+                        "feeds": feeds_dto,
+                        "count": feeds_dto.len()
+                    }))),
+                ))
+            }
+            Err(e) => Ok((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiResponse::error(
+                    "DATABASE_ERROR".to_string(),
+                    e.to_string(),
+                    None,
+                )),
+            )),
+        }
+    }
+
+    pub async fn get_feed(
+        State(handler): State<Arc<FeedHandler>>,
+        Path(feed_id): Path<Uuid>,
+    ) -> Result<impl IntoResponse, StatusCode> {
+        match handler.feed_repository.find_by_id(feed_id).await {
+            Ok(Some(feed)) => Ok((
+                StatusCode::OK,
+                Json(ApiResponse::success(FeedDto::from(&feed))),
+            )),
+            Ok(None) => Ok((
+                StatusCode::NOT_FOUND,
+                Json(ApiResponse::error(
+                    "FEED_NOT_FOUND".to_string(),
+                    format!("Feed with ID {} not found", feed_id),
+                    None,
+                )),
+            )),
+            Err(e) => Ok((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiResponse::error(
+                    "DATABASE_ERROR".to_string(),
+                    e.to_string(),
+                    None,
+                )),
+            )),
+        }
+    }
+
+    pub async fn get_feed_entries(
+        State(handler): State<Arc<FeedHandler>>,
+        Path(feed_id): Path<Uuid>,
+    ) -> Result<impl IntoResponse, StatusCode> {
+        match handler.feed_repository.find_entries(feed_id).await {
+            Ok(entries) => {
+                let entries_dto: Vec<FeedEntryDto> =
+                    entries.iter().map(FeedEntryDto::from).collect();
+                Ok((
+                    StatusCode::OK,
+                    Json(ApiResponse::success(serde_json::json!({
+                        "feed_id": feed_id,
+                        "entries": entries_dto,
+                        "count": entries_dto.len()
+                    }))),
+                ))
+            }
+            Err(e) => Ok((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiResponse::error(
+                    "DATABASE_ERROR".to_string(),
+                    e.to_string(),
+                    None,
+                )),
+            )),
+        }
+    }
+
+    pub async fn poll_feed(
+        State(handler): State<Arc<FeedHandler>>,
+        Path(feed_id): Path<Uuid>,
+    ) -> Result<impl IntoResponse, StatusCode> {
+        match handler.poll_feed_use_case.execute(feed_id).await {
+            Ok(response) => Ok((
+                StatusCode::OK,
+                Json(ApiResponse::success(PollFeedResponseDto::from(response))),
+            )),
+            Err(e) => {
+                let (status, error_code) = match e {
+                    PollFeedError::FeedNotFound(_) => (StatusCode::NOT_FOUND, "FEED_NOT_FOUND"),
+                    PollFeedError::FetchError(_) => (StatusCode::BAD_GATEWAY, "FEED_FETCH_FAILED"),
+                    PollFeedError::RepositoryError(_) => {
+                        (StatusCode::INTERNAL_SERVER_ERROR, "REPOSITORY_ERROR")
+                    }
+                };
+
+                Ok((
+                    status,
+                    Json(ApiResponse::error(
+                        error_code.to_string(),
+                        e.to_string(),
+                        None,
+                    )),
+                ))
+            }
+        }
+    }
+
+    pub async fn delete_feed(
+        State(handler): State<Arc<FeedHandler>>,
+        Path(feed_id): Path<Uuid>,
+    ) -> Result<impl IntoResponse, StatusCode> {
+        match handler.feed_repository.delete(feed_id).await {
+            Ok(true) => Ok((
+                StatusCode::OK,
+                Json(ApiResponse::success(
+                    "Feed unsubscribed successfully".to_string(),
+                )),
+            )),
+            Ok(false) => Ok((
+                StatusCode::NOT_FOUND,
+                Json(ApiResponse::error(
+                    "FEED_NOT_FOUND".to_string(),
+                    format!("Feed with ID {} not found", feed_id),
+                    None,
+                )),
+            )),
+            Err(e) => Ok((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiResponse::error(
+                    "DELETE_FAILED".to_string(),
+                    e.to_string(),
+                    None,
+                )),
+            )),
+        }
+    }
+}
@@ -16,9 +16,9 @@ use crate::application::use_cases::{
 use crate::domain::repositories::FileRepository;
 use crate::presentation::http::dto::content_dto::UploadWithProcessingResponse;
 use crate::presentation::http::dto::{
-    ApiResponse, PaginationDto, PaginationMetaDto, file_dto::FileDetailResponseDto,
-    file_dto::FileListResponseDto, file_dto::FileResponseDto, file_dto::ProcessFileResponseDto,
-    file_dto::UploadResponseDto,
+    ApiResponse, ChunkingConfigDto, PaginationDto, PaginationMetaDto,
+    file_dto::FileDetailResponseDto, file_dto::FileListResponseDto, file_dto::FileResponseDto,
+    file_dto::ProcessFileResponseDto, file_dto::UploadResponseDto,
 };
 
 pub struct FileHandler {
@@ -305,6 +305,7 @@ impl FileHandler {
         let mut file_data = None;
         let mut file_name = None;
         let mut content_type = None;
+        let mut chunking = ChunkingConfigDto::default();
 
         while let Some(field) = multipart.next_field().await.map_err(|e| {
             eprintln!("Error reading multipart field: {:?}", e);
@@ -342,6 +343,52 @@ impl FileHandler {
                         }
                     }
                 }
+                Some(
+                    name @ ("chunking_strategy" | "chunk_size" | "chunk_overlap" | "chunk_unit"
+                    | "parent_chunk_size" | "contextual_headers"),
+                ) => {
+                    let name = name.to_string();
+                    let value = field.text().await.map_err(|e| {
+                        eprintln!("Error reading field {}: {:?}", name, e);
+                        StatusCode::BAD_REQUEST
+                    })?;
+                    let value = value.trim().to_string();
+
+                    match name.as_str() {
+                        "chunking_strategy" => chunking.chunking_strategy = Some(value),
+                        "chunk_unit" => chunking.chunk_unit = Some(value),
+                        "contextual_headers" => {
+                            let Ok(enabled) = value.parse::<bool>() else {
+                                return Ok((
+                                    StatusCode::BAD_REQUEST,
+                                    Json(ApiResponse::error(
+                                        "INVALID_CHUNKING_CONFIG".to_string(),
+                                        "contextual_headers must be true or false".to_string(),
+                                        None,
+                                    )),
+                                ));
+                            };
+                            chunking.contextual_headers = Some(enabled);
+                        }
+                        _ => {
+                            let Ok(number) = value.parse::<usize>() else {
+                                return Ok((
+                                    StatusCode::BAD_REQUEST,
+                                    Json(ApiResponse::error(
+                                        "INVALID_CHUNKING_CONFIG".to_string(),
+                                        format!("{} must be a non-negative integer", name),
+                                        None,
+                                    )),
+                                ));
+                            };
+                            match name.as_str() {
+                                "chunk_size" => chunking.chunk_size = Some(number),
+                                "chunk_overlap" => chunking.chunk_overlap = Some(number),
+                                _ => chunking.parent_chunk_size = Some(number),
+                            }
+                        }
+                    }
+                }
                 _ => {
                     // Skip unknown fields
                     eprintln!("Skipping unknown field: {:?}", field.name());
@@ -349,6 +396,20 @@ impl FileHandler {
             }
         }
 
+        let chunking_config = match chunking.into_config() {
+            Ok(config) => config,
+            Err(e) => {
+                return Ok((
+                    StatusCode::BAD_REQUEST,
+                    Json(ApiResponse::error(
+                        "INVALID_CHUNKING_CONFIG".to_string(),
+                        e,
+                        None,
+                    )),
+                ));
+            }
+        };
+
         let file_data = file_data.ok_or_else(|| {
             eprintln!("No file data found in multipart form. Expected field named 'file', 'upload', or 'document'");
             StatusCode::BAD_REQUEST
@@ -364,6 +425,7 @@ impl FileHandler {
             content_type,
             auto_process,
             metadata: None,
+            chunking_config,
         };
 
         match handler
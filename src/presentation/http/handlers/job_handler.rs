@@ -1,6 +1,6 @@
 use axum::{
     Json,
-    extract::{Path, State},
+    extract::{Path, Query, State},
     http::StatusCode,
     response::IntoResponse,
 };
@@ -8,16 +8,18 @@ use std::sync::Arc;
 use uuid::Uuid;
 
 use crate::application::use_cases::{
-    CancelJobUseCase, GetJobStatusUseCase, QueueProcessingJobUseCase, cancel_job::CancelJobRequest,
-    get_job_status::GetJobStatusRequest,
+    CancelJobUseCase, GetJobStatusUseCase, QueueProcessingJobUseCase, QueueReembedUseCase,
+    cancel_job::CancelJobRequest, get_job_status::GetJobStatusRequest,
+    queue_reembed::QueueReembedRequest,
 };
 use crate::presentation::http::dto::{
-    ApiResponse, CancelJobResponseDto, JobStatusDto, ProcessUrlRequestDto,
-    ProcessYoutubeRequestDto, QueueJobResponseDto,
+    ApiResponse, CancelJobResponseDto, ChunkingConfigDto, JobStatusDto, ProcessUrlRequestDto,
+    ProcessYoutubeRequestDto, QueueJobResponseDto, ReembedRequestDto,
 };
 
 pub struct JobHandler {
     queue_job_use_case: Arc<QueueProcessingJobUseCase>,
+    queue_reembed_use_case: Arc<QueueReembedUseCase>,
     get_job_status_use_case: Arc<GetJobStatusUseCase>,
     cancel_job_use_case: Arc<CancelJobUseCase>,
 }
@@ -25,11 +27,13 @@ pub struct JobHandler {
 impl JobHandler {
     pub fn new(
         queue_job_use_case: Arc<QueueProcessingJobUseCase>,
+        queue_reembed_use_case: Arc<QueueReembedUseCase>,
         get_job_status_use_case: Arc<GetJobStatusUseCase>,
         cancel_job_use_case: Arc<CancelJobUseCase>,
     ) -> Self {
         Self {
             queue_job_use_case,
+            queue_reembed_use_case,
             get_job_status_use_case,
             cancel_job_use_case,
         }
@@ -39,10 +43,16 @@ impl JobHandler {
     pub async fn queue_file_processing(
         State(handler): State<Arc<JobHandler>>,
         Path(file_id): Path<Uuid>,
+        Query(chunking): Query<ChunkingConfigDto>,
     ) -> Result<impl IntoResponse, StatusCode> {
+        let chunking_config = match chunking.into_config() {
+            Ok(config) => config,
+            Err(e) => return Ok(invalid_chunking_config(e)),
+        };
+
         match handler
             .queue_job_use_case
-            .queue_file_processing(file_id)
+            .queue_file_processing(file_id, chunking_config)
             .await
         {
             Ok(response) => {
@@ -66,9 +76,14 @@ impl JobHandler {
         Path(file_id): Path<Uuid>,
         Json(request): Json<ProcessUrlRequestDto>,
     ) -> Result<impl IntoResponse, StatusCode> {
+        let chunking_config = match request.chunking.into_config() {
+            Ok(config) => config,
+            Err(e) => return Ok(invalid_chunking_config(e)),
+        };
+
         match handler
             .queue_job_use_case
-            .queue_url_extraction(file_id, request.url)
+            .queue_url_extraction(file_id, request.url, chunking_config)
             .await
         {
             Ok(response) => {
@@ -92,9 +107,14 @@ impl JobHandler {
         Path(file_id): Path<Uuid>,
         Json(request): Json<ProcessYoutubeRequestDto>,
     ) -> Result<impl IntoResponse, StatusCode> {
+        let chunking_config = match request.chunking.into_config() {
+            Ok(config) => config,
+            Err(e) => return Ok(invalid_chunking_config(e)),
+        };
+
         match handler
             .queue_job_use_case
-            .queue_youtube_extraction(file_id, request.url)
+            .queue_youtube_extraction(file_id, request.url, chunking_config)
             .await
         {
             Ok(response) => {
@@ -112,6 +132,32 @@ impl JobHandler {
         }
     }
 
+    // Queue re-embedding job
+    pub async fn queue_reembed(
+        State(handler): State<Arc<JobHandler>>,
+        Json(request): Json<ReembedRequestDto>,
+    ) -> Result<impl IntoResponse, StatusCode> {
+        let request = QueueReembedRequest {
+            model_name: request.model,
+            file_id: request.file_id,
+        };
+
+        match handler.queue_reembed_use_case.execute(request).await {
+            Ok(response) => {
+                let dto = QueueJobResponseDto::from(response);
+                Ok((StatusCode::ACCEPTED, Json(ApiResponse::success(dto))))
+            }
+            Err(e) => Ok((
+                StatusCode::BAD_REQUEST,
+                Json(ApiResponse::error(
+                    "QUEUE_FAILED".to_string(),
+                    e.to_string(),
+                    None,
+                )),
+            )),
+        }
+    }
+
     // Get job status
     pub async fn get_job_status(
         State(handler): State<Arc<JobHandler>>,
@@ -205,3 +251,16 @@ impl JobHandler {
         }
     }
 }
+
+fn invalid_chunking_config(
+    message: String,
+) -> (StatusCode, Json<ApiResponse<QueueJobResponseDto>>) {
+    (
+        StatusCode::BAD_REQUEST,
+        Json(ApiResponse::error(
+            "INVALID_CHUNKING_CONFIG".to_string(),
+            message,
+            None,
+        )),
+    )
+}
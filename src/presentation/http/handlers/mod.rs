@@ -1,6 +1,7 @@
 pub mod chunk_handler;
 pub mod content_handler;
 pub mod embedding_handler;
+pub mod feed_handler;
 pub mod file_handler;
 pub mod job_handler;
 pub mod search_handler;
@@ -9,6 +10,7 @@ pub mod sse_handler;
 pub use chunk_handler::ChunkHandler;
 pub use content_handler::ContentHandler;
 pub use embedding_handler::EmbeddingHandler;
+pub use feed_handler::FeedHandler;
 pub use file_handler::FileHandler;
 pub use job_handler::JobHandler;
 pub use search_handler::SearchHandler;
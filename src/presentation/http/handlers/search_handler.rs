@@ -6,7 +6,11 @@ use axum::{
 };
 use std::sync::Arc;
 
-use crate::application::use_cases::{SearchContentUseCase, search_content::SearchContentRequest};
+use crate::application::services::search_service::RetrievalMode;
+use crate::application::use_cases::{
+    SearchContentUseCase,
+    search_content::{SearchContentError, SearchContentRequest},
+};
 use crate::presentation::http::dto::{ApiResponse, SearchRequestDto, SearchResponseDto};
 
 pub struct SearchHandler {
@@ -33,11 +37,68 @@ impl SearchHandler {
             ));
         }
 
+        let expand_parents = match search_params.expand.as_deref().map(str::trim) {
+            None | Some("") | Some("none") => false,
+            Some("parent") => true,
+            Some(other) => {
+                return Ok((
+                    StatusCode::BAD_REQUEST,
+                    Json(ApiResponse::error(
+                        "VALIDATION_ERROR".to_string(),
+                        format!("Unknown expand value '{}'; expected 'parent'", other),
+                        None,
+                    )),
+                ));
+            }
+        };
+
+        let mode = match search_params
+            .mode
+            .as_deref()
+            .filter(|mode| !mode.trim().is_empty())
+        {
+            None => RetrievalMode::default(),
+            Some(mode) => match RetrievalMode::parse(mode) {
+                Ok(mode) => mode,
+                Err(msg) => {
+                    return Ok((
+                        StatusCode::BAD_REQUEST,
+                        Json(ApiResponse::error(
+                            "VALIDATION_ERROR".to_string(),
+                            msg,
+                            None,
+                        )),
+                    ));
+                }
+            },
+        };
+
         let request = SearchContentRequest {
             query: search_params.query,
             limit: search_params.limit,
             similarity_threshold: search_params.similarity_threshold,
             file_id_filter: search_params.file_id,
+            languages: search_params
+                .language
+                .as_deref()
+                .map(|languages| {
+                    languages
+                        .split(',')
+                        .map(str::trim)
+                        .filter(|l| !l.is_empty())
+                        .map(String::from)
+                        .collect()
+                })
+                .unwrap_or_default(),
+            cross_lingual: search_params.cross_lingual.unwrap_or(false),
+            max_languages: search_params.max_languages,
+            expand_parents,
+            context_window: search_params.context_window.unwrap_or(0),
+            model: search_params
+                .model
+                .map(|model| model.trim().to_string())
+                .filter(|model| !model.is_empty()),
+            mode,
         };
 
         match handler.search_use_case.execute(request).await {
@@ -48,6 +109,14 @@ impl SearchHandler {
                     Json(ApiResponse::<SearchResponseDto>::success(dto)),
                 ))
             }
+            Err(SearchContentError::ValidationError(msg)) => Ok((
+                StatusCode::BAD_REQUEST,
+                Json(ApiResponse::error(
+                    "VALIDATION_ERROR".to_string(),
+                    msg,
+                    None,
+                )),
+            )),
             Err(e) => Ok((
                 StatusCode::INTERNAL_SERVER_ERROR,
                 Json(ApiResponse::error(
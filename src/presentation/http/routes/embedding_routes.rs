@@ -40,6 +40,10 @@ pub fn embedding_routes(embedding_handler: Arc<EmbeddingHandler>) -> Router {
             "/embeddings-count",
             get(EmbeddingHandler::get_embedding_count),
         )
+        .route(
+            "/embedding-models",
+            get(EmbeddingHandler::get_embedding_models),
+        )
         // .route(
         //     "/embeddings/count/model/{model_name}",
         //     get(EmbeddingHandler::get_embedding_count_by_model),
@@ -0,0 +1,21 @@
+use axum::{
+    Router,
+    routing::{delete, get, post},
+};
+use std::sync::Arc;
+
+use crate::presentation::http::handlers::FeedHandler;
+
+pub fn feed_routes(feed_handler: Arc<FeedHandler>) -> Router {
+    Router::new()
+        .route("/feeds", post(FeedHandler::subscribe))
+        .route("/feeds", get(FeedHandler::list_feeds))
+        .route("/feeds/{feed_id}", get(FeedHandler::get_feed))
+        .route("/feeds/{feed_id}", delete(FeedHandler::delete_feed))
+        .route(
+            "/feeds/{feed_id}/entries",
+            get(FeedHandler::get_feed_entries),
+        )
+        .route("/feeds/{feed_id}/poll", post(FeedHandler::poll_feed))
+        .with_state(feed_handler)
+}
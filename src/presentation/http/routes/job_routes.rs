@@ -17,6 +17,7 @@ pub fn job_routes(job_handler: Arc<JobHandler>, sse_handler: Arc<SseHandler>) ->
             "/processing-job/youtube/{file_id}",
             post(JobHandler::queue_youtube_extraction),
         )
+        .route("/processing-job/reembed", post(JobHandler::queue_reembed))
         .route("/jobs/{job_id}", get(JobHandler::get_job_status))
         .route("/jobs/{job_id}/cancel", delete(JobHandler::cancel_job))
         .route("/file-jobs/file/{file_id}", get(JobHandler::get_file_jobs))
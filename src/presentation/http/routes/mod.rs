@@ -1,6 +1,7 @@
 pub mod chunk_routes;
 pub mod content_routes;
 pub mod embedding_routes;
+pub mod feed_routes;
 pub mod file_routes;
 pub mod health_routes;
 pub mod job_routes;
@@ -9,6 +10,7 @@ pub mod search_routes;
 pub use chunk_routes::*;
 pub use content_routes::*;
 pub use embedding_routes::*;
+pub use feed_routes::*;
 pub use file_routes::*;
 pub use health_routes::*;
 pub use job_routes::*;
@@ -6,75 +6,87 @@ use tower_http::cors::{Any, CorsLayer};
 use tower_http::limit::RequestBodyLimitLayer;
 use tower_http::trace::TraceLayer;
 
-use crate::infrastructure::messaging::BackgroundProcessor;
+use crate::infrastructure::file_system::FolderWatcher;
+use crate::infrastructure::messaging::{BackgroundProcessor, FeedScheduler};
 use crate::presentation::http::{
     handlers::{
-        ChunkHandler, ContentHandler, EmbeddingHandler, FileHandler, JobHandler, SearchHandler,
-        SseHandler,
+        ChunkHandler, ContentHandler, EmbeddingHandler, FeedHandler, FileHandler, JobHandler,
+        SearchHandler, SseHandler,
     },
     routes::{
-        chunk_routes, content_processing_routes, embedding_routes, file_routes, health_routes,
-        job_routes, search_routes,
+        chunk_routes, content_processing_routes, embedding_routes, feed_routes, file_routes,
+        health_routes, job_routes, search_routes,
     },
 };
 
+/// Request handlers behind the routes
+pub struct HttpHandlers {
+    pub file: Arc<FileHandler>,
+    pub content: Arc<ContentHandler>,
+    pub search: Arc<SearchHandler>,
+    pub job: Arc<JobHandler>,
+    pub sse: Arc<SseHandler>,
+    pub chunk: Arc<ChunkHandler>,
+    pub embedding: Arc<EmbeddingHandler>,
+    pub feed: Arc<FeedHandler>,
+}
+
+/// Long-running tasks started alongside the server
+pub struct BackgroundServices {
+    pub background_processor: Arc<BackgroundProcessor>,
+    pub feed_scheduler: Arc<FeedScheduler>,
+    pub folder_watcher: Arc<FolderWatcher>,
+}
+
 pub struct HttpServer {
-    file_handler: Arc<FileHandler>,
-    content_handler: Arc<ContentHandler>,
-    search_handler: Arc<SearchHandler>,
-    job_handler: Arc<JobHandler>,
-    sse_handler: Arc<SseHandler>,
-    chunk_handler: Arc<ChunkHandler>,
-    embedding_handler: Arc<EmbeddingHandler>,
-    background_processor: Arc<BackgroundProcessor>,
+    handlers: HttpHandlers,
+    services: BackgroundServices,
     port: u16,
 }
 
 impl HttpServer {
-    pub fn new(
-        file_handler: Arc<FileHandler>,
-        content_handler: Arc<ContentHandler>,
-        search_handler: Arc<SearchHandler>,
-        job_handler: Arc<JobHandler>,
-        sse_handler: Arc<SseHandler>,
-        chunk_handler: Arc<ChunkHandler>,
-        embedding_handler: Arc<EmbeddingHandler>,
-        background_processor: Arc<BackgroundProcessor>,
-        port: Option<u16>,
-    ) -> Self {
+    pub fn new(handlers: HttpHandlers, services: BackgroundServices, port: Option<u16>) -> Self {
         Self {
-            file_handler,
-            content_handler,
-            search_handler,
-            job_handler,
-            sse_handler,
-            chunk_handler,
-            embedding_handler,
-            background_processor,
+            handlers,
+            services,
             port: port.unwrap_or(3000),
         }
     }
 
     pub async fn run(self) -> Result<(), Box<dyn std::error::Error>> {
         // Start background processor
-        let background_processor = self.background_processor.clone();
+        let background_processor = self.services.background_processor.clone();
         tokio::spawn(async move {
             background_processor.start().await;
         });
 
+        // Start feed scheduler
+        let feed_scheduler = self.services.feed_scheduler.clone();
+        tokio::spawn(async move {
+            feed_scheduler.start().await;
+        });
+
+        // Start watched-folder ingestion
+        let folder_watcher = self.services.folder_watcher.clone();
+        tokio::spawn(async move {
+            folder_watcher.start().await;
+        });
+
         let cors = CorsLayer::new()
             .allow_origin(Any)
             .allow_methods(Any)
             .allow_headers(Any);
 
+        let handlers = self.handlers;
         let app = Router::new()
             .merge(health_routes())
-            .merge(file_routes(self.file_handler.clone()))
-            .merge(content_processing_routes(self.content_handler))
-            .merge(search_routes(self.search_handler))
-            .merge(job_routes(self.job_handler, self.sse_handler))
-            .merge(chunk_routes(self.chunk_handler.clone()))
-            .merge(embedding_routes(self.embedding_handler.clone()))
+            .merge(file_routes(handlers.file))
+            .merge(content_processing_routes(handlers.content))
+            .merge(search_routes(handlers.search))
+            .merge(job_routes(handlers.job, handlers.sse))
+            .merge(chunk_routes(handlers.chunk))
+            .merge(embedding_routes(handlers.embedding))
+            .merge(feed_routes(handlers.feed))
             .layer(cors)
             .layer(RequestBodyLimitLayer::new(250 * 1024 * 1024)) // 250MB cap
             .layer(